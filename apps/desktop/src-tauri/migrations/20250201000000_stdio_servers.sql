@@ -0,0 +1,7 @@
+-- Stdio transport launch configuration
+-- Only used when server_type = 'stdio'; `url` is left empty for these servers
+
+ALTER TABLE mcp_servers ADD COLUMN command TEXT;  -- executable to spawn
+ALTER TABLE mcp_servers ADD COLUMN args TEXT;  -- JSON array of arguments
+ALTER TABLE mcp_servers ADD COLUMN env TEXT;  -- JSON object of extra environment variables
+ALTER TABLE mcp_servers ADD COLUMN cwd TEXT;  -- working directory
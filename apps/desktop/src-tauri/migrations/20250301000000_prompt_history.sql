@@ -0,0 +1,16 @@
+-- MCP Prompt History (prompts/get calls, like mcp_call_history for tools/call)
+CREATE TABLE IF NOT EXISTS mcp_prompt_history (
+    id TEXT PRIMARY KEY NOT NULL,
+    server_id TEXT NOT NULL,
+    prompt_name TEXT NOT NULL,
+    arguments TEXT,  -- JSON string
+    output_result TEXT,  -- JSON string (raw response)
+    status TEXT NOT NULL,  -- 'success', 'error'
+    error_message TEXT,
+    duration_ms INTEGER,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (server_id) REFERENCES mcp_servers(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_mcp_prompt_history_server_id ON mcp_prompt_history(server_id);
+CREATE INDEX IF NOT EXISTS idx_mcp_prompt_history_created_at ON mcp_prompt_history(created_at DESC);
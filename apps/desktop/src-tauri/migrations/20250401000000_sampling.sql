@@ -0,0 +1,36 @@
+-- Sampling (sampling/createMessage) responder
+
+-- Rules answering sampling requests with canned responses
+CREATE TABLE IF NOT EXISTS sampling_rules (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL,
+    server_id TEXT,  -- NULL = applies to every server
+    match_type TEXT NOT NULL DEFAULT 'contains',  -- 'any', 'contains', 'regex'
+    pattern TEXT NOT NULL DEFAULT '',
+    response_text TEXT NOT NULL,
+    model TEXT NOT NULL,
+    stop_reason TEXT,
+    enabled INTEGER NOT NULL DEFAULT 1,
+    priority INTEGER NOT NULL DEFAULT 0,  -- lower runs first
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (server_id) REFERENCES mcp_servers(id) ON DELETE CASCADE
+);
+
+-- Every sampling request received and the response sent back
+CREATE TABLE IF NOT EXISTS sampling_exchanges (
+    id TEXT PRIMARY KEY NOT NULL,
+    server_id TEXT NOT NULL,
+    request TEXT NOT NULL,  -- JSON string
+    response TEXT,  -- JSON string
+    status TEXT NOT NULL,  -- 'pending', 'answered', 'rejected', 'timeout', 'cancelled'
+    source TEXT,  -- 'rule', 'manual'
+    rule_id TEXT,
+    error_message TEXT,
+    duration_ms INTEGER,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (server_id) REFERENCES mcp_servers(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_sampling_exchanges_server_id ON sampling_exchanges(server_id);
+CREATE INDEX IF NOT EXISTS idx_sampling_exchanges_created_at ON sampling_exchanges(created_at DESC);
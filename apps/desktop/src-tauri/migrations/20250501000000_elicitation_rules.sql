@@ -0,0 +1,15 @@
+-- Elicitation (elicitation/create) auto-answer rules
+CREATE TABLE IF NOT EXISTS elicitation_rules (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL,
+    server_id TEXT,  -- NULL = applies to every server
+    match_type TEXT NOT NULL DEFAULT 'contains',  -- 'any', 'contains', 'regex'
+    pattern TEXT NOT NULL DEFAULT '',
+    action TEXT NOT NULL,  -- 'accept', 'decline', 'cancel'
+    content TEXT,  -- JSON string sent with 'accept'
+    enabled INTEGER NOT NULL DEFAULT 1,
+    priority INTEGER NOT NULL DEFAULT 0,  -- lower runs first
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (server_id) REFERENCES mcp_servers(id) ON DELETE CASCADE
+);
@@ -0,0 +1,2 @@
+-- Roots exposed to each server through roots/list
+ALTER TABLE mcp_servers ADD COLUMN roots TEXT;  -- JSON array of { uri, name }
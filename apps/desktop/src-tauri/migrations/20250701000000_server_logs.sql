@@ -0,0 +1,14 @@
+-- Log records sent by MCP servers through notifications/message
+CREATE TABLE IF NOT EXISTS mcp_server_logs (
+    id TEXT PRIMARY KEY NOT NULL,
+    server_id TEXT NOT NULL,
+    level TEXT NOT NULL,  -- 'debug' .. 'emergency' (RFC 5424 names)
+    severity INTEGER NOT NULL,  -- 0 = debug .. 7 = emergency, used for level filtering
+    logger TEXT,
+    data TEXT NOT NULL,  -- JSON string
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (server_id) REFERENCES mcp_servers(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_mcp_server_logs_server_id ON mcp_server_logs(server_id, severity);
+CREATE INDEX IF NOT EXISTS idx_mcp_server_logs_created_at ON mcp_server_logs(created_at DESC);
@@ -0,0 +1,18 @@
+-- Per-server heartbeat settings
+ALTER TABLE mcp_servers ADD COLUMN heartbeat_strategy TEXT NOT NULL DEFAULT 'ping';  -- 'ping', 'list_tools', 'none'
+ALTER TABLE mcp_servers ADD COLUMN heartbeat_interval_secs INTEGER;  -- NULL = global heartbeat_interval setting
+ALTER TABLE mcp_servers ADD COLUMN heartbeat_max_failures INTEGER;  -- NULL = default threshold
+
+-- Heartbeat round trips (for latency charts)
+CREATE TABLE IF NOT EXISTS mcp_heartbeats (
+    id TEXT PRIMARY KEY NOT NULL,
+    server_id TEXT NOT NULL,
+    strategy TEXT NOT NULL,
+    success BOOLEAN NOT NULL,
+    rtt_ms INTEGER NOT NULL,
+    error_message TEXT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (server_id) REFERENCES mcp_servers(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_mcp_heartbeats_server_id ON mcp_heartbeats(server_id, created_at DESC);
@@ -0,0 +1,3 @@
+-- Custom HTTP headers and authentication for remote (SSE / streamable HTTP) servers
+ALTER TABLE mcp_servers ADD COLUMN headers TEXT;  -- JSON object of header name -> value
+ALTER TABLE mcp_servers ADD COLUMN auth TEXT;     -- JSON { type: 'none' | 'bearer' | 'basic', ... }
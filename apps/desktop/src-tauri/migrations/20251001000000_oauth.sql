@@ -0,0 +1,18 @@
+-- OAuth client registration and tokens per remote server
+CREATE TABLE IF NOT EXISTS mcp_oauth_credentials (
+    server_id TEXT PRIMARY KEY NOT NULL,
+    authorization_server TEXT NOT NULL,  -- Issuer URL
+    token_endpoint TEXT NOT NULL,
+    resource TEXT NOT NULL,  -- RFC 8707 resource indicator
+    client_id TEXT NOT NULL,
+    client_secret TEXT,
+    redirect_uri TEXT NOT NULL,
+    access_token TEXT,
+    refresh_token TEXT,
+    token_type TEXT,
+    scope TEXT,
+    expires_at INTEGER,  -- Unix seconds
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (server_id) REFERENCES mcp_servers(id) ON DELETE CASCADE
+);
@@ -0,0 +1,2 @@
+-- Automatic reconnect after connection loss (JSON: enabled, max_attempts, initial_delay_ms, max_delay_ms)
+ALTER TABLE mcp_servers ADD COLUMN reconnect_policy TEXT;  -- NULL = reconnect disabled
@@ -0,0 +1,2 @@
+-- Streamable HTTP: open the standalone GET SSE stream after initialize
+ALTER TABLE mcp_servers ADD COLUMN standalone_sse BOOLEAN NOT NULL DEFAULT 1;
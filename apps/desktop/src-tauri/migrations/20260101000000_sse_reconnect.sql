@@ -0,0 +1,2 @@
+-- Legacy SSE: reopen the GET stream when it drops instead of ending the connection
+ALTER TABLE mcp_servers ADD COLUMN sse_reconnect BOOLEAN NOT NULL DEFAULT 0;
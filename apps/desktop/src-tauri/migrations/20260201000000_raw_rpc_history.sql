@@ -0,0 +1,17 @@
+-- Raw JSON-RPC History (hand-written messages sent from the console)
+CREATE TABLE IF NOT EXISTS mcp_raw_rpc_history (
+    id TEXT PRIMARY KEY NOT NULL,
+    server_id TEXT NOT NULL,
+    kind TEXT NOT NULL,  -- 'request', 'notification', 'invalid'
+    method TEXT,
+    request TEXT NOT NULL,  -- raw text as sent
+    response TEXT,  -- raw reply
+    status TEXT NOT NULL,  -- 'success', 'error', 'timeout', 'sent'
+    error_message TEXT,
+    duration_ms INTEGER,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (server_id) REFERENCES mcp_servers(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_mcp_raw_rpc_history_server_id ON mcp_raw_rpc_history(server_id);
+CREATE INDEX IF NOT EXISTS idx_mcp_raw_rpc_history_created_at ON mcp_raw_rpc_history(created_at DESC);
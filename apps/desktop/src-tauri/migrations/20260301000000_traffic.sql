@@ -0,0 +1,19 @@
+-- Wire Traffic (every JSON-RPC frame written to or read from a transport)
+CREATE TABLE IF NOT EXISTS mcp_traffic (
+    id TEXT PRIMARY KEY NOT NULL,
+    server_id TEXT NOT NULL,
+    connection_id TEXT NOT NULL,  -- new for every connect
+    transport TEXT NOT NULL,  -- 'sse', 'streamable_http', 'websocket', 'stdio'
+    direction TEXT NOT NULL,  -- 'outbound', 'inbound'
+    kind TEXT NOT NULL,  -- 'request', 'response', 'error', 'notification', 'batch', 'invalid'
+    method TEXT,
+    correlation_id TEXT,  -- JSON-RPC id as JSON text
+    frame TEXT NOT NULL,  -- raw text as sent or received
+    size_bytes INTEGER NOT NULL,
+    created_at TEXT NOT NULL,  -- millisecond precision
+    FOREIGN KEY (server_id) REFERENCES mcp_servers(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_mcp_traffic_server_id ON mcp_traffic(server_id);
+CREATE INDEX IF NOT EXISTS idx_mcp_traffic_connection_id ON mcp_traffic(connection_id);
+CREATE INDEX IF NOT EXISTS idx_mcp_traffic_created_at ON mcp_traffic(created_at DESC);
@@ -0,0 +1,16 @@
+-- Imported Transcripts (traffic exported by another Studio)
+CREATE TABLE IF NOT EXISTS mcp_transcripts (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL,
+    server_name TEXT NOT NULL,
+    server_url TEXT NOT NULL,
+    transport TEXT NOT NULL,
+    connection_id TEXT NOT NULL,
+    frame_count INTEGER NOT NULL,
+    call_count INTEGER NOT NULL,
+    started_at TEXT,
+    content TEXT NOT NULL,  -- the transcript file as imported
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_mcp_transcripts_created_at ON mcp_transcripts(created_at DESC);
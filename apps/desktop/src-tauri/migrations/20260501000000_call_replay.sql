@@ -0,0 +1,4 @@
+-- Replayed tool calls point at the history entry they re-ran
+ALTER TABLE mcp_call_history ADD COLUMN replayed_from TEXT REFERENCES mcp_call_history(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_mcp_call_history_replayed_from ON mcp_call_history(replayed_from);
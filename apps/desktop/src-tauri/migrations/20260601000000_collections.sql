@@ -0,0 +1,41 @@
+-- Collections of saved tool calls
+CREATE TABLE IF NOT EXISTS mcp_collections (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL,
+    description TEXT,
+    position INTEGER NOT NULL DEFAULT 0,  -- lower comes first
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS mcp_collection_folders (
+    id TEXT PRIMARY KEY NOT NULL,
+    collection_id TEXT NOT NULL,
+    parent_id TEXT,  -- NULL = top level of the collection
+    name TEXT NOT NULL,
+    position INTEGER NOT NULL DEFAULT 0,  -- shared with the calls of the same parent
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (collection_id) REFERENCES mcp_collections(id) ON DELETE CASCADE,
+    FOREIGN KEY (parent_id) REFERENCES mcp_collection_folders(id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS mcp_saved_calls (
+    id TEXT PRIMARY KEY NOT NULL,
+    collection_id TEXT NOT NULL,
+    folder_id TEXT,  -- NULL = top level of the collection
+    name TEXT NOT NULL,
+    server_id TEXT,  -- NULL once the server is deleted
+    tool_name TEXT NOT NULL,
+    params TEXT,  -- JSON string
+    notes TEXT,
+    position INTEGER NOT NULL DEFAULT 0,  -- shared with the folders of the same parent
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (collection_id) REFERENCES mcp_collections(id) ON DELETE CASCADE,
+    FOREIGN KEY (folder_id) REFERENCES mcp_collection_folders(id) ON DELETE CASCADE,
+    FOREIGN KEY (server_id) REFERENCES mcp_servers(id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_mcp_collection_folders_collection_id ON mcp_collection_folders(collection_id);
+CREATE INDEX IF NOT EXISTS idx_mcp_saved_calls_collection_id ON mcp_saved_calls(collection_id);
@@ -0,0 +1,11 @@
+-- Environments of {{variables}} (the active one is the 'active_environment' system setting)
+CREATE TABLE IF NOT EXISTS mcp_environments (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL,
+    variables TEXT NOT NULL DEFAULT '[]',  -- JSON array of { key, value, secret }
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Tool call params as written, when they had placeholders (input_params holds the masked resolved values)
+ALTER TABLE mcp_call_history ADD COLUMN input_template TEXT;
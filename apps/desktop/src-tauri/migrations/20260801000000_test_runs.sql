@@ -0,0 +1,42 @@
+-- Assertions checked when a saved call runs in a test run
+ALTER TABLE mcp_saved_calls ADD COLUMN assertions TEXT NOT NULL DEFAULT '[]';  -- JSON array of { type, ... }
+
+-- Recorded test runs
+CREATE TABLE IF NOT EXISTS mcp_test_runs (
+    id TEXT PRIMARY KEY NOT NULL,
+    collection_id TEXT,  -- NULL once the collection is deleted
+    collection_name TEXT NOT NULL,
+    folder_id TEXT,  -- set when only one folder was run
+    server_id TEXT,  -- set when every call was sent to one server
+    started_at TEXT NOT NULL,
+    duration_ms INTEGER NOT NULL,
+    total INTEGER NOT NULL,
+    passed INTEGER NOT NULL,
+    failed INTEGER NOT NULL,
+    skipped INTEGER NOT NULL,
+    success BOOLEAN NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (collection_id) REFERENCES mcp_collections(id) ON DELETE SET NULL
+);
+
+CREATE TABLE IF NOT EXISTS mcp_test_results (
+    id TEXT PRIMARY KEY NOT NULL,
+    run_id TEXT NOT NULL,
+    saved_call_id TEXT,  -- NULL once the saved call is deleted
+    name TEXT NOT NULL,
+    folder_path TEXT NOT NULL DEFAULT '[]',  -- JSON array of folder names
+    server_id TEXT,
+    tool_name TEXT NOT NULL,
+    status TEXT NOT NULL,  -- 'success', 'error', 'cancelled', 'timeout' or 'skipped'
+    passed BOOLEAN NOT NULL,
+    error TEXT,
+    history_id TEXT,
+    duration_ms INTEGER NOT NULL,
+    assertions TEXT NOT NULL DEFAULT '[]',  -- JSON array of { assertion, passed, message }
+    position INTEGER NOT NULL,  -- order in the run
+    FOREIGN KEY (run_id) REFERENCES mcp_test_runs(id) ON DELETE CASCADE,
+    FOREIGN KEY (saved_call_id) REFERENCES mcp_saved_calls(id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_mcp_test_runs_collection_id ON mcp_test_runs(collection_id);
+CREATE INDEX IF NOT EXISTS idx_mcp_test_results_run_id ON mcp_test_results(run_id);
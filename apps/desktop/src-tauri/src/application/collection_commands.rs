@@ -0,0 +1,546 @@
+//! Collection Command Handlers - manages saved calls and their folders, and runs collections.
+
+use async_trait::async_trait;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::application::tool_call_runner::ToolCallRunner;
+use crate::domain::collection::{
+    CreateMcpCollectionCmd, CreateMcpCollectionFolderCmd, CreateMcpSavedCallCmd,
+    DeleteMcpCollectionCmd, DeleteMcpCollectionFolderCmd, DeleteMcpSavedCallCmd,
+    IMcpCollectionFolderRepository, IMcpCollectionRepository, IMcpSavedCallRepository,
+    McpCollection, McpCollectionFolder, McpCollectionRunReport, McpCollectionRunResult,
+    McpSavedCall, ReorderMcpCollectionItemsCmd, ReorderMcpCollectionsCmd, RunMcpCollectionCmd,
+    UpdateMcpCollectionCmd, UpdateMcpCollectionFolderCmd, UpdateMcpSavedCallCmd,
+};
+use crate::domain::cqrs::CommandHandler;
+use crate::domain::mcp::{IMcpCallHistoryRepository, IMcpServerRepository};
+use crate::error::AppError;
+use crate::infra::assertion;
+use crate::infra::clock::now_timestamp;
+use crate::infra::environment::EnvironmentResolver;
+use crate::infra::mcp_client::McpClientManager;
+
+/// Handles collection-related commands (write operations).
+pub struct CollectionCommandHandler {
+    collection_repo: Arc<dyn IMcpCollectionRepository>,
+    folder_repo: Arc<dyn IMcpCollectionFolderRepository>,
+    call_repo: Arc<dyn IMcpSavedCallRepository>,
+    server_repo: Arc<dyn IMcpServerRepository>,
+    tool_call_runner: ToolCallRunner,
+}
+
+impl CollectionCommandHandler {
+    pub fn new(
+        collection_repo: Arc<dyn IMcpCollectionRepository>,
+        folder_repo: Arc<dyn IMcpCollectionFolderRepository>,
+        call_repo: Arc<dyn IMcpSavedCallRepository>,
+        server_repo: Arc<dyn IMcpServerRepository>,
+        history_repo: Arc<dyn IMcpCallHistoryRepository>,
+        client_manager: Arc<McpClientManager>,
+        environment: Arc<EnvironmentResolver>,
+    ) -> Self {
+        Self {
+            collection_repo,
+            folder_repo,
+            call_repo,
+            server_repo,
+            tool_call_runner: ToolCallRunner::new(history_repo, client_manager, environment),
+        }
+    }
+
+    async fn find_collection(&self, id: &str) -> Result<McpCollection, AppError> {
+        self.collection_repo
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Collection {} not found", id)))
+    }
+
+    /// Check that a folder (if any) exists in the collection
+    async fn check_folder(
+        &self,
+        collection_id: &str,
+        folder_id: Option<&str>,
+    ) -> Result<(), AppError> {
+        let Some(folder_id) = folder_id else {
+            return Ok(());
+        };
+        match self.folder_repo.find_by_id(folder_id).await? {
+            Some(folder) if folder.collection_id == collection_id => Ok(()),
+            Some(_) => {
+                Err(AppError::Domain(format!("Folder {} belongs to another collection", folder_id)))
+            }
+            None => Err(AppError::NotFound(format!("Folder {} not found", folder_id))),
+        }
+    }
+
+    async fn check_server(&self, server_id: Option<&str>) -> Result<(), AppError> {
+        if let Some(server_id) = server_id {
+            if self.server_repo.find_by_id(server_id).await?.is_none() {
+                return Err(AppError::NotFound(format!("Server {} not found", server_id)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Position after the last folder or call of a parent
+    async fn next_position(
+        &self,
+        collection_id: &str,
+        parent_id: Option<&str>,
+    ) -> Result<i64, AppError> {
+        let folders = self.folder_repo.list(collection_id).await?;
+        let calls = self.call_repo.list(collection_id).await?;
+
+        let last = folders
+            .iter()
+            .filter(|f| f.parent_id.as_deref() == parent_id)
+            .map(|f| f.position)
+            .chain(calls.iter().filter(|c| c.folder_id.as_deref() == parent_id).map(|c| c.position))
+            .max();
+        Ok(last.map_or(0, |p| p + 1))
+    }
+}
+
+/// Saved params must be a JSON object (tools/call arguments)
+fn validate_params(params: &Option<serde_json::Value>) -> Result<(), AppError> {
+    match params {
+        None | Some(serde_json::Value::Object(_)) | Some(serde_json::Value::Null) => Ok(()),
+        Some(_) => Err(AppError::Domain("Tool parameters must be a JSON object".to_string())),
+    }
+}
+
+/// `ids` must list every one of `existing` exactly once
+fn validate_order(ids: &[String], existing: &HashSet<&str>) -> Result<(), AppError> {
+    let unique: HashSet<&str> = ids.iter().map(String::as_str).collect();
+    if unique.len() != ids.len() || unique != *existing {
+        return Err(AppError::Domain(
+            "The new order must list every item exactly once".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl CommandHandler<CreateMcpCollectionCmd, McpCollection> for CollectionCommandHandler {
+    async fn handle(&self, cmd: CreateMcpCollectionCmd) -> Result<McpCollection, AppError> {
+        info!(target: "collection", "Creating collection: {}", cmd.name);
+
+        let position = self.collection_repo.list().await?.iter().map(|c| c.position + 1).max();
+        let collection = McpCollection {
+            id: Uuid::new_v4().to_string(),
+            name: cmd.name,
+            description: cmd.description,
+            position: position.unwrap_or(0),
+            created_at: String::new(),
+            updated_at: String::new(),
+        };
+
+        self.collection_repo.create(collection).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<UpdateMcpCollectionCmd, McpCollection> for CollectionCommandHandler {
+    async fn handle(&self, cmd: UpdateMcpCollectionCmd) -> Result<McpCollection, AppError> {
+        info!(target: "collection", "Updating collection: {}", cmd.id);
+
+        let existing = self.find_collection(&cmd.id).await?;
+        let collection = McpCollection {
+            id: cmd.id,
+            name: cmd.name,
+            description: cmd.description,
+            position: existing.position,
+            created_at: existing.created_at,
+            updated_at: String::new(),
+        };
+
+        self.collection_repo.update(collection).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<DeleteMcpCollectionCmd, ()> for CollectionCommandHandler {
+    async fn handle(&self, cmd: DeleteMcpCollectionCmd) -> Result<(), AppError> {
+        info!(target: "collection", "Deleting collection: {}", cmd.id);
+        self.collection_repo.delete(&cmd.id).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<ReorderMcpCollectionsCmd, Vec<McpCollection>> for CollectionCommandHandler {
+    async fn handle(&self, cmd: ReorderMcpCollectionsCmd) -> Result<Vec<McpCollection>, AppError> {
+        let collections = self.collection_repo.list().await?;
+        validate_order(&cmd.ids, &collections.iter().map(|c| c.id.as_str()).collect())?;
+
+        self.collection_repo.reorder(&cmd.ids).await?;
+        self.collection_repo.list().await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<CreateMcpCollectionFolderCmd, McpCollectionFolder>
+    for CollectionCommandHandler
+{
+    async fn handle(
+        &self,
+        cmd: CreateMcpCollectionFolderCmd,
+    ) -> Result<McpCollectionFolder, AppError> {
+        info!(target: "collection", "Creating folder {} in collection {}", cmd.name, cmd.collection_id);
+
+        self.find_collection(&cmd.collection_id).await?;
+        self.check_folder(&cmd.collection_id, cmd.parent_id.as_deref()).await?;
+
+        let folder = McpCollectionFolder {
+            id: Uuid::new_v4().to_string(),
+            position: self.next_position(&cmd.collection_id, cmd.parent_id.as_deref()).await?,
+            collection_id: cmd.collection_id,
+            parent_id: cmd.parent_id,
+            name: cmd.name,
+            created_at: String::new(),
+            updated_at: String::new(),
+        };
+
+        self.folder_repo.create(folder).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<UpdateMcpCollectionFolderCmd, McpCollectionFolder>
+    for CollectionCommandHandler
+{
+    async fn handle(
+        &self,
+        cmd: UpdateMcpCollectionFolderCmd,
+    ) -> Result<McpCollectionFolder, AppError> {
+        info!(target: "collection", "Updating folder: {}", cmd.id);
+
+        let existing = self
+            .folder_repo
+            .find_by_id(&cmd.id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Folder {} not found", cmd.id)))?;
+
+        let position = if cmd.parent_id == existing.parent_id {
+            existing.position
+        } else {
+            self.check_folder(&existing.collection_id, cmd.parent_id.as_deref()).await?;
+
+            // A folder can't move into itself or one of its subfolders
+            let folders = self.folder_repo.list(&existing.collection_id).await?;
+            let mut ancestor = cmd.parent_id.as_deref();
+            while let Some(id) = ancestor {
+                if id == cmd.id {
+                    return Err(AppError::Domain(
+                        "A folder can't be moved into itself or its subfolders".to_string(),
+                    ));
+                }
+                ancestor = folders.iter().find(|f| f.id == id).and_then(|f| f.parent_id.as_deref());
+            }
+
+            self.next_position(&existing.collection_id, cmd.parent_id.as_deref()).await?
+        };
+
+        let folder = McpCollectionFolder {
+            id: cmd.id,
+            collection_id: existing.collection_id,
+            parent_id: cmd.parent_id,
+            name: cmd.name,
+            position,
+            created_at: existing.created_at,
+            updated_at: String::new(),
+        };
+
+        self.folder_repo.update(folder).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<DeleteMcpCollectionFolderCmd, ()> for CollectionCommandHandler {
+    async fn handle(&self, cmd: DeleteMcpCollectionFolderCmd) -> Result<(), AppError> {
+        info!(target: "collection", "Deleting folder: {}", cmd.id);
+        self.folder_repo.delete(&cmd.id).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<CreateMcpSavedCallCmd, McpSavedCall> for CollectionCommandHandler {
+    async fn handle(&self, cmd: CreateMcpSavedCallCmd) -> Result<McpSavedCall, AppError> {
+        info!(target: "collection", "Saving call {} in collection {}", cmd.name, cmd.collection_id);
+
+        validate_params(&cmd.params)?;
+        assertion::validate(&cmd.assertions)?;
+        self.find_collection(&cmd.collection_id).await?;
+        self.check_folder(&cmd.collection_id, cmd.folder_id.as_deref()).await?;
+        self.check_server(cmd.server_id.as_deref()).await?;
+
+        let call = McpSavedCall {
+            id: Uuid::new_v4().to_string(),
+            position: self.next_position(&cmd.collection_id, cmd.folder_id.as_deref()).await?,
+            collection_id: cmd.collection_id,
+            folder_id: cmd.folder_id,
+            name: cmd.name,
+            server_id: cmd.server_id,
+            tool_name: cmd.tool_name,
+            params: cmd.params.map(|p| p.to_string()),
+            notes: cmd.notes,
+            assertions: cmd.assertions,
+            created_at: String::new(),
+            updated_at: String::new(),
+        };
+
+        self.call_repo.create(call).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<UpdateMcpSavedCallCmd, McpSavedCall> for CollectionCommandHandler {
+    async fn handle(&self, cmd: UpdateMcpSavedCallCmd) -> Result<McpSavedCall, AppError> {
+        info!(target: "collection", "Updating saved call: {}", cmd.id);
+
+        validate_params(&cmd.params)?;
+        assertion::validate(&cmd.assertions)?;
+        let existing = self
+            .call_repo
+            .find_by_id(&cmd.id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Saved call {} not found", cmd.id)))?;
+        self.check_server(cmd.server_id.as_deref()).await?;
+
+        let position = if cmd.folder_id == existing.folder_id {
+            existing.position
+        } else {
+            self.check_folder(&existing.collection_id, cmd.folder_id.as_deref()).await?;
+            self.next_position(&existing.collection_id, cmd.folder_id.as_deref()).await?
+        };
+
+        let call = McpSavedCall {
+            id: cmd.id,
+            collection_id: existing.collection_id,
+            folder_id: cmd.folder_id,
+            name: cmd.name,
+            server_id: cmd.server_id,
+            tool_name: cmd.tool_name,
+            params: cmd.params.map(|p| p.to_string()),
+            notes: cmd.notes,
+            assertions: cmd.assertions,
+            position,
+            created_at: existing.created_at,
+            updated_at: String::new(),
+        };
+
+        self.call_repo.update(call).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<DeleteMcpSavedCallCmd, ()> for CollectionCommandHandler {
+    async fn handle(&self, cmd: DeleteMcpSavedCallCmd) -> Result<(), AppError> {
+        info!(target: "collection", "Deleting saved call: {}", cmd.id);
+        self.call_repo.delete(&cmd.id).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<ReorderMcpCollectionItemsCmd, ()> for CollectionCommandHandler {
+    async fn handle(&self, cmd: ReorderMcpCollectionItemsCmd) -> Result<(), AppError> {
+        self.find_collection(&cmd.collection_id).await?;
+        self.check_folder(&cmd.collection_id, cmd.folder_id.as_deref()).await?;
+
+        let parent_id = cmd.folder_id.as_deref();
+        let folders = self.folder_repo.list(&cmd.collection_id).await?;
+        let calls = self.call_repo.list(&cmd.collection_id).await?;
+        let folder_ids: HashSet<&str> = folders
+            .iter()
+            .filter(|f| f.parent_id.as_deref() == parent_id)
+            .map(|f| f.id.as_str())
+            .collect();
+        let call_ids =
+            calls.iter().filter(|c| c.folder_id.as_deref() == parent_id).map(|c| c.id.as_str());
+        validate_order(&cmd.item_ids, &folder_ids.iter().copied().chain(call_ids).collect())?;
+
+        let (folders, calls): (Vec<_>, Vec<_>) = cmd
+            .item_ids
+            .iter()
+            .enumerate()
+            .map(|(position, id)| (id.as_str(), position as i64))
+            .partition(|(id, _)| folder_ids.contains(id));
+        self.collection_repo.reorder_items(&folders, &calls).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<RunMcpCollectionCmd, McpCollectionRunReport> for CollectionCommandHandler {
+    async fn handle(&self, cmd: RunMcpCollectionCmd) -> Result<McpCollectionRunReport, AppError> {
+        let collection = self.find_collection(&cmd.collection_id).await?;
+        self.check_folder(&cmd.collection_id, cmd.folder_id.as_deref()).await?;
+
+        let folders = self.folder_repo.list(&collection.id).await?;
+        let calls = self.call_repo.list(&collection.id).await?;
+        let order = run_order(&folders, &calls, cmd.folder_id.as_deref());
+
+        info!(target: "collection", "Running {} calls of collection {}", order.len(), collection.name);
+
+        let started_at = now_timestamp();
+        let start = std::time::Instant::now();
+        let mut results = Vec::with_capacity(order.len());
+        let mut stopped = false;
+
+        for (call, folder_path) in order {
+            let server_id = cmd.server_id.clone().or_else(|| call.server_id.clone());
+            let result = if stopped {
+                skipped_call(call, folder_path, server_id)
+            } else {
+                run_saved_call(&self.tool_call_runner, call, folder_path, server_id, cmd.timeout_ms)
+                    .await
+            };
+
+            stopped = stopped || (cmd.stop_on_failure && !result.success);
+            results.push(result);
+        }
+
+        let passed = results.iter().filter(|r| r.success).count();
+        let skipped = results.iter().filter(|r| r.status == "skipped").count();
+        let failed = results.len() - passed - skipped;
+
+        Ok(McpCollectionRunReport {
+            collection_id: collection.id,
+            collection_name: collection.name,
+            folder_id: cmd.folder_id,
+            started_at,
+            duration_ms: start.elapsed().as_millis() as i64,
+            total: results.len(),
+            passed,
+            failed,
+            skipped,
+            success: failed == 0,
+            results,
+        })
+    }
+}
+
+/// Outcome of a saved call that was not called
+pub(crate) fn skipped_call(
+    call: &McpSavedCall,
+    folder_path: Vec<String>,
+    server_id: Option<String>,
+) -> McpCollectionRunResult {
+    McpCollectionRunResult {
+        saved_call_id: call.id.clone(),
+        name: call.name.clone(),
+        folder_path,
+        server_id,
+        tool_name: call.tool_name.clone(),
+        status: "skipped".to_string(),
+        success: false,
+        result: None,
+        error: None,
+        history_id: None,
+        duration_ms: 0,
+    }
+}
+
+/// Call a saved call on `server_id` (errors without a call when it has no server or its
+/// params are broken)
+pub(crate) async fn run_saved_call(
+    tool_call_runner: &ToolCallRunner,
+    call: &McpSavedCall,
+    folder_path: Vec<String>,
+    server_id: Option<String>,
+    timeout_ms: Option<u64>,
+) -> McpCollectionRunResult {
+    let mut result = skipped_call(call, folder_path, server_id.clone());
+    let params = call.params.as_deref().map(serde_json::from_str::<serde_json::Value>).transpose();
+
+    match (server_id, params) {
+        (None, _) => {
+            result.status = "error".to_string();
+            result.error = Some("Saved call has no server".to_string());
+        }
+        (_, Err(e)) => {
+            result.status = "error".to_string();
+            result.error = Some(format!("Saved parameters are not JSON: {}", e));
+        }
+        (Some(server_id), Ok(params)) => {
+            let (outcome, history) = tool_call_runner
+                .call(&server_id, &call.tool_name, params, None, timeout_ms, None)
+                .await;
+            result.history_id = Some(history.id);
+            result.duration_ms = history.duration_ms.unwrap_or(0);
+            match outcome {
+                Ok(r) => {
+                    result.status = r.status.clone();
+                    result.success = r.success;
+                    result.error = r.error.clone();
+                    result.result = Some(r);
+                }
+                Err(e) => {
+                    result.status = "error".to_string();
+                    result.error = Some(e.to_string());
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Calls in the order they are shown (depth first), each with its folder path;
+/// starts at `folder_id` when given
+pub(crate) fn run_order<'a>(
+    folders: &'a [McpCollectionFolder],
+    calls: &'a [McpSavedCall],
+    folder_id: Option<&str>,
+) -> Vec<(&'a McpSavedCall, Vec<String>)> {
+    // Names of the folder and its parents, from the top of the collection
+    let mut path = Vec::new();
+    let mut ancestor = folder_id;
+    while let Some(folder) = ancestor.and_then(|id| folders.iter().find(|f| f.id == id)) {
+        path.insert(0, folder.name.clone());
+        ancestor = folder.parent_id.as_deref();
+    }
+
+    let mut order = Vec::new();
+    visit(folders, calls, folder_id, &mut path, &mut order);
+    order
+}
+
+/// A folder or call of one parent
+enum Child<'a> {
+    Folder(&'a McpCollectionFolder),
+    Call(&'a McpSavedCall),
+}
+
+fn visit<'a>(
+    folders: &'a [McpCollectionFolder],
+    calls: &'a [McpSavedCall],
+    parent_id: Option<&str>,
+    path: &mut Vec<String>,
+    order: &mut Vec<(&'a McpSavedCall, Vec<String>)>,
+) {
+    // Folders and calls of one parent share the position order
+    let mut children: Vec<(i64, &str, Child)> = folders
+        .iter()
+        .filter(|f| f.parent_id.as_deref() == parent_id)
+        .map(|f| (f.position, f.created_at.as_str(), Child::Folder(f)))
+        .chain(
+            calls
+                .iter()
+                .filter(|c| c.folder_id.as_deref() == parent_id)
+                .map(|c| (c.position, c.created_at.as_str(), Child::Call(c))),
+        )
+        .collect();
+    children.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+
+    for (_, _, child) in children {
+        match child {
+            Child::Folder(folder) => {
+                path.push(folder.name.clone());
+                visit(folders, calls, Some(&folder.id), path, order);
+                path.pop();
+            }
+            Child::Call(call) => order.push((call, path.clone())),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Collection Query Handlers - handles all read operations for collections.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::domain::collection::{
+    GetMcpCollectionQuery, IMcpCollectionFolderRepository, IMcpCollectionRepository,
+    IMcpSavedCallRepository, ListMcpCollectionsQuery, McpCollection, McpCollectionDetail,
+};
+use crate::domain::cqrs::QueryHandler;
+use crate::error::AppError;
+
+/// Handles collection-related queries (read operations).
+pub struct CollectionQueryHandler {
+    collection_repo: Arc<dyn IMcpCollectionRepository>,
+    folder_repo: Arc<dyn IMcpCollectionFolderRepository>,
+    call_repo: Arc<dyn IMcpSavedCallRepository>,
+}
+
+impl CollectionQueryHandler {
+    pub fn new(
+        collection_repo: Arc<dyn IMcpCollectionRepository>,
+        folder_repo: Arc<dyn IMcpCollectionFolderRepository>,
+        call_repo: Arc<dyn IMcpSavedCallRepository>,
+    ) -> Self {
+        Self { collection_repo, folder_repo, call_repo }
+    }
+}
+
+#[async_trait]
+impl QueryHandler<ListMcpCollectionsQuery, Vec<McpCollection>> for CollectionQueryHandler {
+    async fn handle(
+        &self,
+        _query: ListMcpCollectionsQuery,
+    ) -> Result<Vec<McpCollection>, AppError> {
+        self.collection_repo.list().await
+    }
+}
+
+#[async_trait]
+impl QueryHandler<GetMcpCollectionQuery, McpCollectionDetail> for CollectionQueryHandler {
+    async fn handle(&self, query: GetMcpCollectionQuery) -> Result<McpCollectionDetail, AppError> {
+        let collection = self
+            .collection_repo
+            .find_by_id(&query.id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Collection {} not found", query.id)))?;
+
+        Ok(McpCollectionDetail {
+            folders: self.folder_repo.list(&collection.id).await?,
+            calls: self.call_repo.list(&collection.id).await?,
+            collection,
+        })
+    }
+}
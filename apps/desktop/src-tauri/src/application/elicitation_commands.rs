@@ -0,0 +1,100 @@
+//! Elicitation Command Handlers - handles rule management and answers to elicitation requests.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::domain::cqrs::CommandHandler;
+use crate::domain::elicitation::{
+    CreateElicitationRuleCmd, DeleteElicitationRuleCmd, ElicitationRule,
+    IElicitationRuleRepository, RespondElicitationRequestCmd, UpdateElicitationRuleCmd,
+};
+use crate::error::AppError;
+use crate::infra::elicitation_responder::ElicitationResponder;
+
+/// Handles elicitation-related commands (write operations).
+pub struct ElicitationCommandHandler {
+    rule_repo: Arc<dyn IElicitationRuleRepository>,
+    responder: Arc<ElicitationResponder>,
+}
+
+impl ElicitationCommandHandler {
+    pub fn new(
+        rule_repo: Arc<dyn IElicitationRuleRepository>,
+        responder: Arc<ElicitationResponder>,
+    ) -> Self {
+        Self { rule_repo, responder }
+    }
+}
+
+#[async_trait]
+impl CommandHandler<CreateElicitationRuleCmd, ElicitationRule> for ElicitationCommandHandler {
+    async fn handle(&self, cmd: CreateElicitationRuleCmd) -> Result<ElicitationRule, AppError> {
+        info!(target: "elicitation", "Creating elicitation rule: {}", cmd.name);
+
+        cmd.match_type.validate_pattern(&cmd.pattern)?;
+
+        let rule = ElicitationRule {
+            id: Uuid::new_v4().to_string(),
+            name: cmd.name,
+            server_id: cmd.server_id,
+            match_type: cmd.match_type,
+            pattern: cmd.pattern,
+            action: cmd.action,
+            content: cmd.content.map(|c| c.to_string()),
+            enabled: cmd.enabled.unwrap_or(true),
+            priority: cmd.priority.unwrap_or(0),
+            created_at: String::new(),
+            updated_at: String::new(),
+        };
+
+        self.rule_repo.create(rule).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<UpdateElicitationRuleCmd, ElicitationRule> for ElicitationCommandHandler {
+    async fn handle(&self, cmd: UpdateElicitationRuleCmd) -> Result<ElicitationRule, AppError> {
+        info!(target: "elicitation", "Updating elicitation rule: {}", cmd.id);
+
+        cmd.match_type.validate_pattern(&cmd.pattern)?;
+
+        let existing =
+            self.rule_repo.find_by_id(&cmd.id).await?.ok_or_else(|| {
+                AppError::NotFound(format!("Elicitation rule {} not found", cmd.id))
+            })?;
+
+        let rule = ElicitationRule {
+            id: cmd.id,
+            name: cmd.name,
+            server_id: cmd.server_id,
+            match_type: cmd.match_type,
+            pattern: cmd.pattern,
+            action: cmd.action,
+            content: cmd.content.map(|c| c.to_string()),
+            enabled: cmd.enabled,
+            priority: cmd.priority,
+            created_at: existing.created_at,
+            updated_at: String::new(),
+        };
+
+        self.rule_repo.update(rule).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<DeleteElicitationRuleCmd, ()> for ElicitationCommandHandler {
+    async fn handle(&self, cmd: DeleteElicitationRuleCmd) -> Result<(), AppError> {
+        info!(target: "elicitation", "Deleting elicitation rule: {}", cmd.id);
+        self.rule_repo.delete(&cmd.id).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<RespondElicitationRequestCmd, ()> for ElicitationCommandHandler {
+    async fn handle(&self, cmd: RespondElicitationRequestCmd) -> Result<(), AppError> {
+        info!(target: "elicitation", "Answering elicitation request {} with {}", cmd.request_id, cmd.action);
+        self.responder.respond(&cmd.request_id, cmd.action, cmd.content).await
+    }
+}
@@ -0,0 +1,49 @@
+//! Elicitation Query Handlers - handles all read operations for elicitation.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::domain::cqrs::QueryHandler;
+use crate::domain::elicitation::{
+    ElicitationRule, IElicitationRuleRepository, ListElicitationRulesQuery,
+    ListPendingElicitationRequestsQuery, PendingElicitationRequest,
+};
+use crate::error::AppError;
+use crate::infra::elicitation_responder::ElicitationResponder;
+
+/// Handles elicitation-related queries (read operations).
+pub struct ElicitationQueryHandler {
+    rule_repo: Arc<dyn IElicitationRuleRepository>,
+    responder: Arc<ElicitationResponder>,
+}
+
+impl ElicitationQueryHandler {
+    pub fn new(
+        rule_repo: Arc<dyn IElicitationRuleRepository>,
+        responder: Arc<ElicitationResponder>,
+    ) -> Self {
+        Self { rule_repo, responder }
+    }
+}
+
+#[async_trait]
+impl QueryHandler<ListElicitationRulesQuery, Vec<ElicitationRule>> for ElicitationQueryHandler {
+    async fn handle(
+        &self,
+        _query: ListElicitationRulesQuery,
+    ) -> Result<Vec<ElicitationRule>, AppError> {
+        self.rule_repo.list().await
+    }
+}
+
+#[async_trait]
+impl QueryHandler<ListPendingElicitationRequestsQuery, Vec<PendingElicitationRequest>>
+    for ElicitationQueryHandler
+{
+    async fn handle(
+        &self,
+        _query: ListPendingElicitationRequestsQuery,
+    ) -> Result<Vec<PendingElicitationRequest>, AppError> {
+        Ok(self.responder.pending_requests().await)
+    }
+}
@@ -0,0 +1,145 @@
+//! Environment Command Handlers - manages environments and which one is active.
+
+use async_trait::async_trait;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::domain::config::IConfigRepository;
+use crate::domain::cqrs::CommandHandler;
+use crate::domain::environment::{
+    CreateMcpEnvironmentCmd, DeleteMcpEnvironmentCmd, IMcpEnvironmentRepository, McpEnvironment,
+    McpEnvironmentVariable, SetActiveMcpEnvironmentCmd, UpdateMcpEnvironmentCmd,
+    ACTIVE_ENVIRONMENT_KEY,
+};
+use crate::domain::events::{DomainEvent, IEventPublisher};
+use crate::error::AppError;
+use crate::infra::environment::is_variable_name;
+
+/// Handles environment-related commands (write operations).
+pub struct EnvironmentCommandHandler {
+    environment_repo: Arc<dyn IMcpEnvironmentRepository>,
+    config_repo: Arc<dyn IConfigRepository>,
+    publisher: Arc<dyn IEventPublisher>,
+}
+
+impl EnvironmentCommandHandler {
+    pub fn new(
+        environment_repo: Arc<dyn IMcpEnvironmentRepository>,
+        config_repo: Arc<dyn IConfigRepository>,
+        publisher: Arc<dyn IEventPublisher>,
+    ) -> Self {
+        Self { environment_repo, config_repo, publisher }
+    }
+
+    /// Store the active environment id and tell the UI
+    async fn set_active(&self, id: Option<&str>) -> Result<(), AppError> {
+        let value = id.unwrap_or_default().to_string();
+        self.config_repo.set(ACTIVE_ENVIRONMENT_KEY, &value).await?;
+        self.publisher
+            .publish(DomainEvent::ConfigChanged { key: ACTIVE_ENVIRONMENT_KEY.to_string(), value });
+        Ok(())
+    }
+}
+
+/// Keys must be usable as `{{key}}` and unique within the environment
+fn validate_variables(variables: &[McpEnvironmentVariable]) -> Result<(), AppError> {
+    let mut keys = HashSet::new();
+    for variable in variables {
+        if !is_variable_name(&variable.key) {
+            return Err(AppError::Domain(format!(
+                "Invalid variable name '{}': use letters, digits, '_', '-' or '.'",
+                variable.key
+            )));
+        }
+        if !keys.insert(variable.key.as_str()) {
+            return Err(AppError::Domain(format!("Variable {} is defined twice", variable.key)));
+        }
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl CommandHandler<CreateMcpEnvironmentCmd, McpEnvironment> for EnvironmentCommandHandler {
+    async fn handle(&self, cmd: CreateMcpEnvironmentCmd) -> Result<McpEnvironment, AppError> {
+        info!(target: "environment", "Creating environment: {}", cmd.name);
+
+        validate_variables(&cmd.variables)?;
+
+        let environment = McpEnvironment {
+            id: Uuid::new_v4().to_string(),
+            name: cmd.name,
+            variables: cmd.variables,
+            created_at: String::new(),
+            updated_at: String::new(),
+        };
+
+        self.environment_repo.create(environment).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<UpdateMcpEnvironmentCmd, McpEnvironment> for EnvironmentCommandHandler {
+    async fn handle(&self, cmd: UpdateMcpEnvironmentCmd) -> Result<McpEnvironment, AppError> {
+        info!(target: "environment", "Updating environment: {}", cmd.id);
+
+        validate_variables(&cmd.variables)?;
+
+        let existing = self
+            .environment_repo
+            .find_by_id(&cmd.id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Environment {} not found", cmd.id)))?;
+
+        let environment = McpEnvironment {
+            id: cmd.id,
+            name: cmd.name,
+            variables: cmd.variables,
+            created_at: existing.created_at,
+            updated_at: String::new(),
+        };
+
+        self.environment_repo.update(environment).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<DeleteMcpEnvironmentCmd, ()> for EnvironmentCommandHandler {
+    async fn handle(&self, cmd: DeleteMcpEnvironmentCmd) -> Result<(), AppError> {
+        info!(target: "environment", "Deleting environment: {}", cmd.id);
+
+        self.environment_repo.delete(&cmd.id).await?;
+
+        // Deleting the active environment turns substitution off
+        if self.config_repo.get(ACTIVE_ENVIRONMENT_KEY).await?.as_deref() == Some(cmd.id.as_str()) {
+            self.set_active(None).await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CommandHandler<SetActiveMcpEnvironmentCmd, Option<McpEnvironment>>
+    for EnvironmentCommandHandler
+{
+    async fn handle(
+        &self,
+        cmd: SetActiveMcpEnvironmentCmd,
+    ) -> Result<Option<McpEnvironment>, AppError> {
+        info!(target: "environment", "Setting active environment: {:?}", cmd.id);
+
+        let environment = match &cmd.id {
+            Some(id) => Some(
+                self.environment_repo
+                    .find_by_id(id)
+                    .await?
+                    .ok_or_else(|| AppError::NotFound(format!("Environment {} not found", id)))?,
+            ),
+            None => None,
+        };
+
+        self.set_active(cmd.id.as_deref()).await?;
+        Ok(environment)
+    }
+}
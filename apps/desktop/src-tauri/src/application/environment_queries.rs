@@ -0,0 +1,52 @@
+//! Environment Query Handlers - handles all read operations for environments.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::domain::config::IConfigRepository;
+use crate::domain::cqrs::QueryHandler;
+use crate::domain::environment::{
+    GetActiveMcpEnvironmentQuery, IMcpEnvironmentRepository, ListMcpEnvironmentsQuery,
+    McpEnvironment, ACTIVE_ENVIRONMENT_KEY,
+};
+use crate::error::AppError;
+
+/// Handles environment-related queries (read operations).
+pub struct EnvironmentQueryHandler {
+    environment_repo: Arc<dyn IMcpEnvironmentRepository>,
+    config_repo: Arc<dyn IConfigRepository>,
+}
+
+impl EnvironmentQueryHandler {
+    pub fn new(
+        environment_repo: Arc<dyn IMcpEnvironmentRepository>,
+        config_repo: Arc<dyn IConfigRepository>,
+    ) -> Self {
+        Self { environment_repo, config_repo }
+    }
+}
+
+#[async_trait]
+impl QueryHandler<ListMcpEnvironmentsQuery, Vec<McpEnvironment>> for EnvironmentQueryHandler {
+    async fn handle(
+        &self,
+        _query: ListMcpEnvironmentsQuery,
+    ) -> Result<Vec<McpEnvironment>, AppError> {
+        self.environment_repo.list().await
+    }
+}
+
+#[async_trait]
+impl QueryHandler<GetActiveMcpEnvironmentQuery, Option<McpEnvironment>>
+    for EnvironmentQueryHandler
+{
+    async fn handle(
+        &self,
+        _query: GetActiveMcpEnvironmentQuery,
+    ) -> Result<Option<McpEnvironment>, AppError> {
+        match self.config_repo.get(ACTIVE_ENVIRONMENT_KEY).await?.filter(|id| !id.is_empty()) {
+            Some(id) => self.environment_repo.find_by_id(&id).await,
+            None => Ok(None),
+        }
+    }
+}
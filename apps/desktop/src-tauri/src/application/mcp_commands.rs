@@ -2,37 +2,71 @@
 
 use async_trait::async_trait;
 use std::sync::Arc;
-use tracing::info;
+use std::time::Duration;
+use tracing::{info, warn};
 use uuid::Uuid;
 
+use crate::application::tool_call_runner::ToolCallRunner;
 use crate::domain::cqrs::CommandHandler;
 use crate::domain::mcp::{
-    CallMcpToolCmd, ConnectMcpServerCmd, CreateMcpServerCmd, DeleteHttpReceivedMessageCmd,
-    DeleteMcpServerCmd, DisconnectMcpServerCmd, HttpReceivedMessage,
-    IHttpReceivedMessageRepository, IMcpCallHistoryRepository, IMcpServerRepository,
-    MarkMcpServerDisconnectedCmd, McpCallHistory, McpServer, McpServerStatus, McpTool,
-    McpToolCallResult, McpToolsListResult, RefreshMcpToolsCmd, SaveHttpReceivedMessageCmd,
-    UpdateMcpServerCmd,
+    CallMcpToolCmd, CancelMcpToolCallCmd, ConnectMcpServerCmd, CreateMcpServerCmd,
+    DeleteHttpReceivedMessageCmd, DeleteMcpServerCmd, DisconnectMcpServerCmd, GetMcpPromptCmd,
+    HttpReceivedMessage, IHttpReceivedMessageRepository, IMcpCallHistoryRepository,
+    IMcpPromptHistoryRepository, IMcpRawRpcHistoryRepository, IMcpServerRepository,
+    MarkMcpServerDisconnectedCmd, McpCallReplay, McpPrompt, McpPromptGetResult, McpPromptHistory,
+    McpPromptsListResult, McpRawRpcHistory, McpReconnectPolicy, McpResource, McpResourceReadResult,
+    McpResourceTemplate, McpResourcesListResult, McpRoot, McpServer, McpServerStatus,
+    McpSessionTermination, McpTool, McpToolCallResult, McpToolsListResult, ReadMcpResourceCmd,
+    RefreshMcpPromptsCmd, RefreshMcpResourcesCmd, RefreshMcpToolsCmd, ReplayMcpCallCmd,
+    SaveHttpReceivedMessageCmd, SendMcpRawRpcCmd, SetMcpServerHeartbeatCmd,
+    SetMcpServerReconnectPolicyCmd, SetMcpServerRootsCmd, SubscribeMcpResourceCmd,
+    TerminateMcpSessionCmd, UnsubscribeMcpResourceCmd, UpdateMcpServerCmd,
 };
 use crate::error::AppError;
-use crate::infra::mcp_client::McpClientManager;
+use crate::infra::environment::EnvironmentResolver;
+use crate::infra::json_diff;
+use crate::infra::mcp_client::{request_headers, McpClientManager};
+use crate::infra::raw_rpc::{RawRpcMessage, RawRpcReply};
+
+/// Wait for the reply to a raw JSON-RPC message when the caller doesn't give a timeout
+const DEFAULT_RAW_RPC_TIMEOUT_MS: u64 = 30_000;
 
 /// Handles MCP server-related commands (write operations).
 pub struct McpCommandHandler {
     server_repo: Arc<dyn IMcpServerRepository>,
     history_repo: Arc<dyn IMcpCallHistoryRepository>,
+    prompt_history_repo: Arc<dyn IMcpPromptHistoryRepository>,
+    raw_rpc_history_repo: Arc<dyn IMcpRawRpcHistoryRepository>,
     message_repo: Arc<dyn IHttpReceivedMessageRepository>,
     client_manager: Arc<McpClientManager>,
+    environment: Arc<EnvironmentResolver>,
+    tool_call_runner: ToolCallRunner,
 }
 
 impl McpCommandHandler {
     pub fn new(
         server_repo: Arc<dyn IMcpServerRepository>,
         history_repo: Arc<dyn IMcpCallHistoryRepository>,
+        prompt_history_repo: Arc<dyn IMcpPromptHistoryRepository>,
+        raw_rpc_history_repo: Arc<dyn IMcpRawRpcHistoryRepository>,
         message_repo: Arc<dyn IHttpReceivedMessageRepository>,
         client_manager: Arc<McpClientManager>,
+        environment: Arc<EnvironmentResolver>,
     ) -> Self {
-        Self { server_repo, history_repo, message_repo, client_manager }
+        Self {
+            server_repo,
+            tool_call_runner: ToolCallRunner::new(
+                history_repo.clone(),
+                client_manager.clone(),
+                environment.clone(),
+            ),
+            environment,
+            history_repo,
+            prompt_history_repo,
+            raw_rpc_history_repo,
+            message_repo,
+            client_manager,
+        }
     }
 }
 
@@ -41,11 +75,29 @@ impl CommandHandler<CreateMcpServerCmd, McpServer> for McpCommandHandler {
     async fn handle(&self, cmd: CreateMcpServerCmd) -> Result<McpServer, AppError> {
         info!(target: "mcp", "Creating MCP server: {} at {}", cmd.name, cmd.url);
 
+        validate_roots(&cmd.roots)?;
+        validate_heartbeat(cmd.heartbeat_interval_secs, cmd.heartbeat_max_failures)?;
+        validate_reconnect_policy(&cmd.reconnect_policy)?;
+        request_headers(&cmd.headers, &cmd.auth)?;
+
         let server = McpServer {
             id: Uuid::new_v4().to_string(),
             name: cmd.name,
             url: cmd.url,
             server_type: cmd.server_type,
+            command: cmd.command,
+            args: cmd.args,
+            env: cmd.env,
+            cwd: cmd.cwd,
+            roots: cmd.roots,
+            headers: cmd.headers,
+            auth: cmd.auth,
+            heartbeat_strategy: cmd.heartbeat_strategy,
+            heartbeat_interval_secs: cmd.heartbeat_interval_secs,
+            heartbeat_max_failures: cmd.heartbeat_max_failures,
+            reconnect_policy: cmd.reconnect_policy,
+            standalone_sse: cmd.standalone_sse,
+            sse_reconnect: cmd.sse_reconnect,
             status: McpServerStatus::Disconnected,
             last_error: None,
             created_at: String::new(),
@@ -72,13 +124,136 @@ impl CommandHandler<UpdateMcpServerCmd, McpServer> for McpCommandHandler {
             name: cmd.name,
             url: cmd.url,
             server_type: cmd.server_type,
+            command: cmd.command,
+            args: cmd.args,
+            env: cmd.env,
+            cwd: cmd.cwd,
+            roots: cmd.roots.unwrap_or(existing.roots),
+            headers: cmd.headers.unwrap_or(existing.headers),
+            auth: cmd.auth.unwrap_or(existing.auth),
+            heartbeat_strategy: existing.heartbeat_strategy,
+            heartbeat_interval_secs: existing.heartbeat_interval_secs,
+            heartbeat_max_failures: existing.heartbeat_max_failures,
+            reconnect_policy: existing.reconnect_policy,
+            standalone_sse: cmd.standalone_sse.unwrap_or(existing.standalone_sse),
+            sse_reconnect: cmd.sse_reconnect.unwrap_or(existing.sse_reconnect),
             status: existing.status,
             last_error: existing.last_error,
             created_at: existing.created_at,
             updated_at: String::new(),
         };
 
-        self.server_repo.update(server).await
+        validate_roots(&server.roots)?;
+        // Headers and auth take effect on the next connect
+        request_headers(&server.headers, &server.auth)?;
+        let server = self.server_repo.update(server).await?;
+
+        // Live connections are told about the new roots
+        self.client_manager.update_roots(&server.id, server.roots.clone()).await;
+
+        Ok(server)
+    }
+}
+
+#[async_trait]
+impl CommandHandler<SetMcpServerRootsCmd, McpServer> for McpCommandHandler {
+    async fn handle(&self, cmd: SetMcpServerRootsCmd) -> Result<McpServer, AppError> {
+        info!(target: "mcp", "Setting {} roots for MCP server: {}", cmd.roots.len(), cmd.server_id);
+
+        validate_roots(&cmd.roots)?;
+
+        let mut server =
+            self.server_repo.find_by_id(&cmd.server_id).await?.ok_or_else(|| {
+                AppError::NotFound(format!("MCP server {} not found", cmd.server_id))
+            })?;
+        server.roots = cmd.roots;
+
+        let server = self.server_repo.update(server).await?;
+        self.client_manager.update_roots(&server.id, server.roots.clone()).await;
+
+        Ok(server)
+    }
+}
+
+#[async_trait]
+impl CommandHandler<SetMcpServerHeartbeatCmd, McpServer> for McpCommandHandler {
+    async fn handle(&self, cmd: SetMcpServerHeartbeatCmd) -> Result<McpServer, AppError> {
+        info!(target: "mcp", "Setting {} heartbeat for MCP server: {}", cmd.strategy, cmd.server_id);
+
+        validate_heartbeat(cmd.interval_secs, cmd.max_failures)?;
+
+        let mut server =
+            self.server_repo.find_by_id(&cmd.server_id).await?.ok_or_else(|| {
+                AppError::NotFound(format!("MCP server {} not found", cmd.server_id))
+            })?;
+        server.heartbeat_strategy = cmd.strategy;
+        server.heartbeat_interval_secs = cmd.interval_secs;
+        server.heartbeat_max_failures = cmd.max_failures;
+
+        let server = self.server_repo.update(server).await?;
+        self.client_manager.update_heartbeat(&server).await;
+
+        Ok(server)
+    }
+}
+
+#[async_trait]
+impl CommandHandler<SetMcpServerReconnectPolicyCmd, McpServer> for McpCommandHandler {
+    async fn handle(&self, cmd: SetMcpServerReconnectPolicyCmd) -> Result<McpServer, AppError> {
+        info!(target: "mcp", "Setting reconnect policy for MCP server: {} (enabled: {})", cmd.server_id, cmd.policy.enabled);
+
+        validate_reconnect_policy(&cmd.policy)?;
+
+        let mut server =
+            self.server_repo.find_by_id(&cmd.server_id).await?.ok_or_else(|| {
+                AppError::NotFound(format!("MCP server {} not found", cmd.server_id))
+            })?;
+        server.reconnect_policy = cmd.policy;
+
+        let server = self.server_repo.update(server).await?;
+        self.client_manager.update_reconnect_policy(&server).await;
+
+        Ok(server)
+    }
+}
+
+/// Reconnect attempts and delays must be positive, with the cap not below the first delay
+fn validate_reconnect_policy(policy: &McpReconnectPolicy) -> Result<(), AppError> {
+    if policy.max_attempts < 1 {
+        return Err(AppError::Domain("Reconnect attempts must be at least 1".to_string()));
+    }
+    if policy.initial_delay_ms < 1 {
+        return Err(AppError::Domain("Reconnect delay must be at least 1 ms".to_string()));
+    }
+    if policy.max_delay_ms < policy.initial_delay_ms {
+        return Err(AppError::Domain(
+            "Maximum reconnect delay must not be below the initial delay".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Heartbeat interval and failure threshold must be at least 1 when set
+fn validate_heartbeat(
+    interval_secs: Option<i64>,
+    max_failures: Option<i64>,
+) -> Result<(), AppError> {
+    if interval_secs.is_some_and(|secs| secs < 1) {
+        return Err(AppError::Domain("Heartbeat interval must be at least 1 second".to_string()));
+    }
+    if max_failures.is_some_and(|n| n < 1) {
+        return Err(AppError::Domain("Heartbeat failure threshold must be at least 1".to_string()));
+    }
+    Ok(())
+}
+
+/// Roots must be `file://` URIs per the MCP spec
+fn validate_roots(roots: &[McpRoot]) -> Result<(), AppError> {
+    match roots.iter().find(|r| !r.uri.starts_with("file://")) {
+        Some(root) => {
+            Err(AppError::Domain(format!("Root URI must start with file://: {}", root.uri)))
+        }
+        None => Ok(()),
     }
 }
 
@@ -106,9 +281,11 @@ impl CommandHandler<ConnectMcpServerCmd, McpServer> for McpCommandHandler {
             .await?
             .ok_or_else(|| AppError::NotFound(format!("MCP server {} not found", cmd.id)))?;
 
-        // Try to connect (status is tracked in McpClientManager, not DB)
-        let server_type_str = server.server_type.to_string();
-        self.client_manager.connect(&server.id, &server.url, &server_type_str).await?;
+        // Try to connect (status is tracked in McpClientManager, not DB); placeholders in
+        // the URL, headers and credentials take the values of the active environment
+        let variables = self.environment.variables().await?;
+        let resolved = variables.resolve_server(&server)?;
+        self.client_manager.connect(&resolved, variables).await?;
 
         // Auto-refresh tools after successful connection
         info!(target: "mcp", "Auto-refreshing tools for server: {}", cmd.id);
@@ -145,6 +322,15 @@ impl CommandHandler<DisconnectMcpServerCmd, McpServer> for McpCommandHandler {
     }
 }
 
+#[async_trait]
+impl CommandHandler<TerminateMcpSessionCmd, McpSessionTermination> for McpCommandHandler {
+    async fn handle(&self, cmd: TerminateMcpSessionCmd) -> Result<McpSessionTermination, AppError> {
+        info!(target: "mcp", "Terminating session of MCP server: {}", cmd.server_id);
+
+        self.client_manager.terminate_session(&cmd.server_id).await
+    }
+}
+
 #[async_trait]
 impl CommandHandler<MarkMcpServerDisconnectedCmd, McpServer> for McpCommandHandler {
     async fn handle(&self, cmd: MarkMcpServerDisconnectedCmd) -> Result<McpServer, AppError> {
@@ -184,38 +370,165 @@ impl CommandHandler<RefreshMcpToolsCmd, McpToolsListResult> for McpCommandHandle
             })
             .collect();
 
-        Ok(McpToolsListResult { tools, raw_response: result.raw_response })
+        Ok(McpToolsListResult { tools, raw_response: result.raw_response, paging: result.paging })
     }
 }
 
 #[async_trait]
-impl CommandHandler<CallMcpToolCmd, McpToolCallResult> for McpCommandHandler {
-    async fn handle(&self, cmd: CallMcpToolCmd) -> Result<McpToolCallResult, AppError> {
-        info!(target: "mcp", "Calling tool {} on server {}", cmd.tool_name, cmd.server_id);
+impl CommandHandler<RefreshMcpResourcesCmd, McpResourcesListResult> for McpCommandHandler {
+    async fn handle(
+        &self,
+        cmd: RefreshMcpResourcesCmd,
+    ) -> Result<McpResourcesListResult, AppError> {
+        info!(target: "mcp", "Refreshing resources for MCP server: {}", cmd.server_id);
+
+        // list_resources / list_resource_templates cache the results in memory
+        let result = self.client_manager.list_resources(&cmd.server_id).await?;
+
+        // Templates are optional for servers; a failure here shouldn't hide the resources
+        let templates_result = match self
+            .client_manager
+            .list_resource_templates(&cmd.server_id)
+            .await
+        {
+            Ok(r) => Some(r),
+            Err(e) => {
+                warn!(target: "mcp", "Failed to list resource templates for {}: {}", cmd.server_id, e);
+                None
+            }
+        };
+
+        let resources: Vec<McpResource> = result
+            .resources
+            .iter()
+            .map(|r| McpResource {
+                server_id: cmd.server_id.clone(),
+                uri: r.uri.clone(),
+                name: r.name.clone(),
+                title: r.title.clone(),
+                description: r.description.clone(),
+                mime_type: r.mime_type.clone(),
+                size: r.size,
+                extra: r.extra.clone(),
+            })
+            .collect();
+
+        let (resource_templates, templates_raw_response, templates_paging) = match templates_result
+        {
+            Some(t) => {
+                let templates: Vec<McpResourceTemplate> = t
+                    .resource_templates
+                    .iter()
+                    .map(|t| McpResourceTemplate {
+                        server_id: cmd.server_id.clone(),
+                        uri_template: t.uri_template.clone(),
+                        name: t.name.clone(),
+                        title: t.title.clone(),
+                        description: t.description.clone(),
+                        mime_type: t.mime_type.clone(),
+                        extra: t.extra.clone(),
+                    })
+                    .collect();
+                (templates, Some(t.raw_response), Some(t.paging))
+            }
+            None => (Vec::new(), None, None),
+        };
+
+        Ok(McpResourcesListResult {
+            resources,
+            resource_templates,
+            raw_response: result.raw_response,
+            templates_raw_response,
+            paging: result.paging,
+            templates_paging,
+        })
+    }
+}
+
+#[async_trait]
+impl CommandHandler<ReadMcpResourceCmd, McpResourceReadResult> for McpCommandHandler {
+    async fn handle(&self, cmd: ReadMcpResourceCmd) -> Result<McpResourceReadResult, AppError> {
+        info!(target: "mcp", "Reading resource {} on server {}", cmd.uri, cmd.server_id);
+        self.client_manager.read_resource(&cmd.server_id, &cmd.uri).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<SubscribeMcpResourceCmd, ()> for McpCommandHandler {
+    async fn handle(&self, cmd: SubscribeMcpResourceCmd) -> Result<(), AppError> {
+        info!(target: "mcp", "Subscribing to resource {} on server {}", cmd.uri, cmd.server_id);
+        self.client_manager.subscribe_resource(&cmd.server_id, &cmd.uri).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<UnsubscribeMcpResourceCmd, ()> for McpCommandHandler {
+    async fn handle(&self, cmd: UnsubscribeMcpResourceCmd) -> Result<(), AppError> {
+        info!(target: "mcp", "Unsubscribing from resource {} on server {}", cmd.uri, cmd.server_id);
+        self.client_manager.unsubscribe_resource(&cmd.server_id, &cmd.uri).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<RefreshMcpPromptsCmd, McpPromptsListResult> for McpCommandHandler {
+    async fn handle(&self, cmd: RefreshMcpPromptsCmd) -> Result<McpPromptsListResult, AppError> {
+        info!(target: "mcp", "Refreshing prompts for MCP server: {}", cmd.server_id);
+
+        // list_prompts will automatically cache the prompts in memory
+        let result = self.client_manager.list_prompts(&cmd.server_id).await?;
+
+        let prompts: Vec<McpPrompt> = result
+            .prompts
+            .iter()
+            .map(|p| McpPrompt {
+                server_id: cmd.server_id.clone(),
+                name: p.name.clone(),
+                title: p.title.clone(),
+                description: p.description.clone(),
+                arguments: p.arguments.clone(),
+                extra: p.extra.clone(),
+            })
+            .collect();
+
+        Ok(McpPromptsListResult {
+            prompts,
+            raw_response: result.raw_response,
+            paging: result.paging,
+        })
+    }
+}
+
+#[async_trait]
+impl CommandHandler<GetMcpPromptCmd, McpPromptGetResult> for McpCommandHandler {
+    async fn handle(&self, cmd: GetMcpPromptCmd) -> Result<McpPromptGetResult, AppError> {
+        info!(target: "mcp", "Getting prompt {} on server {}", cmd.prompt_name, cmd.server_id);
 
         let start = std::time::Instant::now();
-        let result =
-            self.client_manager.call_tool(&cmd.server_id, &cmd.tool_name, cmd.params.clone()).await;
+        let result = self
+            .client_manager
+            .get_prompt(&cmd.server_id, &cmd.prompt_name, cmd.arguments.clone())
+            .await;
         let duration_ms = start.elapsed().as_millis() as i64;
 
         // Save to history
+        let arguments = cmd.arguments.map(|a| serde_json::to_string(&a).unwrap_or_default());
         let history = match &result {
-            Ok(r) => McpCallHistory {
+            Ok(r) => McpPromptHistory {
                 id: Uuid::new_v4().to_string(),
                 server_id: cmd.server_id.clone(),
-                tool_name: cmd.tool_name.clone(),
-                input_params: cmd.params.map(|p| serde_json::to_string(&p).unwrap_or_default()),
+                prompt_name: cmd.prompt_name.clone(),
+                arguments,
                 output_result: Some(r.raw_response.clone()),
-                status: "success".to_string(),
-                error_message: None,
-                duration_ms: Some(duration_ms),
+                status: if r.success { "success" } else { "error" }.to_string(),
+                error_message: r.error.clone(),
+                duration_ms: Some(r.duration_ms),
                 created_at: String::new(),
             },
-            Err(e) => McpCallHistory {
+            Err(e) => McpPromptHistory {
                 id: Uuid::new_v4().to_string(),
                 server_id: cmd.server_id.clone(),
-                tool_name: cmd.tool_name.clone(),
-                input_params: cmd.params.map(|p| serde_json::to_string(&p).unwrap_or_default()),
+                prompt_name: cmd.prompt_name.clone(),
+                arguments,
                 output_result: None,
                 status: "error".to_string(),
                 error_message: Some(e.to_string()),
@@ -224,12 +537,156 @@ impl CommandHandler<CallMcpToolCmd, McpToolCallResult> for McpCommandHandler {
             },
         };
 
-        let _ = self.history_repo.create(history).await;
+        if let Err(e) = self.prompt_history_repo.create(history).await {
+            warn!(target: "mcp", "Failed to save prompt history: {}", e);
+        }
+
+        result
+    }
+}
+
+#[async_trait]
+impl CommandHandler<SendMcpRawRpcCmd, McpRawRpcHistory> for McpCommandHandler {
+    async fn handle(&self, cmd: SendMcpRawRpcCmd) -> Result<McpRawRpcHistory, AppError> {
+        let message = RawRpcMessage::inspect(&cmd.message);
+        info!(target: "mcp", "Sending raw {} {:?} to server {}", message.kind, message.method, cmd.server_id);
+
+        let timeout = Duration::from_millis(cmd.timeout_ms.unwrap_or(DEFAULT_RAW_RPC_TIMEOUT_MS));
+
+        let start = std::time::Instant::now();
+        let result = self.client_manager.send_raw_rpc(&cmd.server_id, &cmd.message, timeout).await;
+        let duration_ms = start.elapsed().as_millis() as i64;
+
+        // Save to history
+        let (response, status, error_message) = match &result {
+            Ok(reply) => {
+                let (status, error_message) = raw_rpc_status(reply);
+                (reply.body.clone(), status, error_message)
+            }
+            Err(e) => (None, "error", Some(e.to_string())),
+        };
+        let history = McpRawRpcHistory {
+            id: Uuid::new_v4().to_string(),
+            server_id: cmd.server_id,
+            kind: message.kind.to_string(),
+            method: message.method,
+            request: cmd.message,
+            response,
+            status: status.to_string(),
+            error_message,
+            duration_ms: Some(duration_ms),
+            created_at: String::new(),
+        };
+
+        if let Err(e) = self.raw_rpc_history_repo.create(history.clone()).await {
+            warn!(target: "mcp", "Failed to save raw JSON-RPC history: {}", e);
+        }
+
+        result.map(|_| history)
+    }
+}
+
+/// Status and error of a raw exchange; a JSON-RPC error reply counts as an error
+fn raw_rpc_status(reply: &RawRpcReply) -> (&'static str, Option<String>) {
+    if reply.timed_out {
+        return ("timeout", reply.error.clone());
+    }
+    if reply.error.is_some() {
+        return ("error", reply.error.clone());
+    }
+
+    let Some(body) = &reply.body else {
+        return ("sent", None);
+    };
+    match serde_json::from_str::<serde_json::Value>(body).ok().and_then(|v| v.get("error").cloned())
+    {
+        Some(error) => (
+            "error",
+            Some(
+                error
+                    .get("message")
+                    .and_then(|m| m.as_str())
+                    .map_or_else(|| error.to_string(), str::to_string),
+            ),
+        ),
+        None => ("success", None),
+    }
+}
+
+#[async_trait]
+impl CommandHandler<CallMcpToolCmd, McpToolCallResult> for McpCommandHandler {
+    async fn handle(&self, cmd: CallMcpToolCmd) -> Result<McpToolCallResult, AppError> {
+        info!(target: "mcp", "Calling tool {} on server {}", cmd.tool_name, cmd.server_id);
 
+        let (result, _) = self
+            .tool_call_runner
+            .call(&cmd.server_id, &cmd.tool_name, cmd.params, cmd.call_id, cmd.timeout_ms, None)
+            .await;
         result
     }
 }
 
+#[async_trait]
+impl CommandHandler<ReplayMcpCallCmd, McpCallReplay> for McpCommandHandler {
+    async fn handle(&self, cmd: ReplayMcpCallCmd) -> Result<McpCallReplay, AppError> {
+        let original = self.history_repo.find_by_id(&cmd.history_id).await?.ok_or_else(|| {
+            AppError::NotFound(format!("Call history entry {} not found", cmd.history_id))
+        })?;
+        let server_id = cmd.server_id.unwrap_or_else(|| original.server_id.clone());
+
+        info!(target: "mcp", "Replaying call {} ({}) on server {}", original.id, original.tool_name, server_id);
+
+        // Params as written, so placeholders (and masked secrets) resolve again
+        let params = original
+            .input_template
+            .as_deref()
+            .or(original.input_params.as_deref())
+            .map(serde_json::from_str::<serde_json::Value>)
+            .transpose()
+            .map_err(|e| AppError::Domain(format!("Stored call parameters are not JSON: {}", e)))?;
+
+        let call_id = Uuid::new_v4().to_string();
+        let (result, replay) = self
+            .tool_call_runner
+            .call(
+                &server_id,
+                &original.tool_name,
+                params,
+                Some(call_id.clone()),
+                cmd.timeout_ms,
+                Some(original.id.clone()),
+            )
+            .await;
+
+        // A replay that never reached the server (not connected, undefined variable) is still
+        // in history; it is compared as an error result
+        let result = result.unwrap_or_else(|e| {
+            warn!(target: "mcp", "Replay of call {} failed: {}", original.id, e);
+            let error = replay.error_message.clone().unwrap_or_else(|| e.to_string());
+            McpToolCallResult {
+                call_id,
+                status: "error".to_string(),
+                success: false,
+                raw_response: serde_json::json!({ "error": error }).to_string(),
+                result: None,
+                error: Some(error),
+                duration_ms: replay.duration_ms.unwrap_or_default(),
+            }
+        });
+
+        let diff = json_diff::call_diff(&original, &replay);
+        Ok(McpCallReplay { original, replay, result, diff })
+    }
+}
+
+#[async_trait]
+impl CommandHandler<CancelMcpToolCallCmd, ()> for McpCommandHandler {
+    async fn handle(&self, cmd: CancelMcpToolCallCmd) -> Result<(), AppError> {
+        info!(target: "mcp", "Cancelling tool call {}", cmd.call_id);
+        self.client_manager.cancel_tool_call(&cmd.call_id).await
+    }
+}
+
 #[async_trait]
 impl CommandHandler<SaveHttpReceivedMessageCmd, HttpReceivedMessage> for McpCommandHandler {
     async fn handle(
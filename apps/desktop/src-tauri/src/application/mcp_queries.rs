@@ -5,10 +5,15 @@ use std::sync::Arc;
 
 use crate::domain::cqrs::QueryHandler;
 use crate::domain::mcp::{
-    GetMcpCallHistoryQuery, GetMcpServerQuery, GetMcpToolsQuery, HttpReceivedMessage,
-    IHttpReceivedMessageRepository, IMcpCallHistoryRepository, IMcpServerRepository,
-    ListHttpReceivedMessagesQuery, ListMcpServersQuery, McpCallHistory, McpServer, McpServerStatus,
-    McpTool,
+    GetMcpCallHistoryQuery, GetMcpConnectionInfoQuery, GetMcpHeartbeatsQuery,
+    GetMcpPromptHistoryQuery, GetMcpPromptsQuery, GetMcpRawRpcHistoryQuery,
+    GetMcpResourceSubscriptionsQuery, GetMcpResourceTemplatesQuery, GetMcpResourcesQuery,
+    GetMcpServerQuery, GetMcpToolsQuery, HttpReceivedMessage, IHttpReceivedMessageRepository,
+    IMcpCallHistoryRepository, IMcpHeartbeatRepository, IMcpPromptHistoryRepository,
+    IMcpRawRpcHistoryRepository, IMcpServerRepository, ListHttpReceivedMessagesQuery,
+    ListMcpServersQuery, McpCallHistory, McpConnectionInfo, McpHeartbeat, McpPrompt,
+    McpPromptHistory, McpRawRpcHistory, McpResource, McpResourceTemplate, McpServer,
+    McpServerStatus, McpTool,
 };
 use crate::error::AppError;
 use crate::infra::mcp_client::McpClientManager;
@@ -18,7 +23,10 @@ use uuid::Uuid;
 pub struct McpQueryHandler {
     server_repo: Arc<dyn IMcpServerRepository>,
     history_repo: Arc<dyn IMcpCallHistoryRepository>,
+    prompt_history_repo: Arc<dyn IMcpPromptHistoryRepository>,
+    raw_rpc_history_repo: Arc<dyn IMcpRawRpcHistoryRepository>,
     message_repo: Arc<dyn IHttpReceivedMessageRepository>,
+    heartbeat_repo: Arc<dyn IMcpHeartbeatRepository>,
     client_manager: Arc<McpClientManager>,
 }
 
@@ -26,10 +34,21 @@ impl McpQueryHandler {
     pub fn new(
         server_repo: Arc<dyn IMcpServerRepository>,
         history_repo: Arc<dyn IMcpCallHistoryRepository>,
+        prompt_history_repo: Arc<dyn IMcpPromptHistoryRepository>,
+        raw_rpc_history_repo: Arc<dyn IMcpRawRpcHistoryRepository>,
         message_repo: Arc<dyn IHttpReceivedMessageRepository>,
+        heartbeat_repo: Arc<dyn IMcpHeartbeatRepository>,
         client_manager: Arc<McpClientManager>,
     ) -> Self {
-        Self { server_repo, history_repo, message_repo, client_manager }
+        Self {
+            server_repo,
+            history_repo,
+            prompt_history_repo,
+            raw_rpc_history_repo,
+            message_repo,
+            heartbeat_repo,
+            client_manager,
+        }
     }
 }
 
@@ -97,6 +116,69 @@ impl QueryHandler<GetMcpToolsQuery, Vec<McpTool>> for McpQueryHandler {
     }
 }
 
+#[async_trait]
+impl QueryHandler<GetMcpResourcesQuery, Vec<McpResource>> for McpQueryHandler {
+    async fn handle(&self, query: GetMcpResourcesQuery) -> Result<Vec<McpResource>, AppError> {
+        // Get resources from memory cache
+        let cached_resources = self.client_manager.get_cached_resources(&query.server_id).await;
+
+        match cached_resources {
+            Some(resources) => Ok(resources
+                .iter()
+                .map(|r| McpResource {
+                    server_id: query.server_id.clone(),
+                    uri: r.uri.clone(),
+                    name: r.name.clone(),
+                    title: r.title.clone(),
+                    description: r.description.clone(),
+                    mime_type: r.mime_type.clone(),
+                    size: r.size,
+                    extra: r.extra.clone(),
+                })
+                .collect()),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl QueryHandler<GetMcpResourceTemplatesQuery, Vec<McpResourceTemplate>> for McpQueryHandler {
+    async fn handle(
+        &self,
+        query: GetMcpResourceTemplatesQuery,
+    ) -> Result<Vec<McpResourceTemplate>, AppError> {
+        // Get resource templates from memory cache
+        let cached_templates =
+            self.client_manager.get_cached_resource_templates(&query.server_id).await;
+
+        match cached_templates {
+            Some(templates) => Ok(templates
+                .iter()
+                .map(|t| McpResourceTemplate {
+                    server_id: query.server_id.clone(),
+                    uri_template: t.uri_template.clone(),
+                    name: t.name.clone(),
+                    title: t.title.clone(),
+                    description: t.description.clone(),
+                    mime_type: t.mime_type.clone(),
+                    extra: t.extra.clone(),
+                })
+                .collect()),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl QueryHandler<GetMcpResourceSubscriptionsQuery, Vec<String>> for McpQueryHandler {
+    async fn handle(
+        &self,
+        query: GetMcpResourceSubscriptionsQuery,
+    ) -> Result<Vec<String>, AppError> {
+        Ok(self.client_manager.get_resource_subscriptions(&query.server_id).await)
+    }
+}
+
 #[async_trait]
 impl QueryHandler<GetMcpCallHistoryQuery, Vec<McpCallHistory>> for McpQueryHandler {
     async fn handle(&self, query: GetMcpCallHistoryQuery) -> Result<Vec<McpCallHistory>, AppError> {
@@ -104,6 +186,66 @@ impl QueryHandler<GetMcpCallHistoryQuery, Vec<McpCallHistory>> for McpQueryHandl
     }
 }
 
+#[async_trait]
+impl QueryHandler<GetMcpPromptsQuery, Vec<McpPrompt>> for McpQueryHandler {
+    async fn handle(&self, query: GetMcpPromptsQuery) -> Result<Vec<McpPrompt>, AppError> {
+        // Get prompts from memory cache
+        let cached_prompts = self.client_manager.get_cached_prompts(&query.server_id).await;
+
+        match cached_prompts {
+            Some(prompts) => Ok(prompts
+                .iter()
+                .map(|p| McpPrompt {
+                    server_id: query.server_id.clone(),
+                    name: p.name.clone(),
+                    title: p.title.clone(),
+                    description: p.description.clone(),
+                    arguments: p.arguments.clone(),
+                    extra: p.extra.clone(),
+                })
+                .collect()),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl QueryHandler<GetMcpPromptHistoryQuery, Vec<McpPromptHistory>> for McpQueryHandler {
+    async fn handle(
+        &self,
+        query: GetMcpPromptHistoryQuery,
+    ) -> Result<Vec<McpPromptHistory>, AppError> {
+        self.prompt_history_repo.list(query.server_id.as_deref(), query.limit).await
+    }
+}
+
+#[async_trait]
+impl QueryHandler<GetMcpRawRpcHistoryQuery, Vec<McpRawRpcHistory>> for McpQueryHandler {
+    async fn handle(
+        &self,
+        query: GetMcpRawRpcHistoryQuery,
+    ) -> Result<Vec<McpRawRpcHistory>, AppError> {
+        self.raw_rpc_history_repo.list(query.server_id.as_deref(), query.limit).await
+    }
+}
+
+#[async_trait]
+impl QueryHandler<GetMcpHeartbeatsQuery, Vec<McpHeartbeat>> for McpQueryHandler {
+    async fn handle(&self, query: GetMcpHeartbeatsQuery) -> Result<Vec<McpHeartbeat>, AppError> {
+        self.heartbeat_repo.list(&query.server_id, query.limit).await
+    }
+}
+
+#[async_trait]
+impl QueryHandler<GetMcpConnectionInfoQuery, McpConnectionInfo> for McpQueryHandler {
+    async fn handle(
+        &self,
+        query: GetMcpConnectionInfoQuery,
+    ) -> Result<McpConnectionInfo, AppError> {
+        self.client_manager.connection_info(&query.server_id).await
+    }
+}
+
 #[async_trait]
 impl QueryHandler<ListHttpReceivedMessagesQuery, Vec<HttpReceivedMessage>> for McpQueryHandler {
     async fn handle(
@@ -1,11 +1,46 @@
 // CQRS Handlers
+pub mod collection_commands;
+pub mod collection_queries;
 pub mod config_commands;
 pub mod config_queries;
+pub mod elicitation_commands;
+pub mod elicitation_queries;
+pub mod environment_commands;
+pub mod environment_queries;
 pub mod mcp_commands;
 pub mod mcp_queries;
+pub mod oauth_commands;
+pub mod oauth_queries;
+pub mod sampling_commands;
+pub mod sampling_queries;
+pub mod server_log_commands;
+pub mod server_log_queries;
+pub mod test_run_commands;
+pub mod test_run_queries;
+pub mod tool_call_runner;
+pub mod traffic_commands;
+pub mod traffic_queries;
+pub mod wiring;
 
 // Re-exports for convenience
+pub use collection_commands::CollectionCommandHandler;
+pub use collection_queries::CollectionQueryHandler;
 pub use config_commands::ConfigCommandHandler;
 pub use config_queries::ConfigQueryHandler;
+pub use elicitation_commands::ElicitationCommandHandler;
+pub use elicitation_queries::ElicitationQueryHandler;
+pub use environment_commands::EnvironmentCommandHandler;
+pub use environment_queries::EnvironmentQueryHandler;
 pub use mcp_commands::McpCommandHandler;
 pub use mcp_queries::McpQueryHandler;
+pub use oauth_commands::OAuthCommandHandler;
+pub use oauth_queries::OAuthQueryHandler;
+pub use sampling_commands::SamplingCommandHandler;
+pub use sampling_queries::SamplingQueryHandler;
+pub use server_log_commands::ServerLogCommandHandler;
+pub use server_log_queries::ServerLogQueryHandler;
+pub use test_run_commands::TestRunCommandHandler;
+pub use test_run_queries::TestRunQueryHandler;
+pub use tool_call_runner::ToolCallRunner;
+pub use traffic_commands::TrafficCommandHandler;
+pub use traffic_queries::TrafficQueryHandler;
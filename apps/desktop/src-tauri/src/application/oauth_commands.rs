@@ -0,0 +1,57 @@
+//! OAuth Command Handlers - starts authorization flows, refreshes and forgets tokens.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::info;
+
+use crate::domain::cqrs::CommandHandler;
+use crate::domain::mcp::IMcpServerRepository;
+use crate::domain::oauth::{
+    ClearMcpOAuthCmd, McpOAuthStart, McpOAuthStatus, RefreshMcpOAuthTokenCmd, StartMcpOAuthCmd,
+};
+use crate::error::AppError;
+use crate::infra::oauth::McpOAuthManager;
+
+/// Handles OAuth commands (write operations).
+pub struct OAuthCommandHandler {
+    server_repo: Arc<dyn IMcpServerRepository>,
+    oauth_manager: Arc<McpOAuthManager>,
+}
+
+impl OAuthCommandHandler {
+    pub fn new(
+        server_repo: Arc<dyn IMcpServerRepository>,
+        oauth_manager: Arc<McpOAuthManager>,
+    ) -> Self {
+        Self { server_repo, oauth_manager }
+    }
+}
+
+#[async_trait]
+impl CommandHandler<StartMcpOAuthCmd, McpOAuthStart> for OAuthCommandHandler {
+    async fn handle(&self, cmd: StartMcpOAuthCmd) -> Result<McpOAuthStart, AppError> {
+        info!(target: "mcp", "Starting OAuth authorization for MCP server: {}", cmd.server_id);
+
+        let server =
+            self.server_repo.find_by_id(&cmd.server_id).await?.ok_or_else(|| {
+                AppError::NotFound(format!("MCP server {} not found", cmd.server_id))
+            })?;
+
+        self.oauth_manager.start(&server, &cmd.redirect_uri).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<RefreshMcpOAuthTokenCmd, McpOAuthStatus> for OAuthCommandHandler {
+    async fn handle(&self, cmd: RefreshMcpOAuthTokenCmd) -> Result<McpOAuthStatus, AppError> {
+        self.oauth_manager.refresh(&cmd.server_id).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<ClearMcpOAuthCmd, ()> for OAuthCommandHandler {
+    async fn handle(&self, cmd: ClearMcpOAuthCmd) -> Result<(), AppError> {
+        info!(target: "mcp", "Clearing OAuth credentials of MCP server: {}", cmd.server_id);
+        self.oauth_manager.clear(&cmd.server_id).await
+    }
+}
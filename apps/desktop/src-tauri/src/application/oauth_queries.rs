@@ -0,0 +1,27 @@
+//! OAuth Query Handlers - handles all read operations for OAuth credentials.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::domain::cqrs::QueryHandler;
+use crate::domain::oauth::{GetMcpOAuthStatusQuery, McpOAuthStatus};
+use crate::error::AppError;
+use crate::infra::oauth::McpOAuthManager;
+
+/// Handles OAuth queries (read operations).
+pub struct OAuthQueryHandler {
+    oauth_manager: Arc<McpOAuthManager>,
+}
+
+impl OAuthQueryHandler {
+    pub fn new(oauth_manager: Arc<McpOAuthManager>) -> Self {
+        Self { oauth_manager }
+    }
+}
+
+#[async_trait]
+impl QueryHandler<GetMcpOAuthStatusQuery, McpOAuthStatus> for OAuthQueryHandler {
+    async fn handle(&self, query: GetMcpOAuthStatusQuery) -> Result<McpOAuthStatus, AppError> {
+        self.oauth_manager.status(&query.server_id).await
+    }
+}
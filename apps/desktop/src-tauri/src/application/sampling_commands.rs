@@ -0,0 +1,121 @@
+//! Sampling Command Handlers - handles rule management and manual replies to sampling requests.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::domain::cqrs::CommandHandler;
+use crate::domain::sampling::{
+    ClearSamplingExchangesCmd, CreateSamplingRuleCmd, DeleteSamplingRuleCmd,
+    ISamplingExchangeRepository, ISamplingRuleRepository, RejectSamplingRequestCmd,
+    RespondSamplingRequestCmd, SamplingRule, UpdateSamplingRuleCmd,
+};
+use crate::error::AppError;
+use crate::infra::sampling_responder::{SamplingResponder, DEFAULT_SAMPLING_MODEL};
+
+/// Handles sampling-related commands (write operations).
+pub struct SamplingCommandHandler {
+    rule_repo: Arc<dyn ISamplingRuleRepository>,
+    exchange_repo: Arc<dyn ISamplingExchangeRepository>,
+    responder: Arc<SamplingResponder>,
+}
+
+impl SamplingCommandHandler {
+    pub fn new(
+        rule_repo: Arc<dyn ISamplingRuleRepository>,
+        exchange_repo: Arc<dyn ISamplingExchangeRepository>,
+        responder: Arc<SamplingResponder>,
+    ) -> Self {
+        Self { rule_repo, exchange_repo, responder }
+    }
+}
+
+#[async_trait]
+impl CommandHandler<CreateSamplingRuleCmd, SamplingRule> for SamplingCommandHandler {
+    async fn handle(&self, cmd: CreateSamplingRuleCmd) -> Result<SamplingRule, AppError> {
+        info!(target: "sampling", "Creating sampling rule: {}", cmd.name);
+
+        cmd.match_type.validate_pattern(&cmd.pattern)?;
+
+        let rule = SamplingRule {
+            id: Uuid::new_v4().to_string(),
+            name: cmd.name,
+            server_id: cmd.server_id,
+            match_type: cmd.match_type,
+            pattern: cmd.pattern,
+            response_text: cmd.response_text,
+            model: cmd.model.unwrap_or_else(|| DEFAULT_SAMPLING_MODEL.to_string()),
+            stop_reason: cmd.stop_reason,
+            enabled: cmd.enabled.unwrap_or(true),
+            priority: cmd.priority.unwrap_or(0),
+            created_at: String::new(),
+            updated_at: String::new(),
+        };
+
+        self.rule_repo.create(rule).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<UpdateSamplingRuleCmd, SamplingRule> for SamplingCommandHandler {
+    async fn handle(&self, cmd: UpdateSamplingRuleCmd) -> Result<SamplingRule, AppError> {
+        info!(target: "sampling", "Updating sampling rule: {}", cmd.id);
+
+        cmd.match_type.validate_pattern(&cmd.pattern)?;
+
+        let existing = self
+            .rule_repo
+            .find_by_id(&cmd.id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Sampling rule {} not found", cmd.id)))?;
+
+        let rule = SamplingRule {
+            id: cmd.id,
+            name: cmd.name,
+            server_id: cmd.server_id,
+            match_type: cmd.match_type,
+            pattern: cmd.pattern,
+            response_text: cmd.response_text,
+            model: cmd.model.unwrap_or_else(|| DEFAULT_SAMPLING_MODEL.to_string()),
+            stop_reason: cmd.stop_reason,
+            enabled: cmd.enabled,
+            priority: cmd.priority,
+            created_at: existing.created_at,
+            updated_at: String::new(),
+        };
+
+        self.rule_repo.update(rule).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<DeleteSamplingRuleCmd, ()> for SamplingCommandHandler {
+    async fn handle(&self, cmd: DeleteSamplingRuleCmd) -> Result<(), AppError> {
+        info!(target: "sampling", "Deleting sampling rule: {}", cmd.id);
+        self.rule_repo.delete(&cmd.id).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<RespondSamplingRequestCmd, ()> for SamplingCommandHandler {
+    async fn handle(&self, cmd: RespondSamplingRequestCmd) -> Result<(), AppError> {
+        info!(target: "sampling", "Replying to sampling request: {}", cmd.request_id);
+        self.responder.respond(&cmd.request_id, cmd.text, cmd.model, cmd.stop_reason).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<RejectSamplingRequestCmd, ()> for SamplingCommandHandler {
+    async fn handle(&self, cmd: RejectSamplingRequestCmd) -> Result<(), AppError> {
+        info!(target: "sampling", "Rejecting sampling request: {}", cmd.request_id);
+        self.responder.reject(&cmd.request_id, cmd.message).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<ClearSamplingExchangesCmd, ()> for SamplingCommandHandler {
+    async fn handle(&self, cmd: ClearSamplingExchangesCmd) -> Result<(), AppError> {
+        self.exchange_repo.clear(cmd.server_id.as_deref()).await
+    }
+}
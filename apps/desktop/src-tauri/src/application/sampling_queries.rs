@@ -0,0 +1,59 @@
+//! Sampling Query Handlers - handles all read operations for sampling rules and exchanges.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::domain::cqrs::QueryHandler;
+use crate::domain::sampling::{
+    ISamplingExchangeRepository, ISamplingRuleRepository, ListPendingSamplingRequestsQuery,
+    ListSamplingExchangesQuery, ListSamplingRulesQuery, PendingSamplingRequest, SamplingExchange,
+    SamplingRule,
+};
+use crate::error::AppError;
+use crate::infra::sampling_responder::SamplingResponder;
+
+/// Handles sampling-related queries (read operations).
+pub struct SamplingQueryHandler {
+    rule_repo: Arc<dyn ISamplingRuleRepository>,
+    exchange_repo: Arc<dyn ISamplingExchangeRepository>,
+    responder: Arc<SamplingResponder>,
+}
+
+impl SamplingQueryHandler {
+    pub fn new(
+        rule_repo: Arc<dyn ISamplingRuleRepository>,
+        exchange_repo: Arc<dyn ISamplingExchangeRepository>,
+        responder: Arc<SamplingResponder>,
+    ) -> Self {
+        Self { rule_repo, exchange_repo, responder }
+    }
+}
+
+#[async_trait]
+impl QueryHandler<ListSamplingRulesQuery, Vec<SamplingRule>> for SamplingQueryHandler {
+    async fn handle(&self, _query: ListSamplingRulesQuery) -> Result<Vec<SamplingRule>, AppError> {
+        self.rule_repo.list().await
+    }
+}
+
+#[async_trait]
+impl QueryHandler<ListSamplingExchangesQuery, Vec<SamplingExchange>> for SamplingQueryHandler {
+    async fn handle(
+        &self,
+        query: ListSamplingExchangesQuery,
+    ) -> Result<Vec<SamplingExchange>, AppError> {
+        self.exchange_repo.list(query.server_id.as_deref(), query.limit).await
+    }
+}
+
+#[async_trait]
+impl QueryHandler<ListPendingSamplingRequestsQuery, Vec<PendingSamplingRequest>>
+    for SamplingQueryHandler
+{
+    async fn handle(
+        &self,
+        _query: ListPendingSamplingRequestsQuery,
+    ) -> Result<Vec<PendingSamplingRequest>, AppError> {
+        Ok(self.responder.pending_requests().await)
+    }
+}
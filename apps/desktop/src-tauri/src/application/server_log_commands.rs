@@ -0,0 +1,42 @@
+//! Server Log Command Handlers - handles logging/setLevel and clearing stored log records.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::info;
+
+use crate::domain::cqrs::CommandHandler;
+use crate::domain::server_log::{
+    ClearMcpServerLogsCmd, IMcpServerLogRepository, SetMcpLogLevelCmd,
+};
+use crate::error::AppError;
+use crate::infra::mcp_client::McpClientManager;
+
+/// Handles server log commands (write operations).
+pub struct ServerLogCommandHandler {
+    log_repo: Arc<dyn IMcpServerLogRepository>,
+    client_manager: Arc<McpClientManager>,
+}
+
+impl ServerLogCommandHandler {
+    pub fn new(
+        log_repo: Arc<dyn IMcpServerLogRepository>,
+        client_manager: Arc<McpClientManager>,
+    ) -> Self {
+        Self { log_repo, client_manager }
+    }
+}
+
+#[async_trait]
+impl CommandHandler<SetMcpLogLevelCmd, ()> for ServerLogCommandHandler {
+    async fn handle(&self, cmd: SetMcpLogLevelCmd) -> Result<(), AppError> {
+        info!(target: "mcp", "Setting log level of MCP server {} to {}", cmd.server_id, cmd.level);
+        self.client_manager.set_log_level(&cmd.server_id, cmd.level).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<ClearMcpServerLogsCmd, ()> for ServerLogCommandHandler {
+    async fn handle(&self, cmd: ClearMcpServerLogsCmd) -> Result<(), AppError> {
+        self.log_repo.clear(cmd.server_id.as_deref()).await
+    }
+}
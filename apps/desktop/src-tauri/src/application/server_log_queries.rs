@@ -0,0 +1,26 @@
+//! Server Log Query Handlers - handles all read operations for server log records.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::domain::cqrs::QueryHandler;
+use crate::domain::server_log::{IMcpServerLogRepository, ListMcpServerLogsQuery, McpServerLog};
+use crate::error::AppError;
+
+/// Handles server log queries (read operations).
+pub struct ServerLogQueryHandler {
+    log_repo: Arc<dyn IMcpServerLogRepository>,
+}
+
+impl ServerLogQueryHandler {
+    pub fn new(log_repo: Arc<dyn IMcpServerLogRepository>) -> Self {
+        Self { log_repo }
+    }
+}
+
+#[async_trait]
+impl QueryHandler<ListMcpServerLogsQuery, Vec<McpServerLog>> for ServerLogQueryHandler {
+    async fn handle(&self, query: ListMcpServerLogsQuery) -> Result<Vec<McpServerLog>, AppError> {
+        self.log_repo.list(query.server_id.as_deref(), query.min_level, query.limit).await
+    }
+}
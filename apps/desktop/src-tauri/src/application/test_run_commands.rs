@@ -0,0 +1,189 @@
+//! Test Run Command Handlers - runs saved calls, checks their assertions and records the run.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::application::collection_commands::{run_order, run_saved_call, skipped_call};
+use crate::application::tool_call_runner::ToolCallRunner;
+use crate::domain::collection::{
+    IMcpCollectionFolderRepository, IMcpCollectionRepository, IMcpSavedCallRepository,
+    McpCollectionRunResult,
+};
+use crate::domain::cqrs::CommandHandler;
+use crate::domain::mcp::IMcpCallHistoryRepository;
+use crate::domain::test_run::{
+    DeleteMcpTestRunCmd, IMcpTestRunRepository, McpAssertion, McpAssertionResult, McpTestResult,
+    McpTestRun, McpTestRunDetail, RunMcpTestsCmd,
+};
+use crate::error::AppError;
+use crate::infra::assertion;
+use crate::infra::clock::now_timestamp;
+use crate::infra::environment::EnvironmentResolver;
+use crate::infra::mcp_client::McpClientManager;
+
+/// Handles test run commands (write operations).
+pub struct TestRunCommandHandler {
+    collection_repo: Arc<dyn IMcpCollectionRepository>,
+    folder_repo: Arc<dyn IMcpCollectionFolderRepository>,
+    call_repo: Arc<dyn IMcpSavedCallRepository>,
+    test_run_repo: Arc<dyn IMcpTestRunRepository>,
+    client_manager: Arc<McpClientManager>,
+    tool_call_runner: ToolCallRunner,
+}
+
+impl TestRunCommandHandler {
+    pub fn new(
+        collection_repo: Arc<dyn IMcpCollectionRepository>,
+        folder_repo: Arc<dyn IMcpCollectionFolderRepository>,
+        call_repo: Arc<dyn IMcpSavedCallRepository>,
+        test_run_repo: Arc<dyn IMcpTestRunRepository>,
+        history_repo: Arc<dyn IMcpCallHistoryRepository>,
+        client_manager: Arc<McpClientManager>,
+        environment: Arc<EnvironmentResolver>,
+    ) -> Self {
+        Self {
+            collection_repo,
+            folder_repo,
+            call_repo,
+            test_run_repo,
+            tool_call_runner: ToolCallRunner::new(
+                history_repo,
+                client_manager.clone(),
+                environment,
+            ),
+            client_manager,
+        }
+    }
+
+    /// Output schema of the called tool, from the tools listed on connect
+    async fn output_schema(&self, run: &McpCollectionRunResult) -> Option<serde_json::Value> {
+        let tools = self.client_manager.get_cached_tools(run.server_id.as_deref()?).await?;
+        let schema = tools.into_iter().find(|t| t.name == run.tool_name)?.output_schema?;
+        serde_json::from_str(&schema).ok()
+    }
+
+    /// Check the assertions of a call that ran (calls without any are checked for success)
+    async fn check(
+        &self,
+        assertions: &[McpAssertion],
+        run: &McpCollectionRunResult,
+    ) -> Vec<McpAssertionResult> {
+        let assertions = assertion::effective(assertions);
+
+        let output_schema = if assertions.contains(&McpAssertion::OutputSchema) {
+            self.output_schema(run).await
+        } else {
+            None
+        };
+        assertions.iter().map(|a| assertion::evaluate(a, run, output_schema.as_ref())).collect()
+    }
+}
+
+#[async_trait]
+impl CommandHandler<RunMcpTestsCmd, McpTestRunDetail> for TestRunCommandHandler {
+    async fn handle(&self, cmd: RunMcpTestsCmd) -> Result<McpTestRunDetail, AppError> {
+        let collection =
+            self.collection_repo.find_by_id(&cmd.collection_id).await?.ok_or_else(|| {
+                AppError::NotFound(format!("Collection {} not found", cmd.collection_id))
+            })?;
+        if let Some(folder_id) = &cmd.folder_id {
+            match self.folder_repo.find_by_id(folder_id).await? {
+                Some(folder) if folder.collection_id == collection.id => {}
+                _ => return Err(AppError::NotFound(format!("Folder {} not found", folder_id))),
+            }
+        }
+
+        let folders = self.folder_repo.list(&collection.id).await?;
+        let calls = self.call_repo.list(&collection.id).await?;
+        let mut order = run_order(&folders, &calls, cmd.folder_id.as_deref());
+        if let Some(ids) = &cmd.saved_call_ids {
+            if let Some(id) = ids.iter().find(|id| !calls.iter().any(|c| c.id == **id)) {
+                return Err(AppError::NotFound(format!(
+                    "Saved call {} not found in collection {}",
+                    id, collection.name
+                )));
+            }
+            order.retain(|(call, _)| ids.contains(&call.id));
+        }
+
+        info!(target: "test_run", "Testing {} calls of collection {}", order.len(), collection.name);
+
+        let run_id = Uuid::new_v4().to_string();
+        let started_at = now_timestamp();
+        let start = std::time::Instant::now();
+        let mut results = Vec::with_capacity(order.len());
+        let mut stopped = false;
+
+        for (position, (call, folder_path)) in order.into_iter().enumerate() {
+            let server_id = cmd.server_id.clone().or_else(|| call.server_id.clone());
+            let (run, assertions) = if stopped {
+                (skipped_call(call, folder_path, server_id), Vec::new())
+            } else {
+                let run = run_saved_call(
+                    &self.tool_call_runner,
+                    call,
+                    folder_path,
+                    server_id,
+                    cmd.timeout_ms,
+                )
+                .await;
+                let assertions = self.check(&call.assertions, &run).await;
+                (run, assertions)
+            };
+
+            let passed = !stopped && assertions.iter().all(|a| a.passed);
+            stopped = stopped || (cmd.stop_on_failure && !passed);
+
+            results.push(McpTestResult {
+                id: Uuid::new_v4().to_string(),
+                run_id: run_id.clone(),
+                saved_call_id: Some(run.saved_call_id),
+                name: run.name,
+                folder_path: run.folder_path,
+                server_id: run.server_id,
+                tool_name: run.tool_name,
+                status: run.status,
+                passed,
+                error: run.error,
+                history_id: run.history_id,
+                duration_ms: run.duration_ms,
+                assertions,
+                position: position as i64,
+            });
+        }
+
+        let passed = results.iter().filter(|r| r.passed).count();
+        let skipped = results.iter().filter(|r| r.status == "skipped").count();
+        let failed = results.len() - passed - skipped;
+
+        info!(target: "test_run", "Test run of {}: {} passed, {} failed, {} skipped", collection.name, passed, failed, skipped);
+
+        let run = McpTestRun {
+            id: run_id,
+            collection_id: Some(collection.id),
+            collection_name: collection.name,
+            folder_id: cmd.folder_id,
+            server_id: cmd.server_id,
+            started_at,
+            duration_ms: start.elapsed().as_millis() as i64,
+            total: results.len(),
+            passed,
+            failed,
+            skipped,
+            success: failed == 0,
+            created_at: String::new(),
+        };
+
+        self.test_run_repo.create(McpTestRunDetail { run, results }).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<DeleteMcpTestRunCmd, ()> for TestRunCommandHandler {
+    async fn handle(&self, cmd: DeleteMcpTestRunCmd) -> Result<(), AppError> {
+        info!(target: "test_run", "Deleting test run: {}", cmd.id);
+        self.test_run_repo.delete(&cmd.id).await
+    }
+}
@@ -0,0 +1,38 @@
+//! Test Run Query Handlers - handles all read operations for recorded test runs.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::domain::cqrs::QueryHandler;
+use crate::domain::test_run::{
+    GetMcpTestRunQuery, IMcpTestRunRepository, ListMcpTestRunsQuery, McpTestRun, McpTestRunDetail,
+};
+use crate::error::AppError;
+
+/// Handles test run queries (read operations).
+pub struct TestRunQueryHandler {
+    test_run_repo: Arc<dyn IMcpTestRunRepository>,
+}
+
+impl TestRunQueryHandler {
+    pub fn new(test_run_repo: Arc<dyn IMcpTestRunRepository>) -> Self {
+        Self { test_run_repo }
+    }
+}
+
+#[async_trait]
+impl QueryHandler<ListMcpTestRunsQuery, Vec<McpTestRun>> for TestRunQueryHandler {
+    async fn handle(&self, query: ListMcpTestRunsQuery) -> Result<Vec<McpTestRun>, AppError> {
+        self.test_run_repo.list(query.collection_id.as_deref(), query.limit).await
+    }
+}
+
+#[async_trait]
+impl QueryHandler<GetMcpTestRunQuery, McpTestRunDetail> for TestRunQueryHandler {
+    async fn handle(&self, query: GetMcpTestRunQuery) -> Result<McpTestRunDetail, AppError> {
+        self.test_run_repo
+            .find_by_id(&query.id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Test run {} not found", query.id)))
+    }
+}
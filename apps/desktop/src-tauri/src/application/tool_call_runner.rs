@@ -0,0 +1,105 @@
+//! Tool Call Runner - calls a tool and saves the call to history.
+//!
+//! Shared by every handler that calls tools (single calls, replays and collection runs),
+//! so each of them resolves `{{variable}}` placeholders against the active environment
+//! and leaves the same history entry behind, with secret values masked.
+
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::domain::mcp::{IMcpCallHistoryRepository, McpCallHistory, McpToolCallResult};
+use crate::error::AppError;
+use crate::infra::environment::{EnvironmentResolver, Variables};
+use crate::infra::mcp_client::McpClientManager;
+
+/// Timeout for tools/call when the caller doesn't give one
+const DEFAULT_TOOL_CALL_TIMEOUT_MS: u64 = 30_000;
+
+pub struct ToolCallRunner {
+    history_repo: Arc<dyn IMcpCallHistoryRepository>,
+    client_manager: Arc<McpClientManager>,
+    environment: Arc<EnvironmentResolver>,
+}
+
+impl ToolCallRunner {
+    pub fn new(
+        history_repo: Arc<dyn IMcpCallHistoryRepository>,
+        client_manager: Arc<McpClientManager>,
+        environment: Arc<EnvironmentResolver>,
+    ) -> Self {
+        Self { history_repo, client_manager, environment }
+    }
+
+    /// Call a tool and save the call to history (also when it fails or a variable is undefined)
+    pub async fn call(
+        &self,
+        server_id: &str,
+        tool_name: &str,
+        params: Option<serde_json::Value>,
+        call_id: Option<String>,
+        timeout_ms: Option<u64>,
+        replayed_from: Option<String>,
+    ) -> (Result<McpToolCallResult, AppError>, McpCallHistory) {
+        let call_id = call_id.unwrap_or_else(|| Uuid::new_v4().to_string());
+        let timeout = Duration::from_millis(timeout_ms.unwrap_or(DEFAULT_TOOL_CALL_TIMEOUT_MS));
+
+        let (variables, resolved) = match self.environment.variables().await {
+            Ok(variables) => {
+                let resolved =
+                    params.as_ref().map(|p| variables.resolve_json(p, false)).transpose();
+                (variables, resolved)
+            }
+            Err(e) => (Variables::default(), Err(e)),
+        };
+
+        let mut input_template = None;
+        let start = std::time::Instant::now();
+        let result = match resolved {
+            Ok(resolved) => {
+                if resolved != params {
+                    input_template = params.as_ref().map(|p| p.to_string());
+                }
+                self.client_manager
+                    .call_tool(server_id, &call_id, tool_name, resolved, timeout)
+                    .await
+            }
+            Err(e) => Err(e),
+        };
+        let duration_ms = start.elapsed().as_millis() as i64;
+
+        let (output_result, status, error_message) = match &result {
+            Ok(r) => {
+                (Some(variables.mask_secrets(&r.raw_response)), r.status.clone(), r.error.clone())
+            }
+            Err(e) => (None, "error".to_string(), Some(e.to_string())),
+        };
+        // Resolved values with secrets masked (as written when they can't be resolved)
+        let input_params =
+            params.as_ref().map(|p| variables.resolve_json(p, true).unwrap_or_else(|_| p.clone()));
+        let history = McpCallHistory {
+            id: Uuid::new_v4().to_string(),
+            server_id: server_id.to_string(),
+            tool_name: tool_name.to_string(),
+            input_params: input_params.map(|p| serde_json::to_string(&p).unwrap_or_default()),
+            output_result,
+            status,
+            error_message: error_message.map(|e| variables.mask_secrets(&e)),
+            duration_ms: Some(duration_ms),
+            created_at: String::new(),
+            replayed_from,
+            input_template,
+        };
+
+        let history = match self.history_repo.create(history.clone()).await {
+            Ok(saved) => saved,
+            Err(e) => {
+                warn!(target: "mcp", "Failed to save call history: {}", e);
+                history
+            }
+        };
+
+        (result, history)
+    }
+}
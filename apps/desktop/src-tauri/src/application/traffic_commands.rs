@@ -0,0 +1,72 @@
+//! Traffic Command Handlers - handles clearing recorded traffic frames and importing transcripts.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::info;
+
+use crate::domain::cqrs::CommandHandler;
+use crate::domain::traffic::{
+    ClearMcpTrafficCmd, DeleteMcpTranscriptCmd, IMcpTrafficRepository, IMcpTranscriptRepository,
+    ImportMcpTranscriptCmd, McpTranscript,
+};
+use crate::error::AppError;
+use crate::infra::clock::now_timestamp;
+use crate::infra::traffic_export;
+
+/// Handles traffic commands (write operations).
+pub struct TrafficCommandHandler {
+    traffic_repo: Arc<dyn IMcpTrafficRepository>,
+    transcript_repo: Arc<dyn IMcpTranscriptRepository>,
+}
+
+impl TrafficCommandHandler {
+    pub fn new(
+        traffic_repo: Arc<dyn IMcpTrafficRepository>,
+        transcript_repo: Arc<dyn IMcpTranscriptRepository>,
+    ) -> Self {
+        Self { traffic_repo, transcript_repo }
+    }
+}
+
+#[async_trait]
+impl CommandHandler<ClearMcpTrafficCmd, ()> for TrafficCommandHandler {
+    async fn handle(&self, cmd: ClearMcpTrafficCmd) -> Result<(), AppError> {
+        self.traffic_repo.clear(cmd.server_id.as_deref()).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<ImportMcpTranscriptCmd, McpTranscript> for TrafficCommandHandler {
+    async fn handle(&self, cmd: ImportMcpTranscriptCmd) -> Result<McpTranscript, AppError> {
+        let (header, frames, calls) = traffic_export::parse_transcript(&cmd.content)?;
+
+        let name = cmd.name.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+        let name = name.unwrap_or_else(|| match &header.started_at {
+            Some(started_at) => format!("{} ({})", header.server.name, started_at),
+            None => header.server.name.clone(),
+        });
+
+        let transcript = McpTranscript {
+            id: uuid::Uuid::new_v4().to_string(),
+            name,
+            server_name: header.server.name,
+            server_url: header.server.url,
+            transport: header.server.transport,
+            connection_id: header.connection_id,
+            frame_count: frames.len() as i64,
+            call_count: calls.len() as i64,
+            started_at: header.started_at,
+            created_at: now_timestamp(),
+        };
+
+        info!(target: "mcp", "Importing transcript {} ({} frames, {} calls)", transcript.name, transcript.frame_count, transcript.call_count);
+        self.transcript_repo.create(transcript, &cmd.content).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler<DeleteMcpTranscriptCmd, ()> for TrafficCommandHandler {
+    async fn handle(&self, cmd: DeleteMcpTranscriptCmd) -> Result<(), AppError> {
+        self.transcript_repo.delete(&cmd.id).await
+    }
+}
@@ -0,0 +1,178 @@
+//! Traffic Query Handlers - handles all read operations for recorded traffic frames,
+//! exports and imported transcripts.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::domain::cqrs::QueryHandler;
+use crate::domain::mcp::{IMcpCallHistoryRepository, IMcpServerRepository, McpServerType};
+use crate::domain::traffic::{
+    ExportMcpTrafficQuery, GetMcpTranscriptQuery, IMcpTrafficRepository, IMcpTranscriptRepository,
+    ListMcpTrafficQuery, ListMcpTranscriptsQuery, McpTrafficExport, McpTrafficExportFormat,
+    McpTrafficFrame, McpTranscript, McpTranscriptDetail, McpTranscriptHeader, McpTranscriptServer,
+    TRANSCRIPT_FORMAT, TRANSCRIPT_VERSION,
+};
+use crate::error::AppError;
+use crate::infra::clock::now_timestamp;
+use crate::infra::traffic_export;
+
+/// Upper bound on frames read for one export (the repository keeps fewer per server)
+const MAX_EXPORT_FRAMES: i64 = 100_000;
+
+/// Upper bound on history entries scanned for calls made during the connection
+const MAX_EXPORT_CALLS: i64 = 10_000;
+
+/// Handles traffic queries (read operations).
+pub struct TrafficQueryHandler {
+    traffic_repo: Arc<dyn IMcpTrafficRepository>,
+    transcript_repo: Arc<dyn IMcpTranscriptRepository>,
+    server_repo: Arc<dyn IMcpServerRepository>,
+    history_repo: Arc<dyn IMcpCallHistoryRepository>,
+}
+
+impl TrafficQueryHandler {
+    pub fn new(
+        traffic_repo: Arc<dyn IMcpTrafficRepository>,
+        transcript_repo: Arc<dyn IMcpTranscriptRepository>,
+        server_repo: Arc<dyn IMcpServerRepository>,
+        history_repo: Arc<dyn IMcpCallHistoryRepository>,
+    ) -> Self {
+        Self { traffic_repo, transcript_repo, server_repo, history_repo }
+    }
+}
+
+#[async_trait]
+impl QueryHandler<ListMcpTrafficQuery, Vec<McpTrafficFrame>> for TrafficQueryHandler {
+    async fn handle(&self, query: ListMcpTrafficQuery) -> Result<Vec<McpTrafficFrame>, AppError> {
+        self.traffic_repo
+            .list(
+                query.server_id.as_deref(),
+                query.connection_id.as_deref(),
+                query.direction,
+                query.correlation_id.as_deref(),
+                query.limit,
+            )
+            .await
+    }
+}
+
+#[async_trait]
+impl QueryHandler<ExportMcpTrafficQuery, McpTrafficExport> for TrafficQueryHandler {
+    async fn handle(&self, query: ExportMcpTrafficQuery) -> Result<McpTrafficExport, AppError> {
+        let server = self
+            .server_repo
+            .find_by_id(&query.server_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("MCP server {}", query.server_id)))?;
+
+        let connection_id = match query.connection_id {
+            Some(connection_id) => connection_id,
+            None => self
+                .traffic_repo
+                .list(Some(&server.id), None, None, None, Some(1))
+                .await?
+                .pop()
+                .map(|frame| frame.connection_id)
+                .ok_or_else(|| {
+                    AppError::Domain("No traffic recorded for this server".to_string())
+                })?,
+        };
+
+        // Oldest first
+        let mut frames = self
+            .traffic_repo
+            .list(Some(&server.id), Some(&connection_id), None, None, Some(MAX_EXPORT_FRAMES))
+            .await?;
+        frames.reverse();
+        if frames.is_empty() {
+            return Err(AppError::NotFound(format!("Traffic of connection {}", connection_id)));
+        }
+
+        // The transport the connection actually used; the server may have been edited since
+        let transport = frames[0].transport.clone();
+        let started_at = frames.first().map(|f| f.created_at.clone());
+        let ended_at = frames.last().map(|f| f.created_at.clone());
+
+        // History timestamps have whole seconds, so the window starts at the first frame's second
+        let mut calls = self.history_repo.list(Some(&server.id), Some(MAX_EXPORT_CALLS)).await?;
+        calls.retain(|call| {
+            let after_start = started_at
+                .as_deref()
+                .is_some_and(|s| call.created_at.as_str() >= s.get(..19).unwrap_or(s));
+            let before_end = ended_at.as_deref().is_some_and(|e| call.created_at.as_str() <= e);
+            after_start && before_end
+        });
+        calls.reverse();
+
+        let header = McpTranscriptHeader {
+            format: TRANSCRIPT_FORMAT.to_string(),
+            version: TRANSCRIPT_VERSION,
+            server: McpTranscriptServer {
+                id: server.id.clone(),
+                name: server.name.clone(),
+                url: server.url.clone(),
+                transport: transport.clone(),
+            },
+            connection_id: connection_id.clone(),
+            server_info: traffic_export::server_info(&frames),
+            started_at,
+            ended_at,
+            exported_at: now_timestamp(),
+        };
+        let transcript_frames = traffic_export::transcript_frames(&frames);
+
+        let file_stem = format!(
+            "{}-{}",
+            server.name.replace(|c: char| !c.is_ascii_alphanumeric() && c != '-', "_"),
+            connection_id.chars().take(8).collect::<String>()
+        );
+        let (file_name, content) = match query.format {
+            McpTrafficExportFormat::Har => {
+                if !matches!(transport, McpServerType::Sse | McpServerType::StreamableHttp) {
+                    return Err(AppError::Domain(
+                        "HAR export applies to HTTP transports only (SSE, streamable HTTP)"
+                            .to_string(),
+                    ));
+                }
+                let content = traffic_export::har(&header, &transcript_frames, &server.url)?;
+                (format!("{}.har", file_stem), content)
+            }
+            McpTrafficExportFormat::Transcript => {
+                let content = traffic_export::transcript(&header, &transcript_frames, &calls)?;
+                (format!("{}.ndjson", file_stem), content)
+            }
+        };
+
+        Ok(McpTrafficExport {
+            format: query.format,
+            file_name,
+            content,
+            frame_count: transcript_frames.len(),
+            call_count: calls.len(),
+        })
+    }
+}
+
+#[async_trait]
+impl QueryHandler<ListMcpTranscriptsQuery, Vec<McpTranscript>> for TrafficQueryHandler {
+    async fn handle(
+        &self,
+        _query: ListMcpTranscriptsQuery,
+    ) -> Result<Vec<McpTranscript>, AppError> {
+        self.transcript_repo.list().await
+    }
+}
+
+#[async_trait]
+impl QueryHandler<GetMcpTranscriptQuery, McpTranscriptDetail> for TrafficQueryHandler {
+    async fn handle(&self, query: GetMcpTranscriptQuery) -> Result<McpTranscriptDetail, AppError> {
+        let (transcript, content) = self
+            .transcript_repo
+            .find_by_id(&query.id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Transcript {}", query.id)))?;
+
+        let (header, frames, calls) = traffic_export::parse_transcript(&content)?;
+        Ok(McpTranscriptDetail { transcript, header, frames, calls })
+    }
+}
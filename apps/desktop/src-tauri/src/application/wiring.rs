@@ -0,0 +1,258 @@
+//! Wiring - builds the repositories and CQRS handlers on one database.
+//!
+//! Shared by the app (which hands the handlers to Tauri) and the headless runner, so both
+//! always run with the same repositories, responders and recorders.
+
+use std::sync::Arc;
+
+use sqlx::SqlitePool;
+
+use crate::application::{
+    CollectionCommandHandler, CollectionQueryHandler, ConfigCommandHandler, ConfigQueryHandler,
+    ElicitationCommandHandler, ElicitationQueryHandler, EnvironmentCommandHandler,
+    EnvironmentQueryHandler, McpCommandHandler, McpQueryHandler, OAuthCommandHandler,
+    OAuthQueryHandler, SamplingCommandHandler, SamplingQueryHandler, ServerLogCommandHandler,
+    ServerLogQueryHandler, TestRunCommandHandler, TestRunQueryHandler, TrafficCommandHandler,
+    TrafficQueryHandler,
+};
+use crate::domain::events::IEventPublisher;
+use crate::error::AppError;
+use crate::infra;
+use crate::infra::event_publisher::EventPublisher;
+use crate::infra::mcp_client::McpClientManager;
+use crate::infra::oauth::McpOAuthManager;
+
+/// The client manager and every handler, ready to use
+pub struct Handlers {
+    pub client_manager: Arc<McpClientManager>,
+    /// Finishes OAuth flows when the redirect arrives
+    pub oauth_manager: Arc<McpOAuthManager>,
+    pub config_cmd_handler: ConfigCommandHandler,
+    pub config_query_handler: ConfigQueryHandler,
+    pub environment_cmd_handler: EnvironmentCommandHandler,
+    pub environment_query_handler: EnvironmentQueryHandler,
+    pub mcp_cmd_handler: McpCommandHandler,
+    pub mcp_query_handler: McpQueryHandler,
+    pub sampling_cmd_handler: SamplingCommandHandler,
+    pub sampling_query_handler: SamplingQueryHandler,
+    pub elicitation_cmd_handler: ElicitationCommandHandler,
+    pub elicitation_query_handler: ElicitationQueryHandler,
+    pub server_log_cmd_handler: ServerLogCommandHandler,
+    pub server_log_query_handler: ServerLogQueryHandler,
+    pub traffic_cmd_handler: TrafficCommandHandler,
+    pub traffic_query_handler: TrafficQueryHandler,
+    pub collection_cmd_handler: CollectionCommandHandler,
+    pub collection_query_handler: CollectionQueryHandler,
+    pub test_run_cmd_handler: TestRunCommandHandler,
+    pub test_run_query_handler: TestRunQueryHandler,
+    pub oauth_cmd_handler: OAuthCommandHandler,
+    pub oauth_query_handler: OAuthQueryHandler,
+}
+
+/// Build the client manager and the handlers on `pool`
+///
+/// `publisher` receives the MCP events (connections, traffic, sampling, OAuth...) and
+/// `domain_publisher` the domain events. `environment_id` pins the environment calls are
+/// resolved with; without it the active environment is used.
+pub async fn wire(
+    pool: SqlitePool,
+    publisher: Arc<dyn EventPublisher>,
+    domain_publisher: Arc<dyn IEventPublisher>,
+    environment_id: Option<String>,
+) -> Result<Handlers, AppError> {
+    let client_manager = Arc::new(McpClientManager::new(publisher.clone()));
+
+    // --- Config Domain (CQRS) ---
+    let config_repo = Arc::new(infra::repo_config::SqliteConfigRepository::new(pool.clone()));
+    client_manager.set_config_repo(config_repo.clone()).await;
+
+    let config_cmd_handler =
+        ConfigCommandHandler::new(config_repo.clone(), domain_publisher.clone());
+    let config_query_handler = ConfigQueryHandler::new(config_repo.clone());
+
+    // --- Environment Domain (CQRS) ---
+    let environment_repo =
+        Arc::new(infra::repo_environment::SqliteMcpEnvironmentRepository::new(pool.clone()));
+
+    // Resolves {{variables}} of the environment for calls and connects
+    let mut environment_resolver =
+        infra::environment::EnvironmentResolver::new(environment_repo.clone(), config_repo.clone());
+    if let Some(environment_id) = environment_id {
+        environment_resolver = environment_resolver.with_environment(environment_id);
+    }
+    let environment_resolver = Arc::new(environment_resolver);
+
+    let environment_cmd_handler = EnvironmentCommandHandler::new(
+        environment_repo.clone(),
+        config_repo.clone(),
+        domain_publisher,
+    );
+    let environment_query_handler = EnvironmentQueryHandler::new(environment_repo, config_repo);
+
+    // --- MCP Domain (CQRS) ---
+    let server_repo = Arc::new(infra::repo_mcp::SqliteMcpServerRepository::new(pool.clone()));
+    let history_repo = Arc::new(infra::repo_mcp::SqliteMcpCallHistoryRepository::new(pool.clone()));
+    let prompt_history_repo =
+        Arc::new(infra::repo_mcp::SqliteMcpPromptHistoryRepository::new(pool.clone()));
+    let raw_rpc_history_repo =
+        Arc::new(infra::repo_mcp::SqliteMcpRawRpcHistoryRepository::new(pool.clone()));
+    let message_repo =
+        Arc::new(infra::repo_mcp::SqliteHttpReceivedMessageRepository::new(pool.clone()));
+    let heartbeat_repo = Arc::new(infra::repo_mcp::SqliteMcpHeartbeatRepository::new(pool.clone()));
+    client_manager.set_heartbeat_repo(heartbeat_repo.clone()).await;
+
+    let mcp_cmd_handler = McpCommandHandler::new(
+        server_repo.clone(),
+        history_repo.clone(),
+        prompt_history_repo.clone(),
+        raw_rpc_history_repo.clone(),
+        message_repo.clone(),
+        client_manager.clone(),
+        environment_resolver.clone(),
+    );
+    let mcp_query_handler = McpQueryHandler::new(
+        server_repo.clone(),
+        history_repo.clone(),
+        prompt_history_repo,
+        raw_rpc_history_repo,
+        message_repo,
+        heartbeat_repo,
+        client_manager.clone(),
+    );
+
+    // --- Sampling Domain (CQRS) ---
+    let sampling_rule_repo =
+        Arc::new(infra::repo_sampling::SqliteSamplingRuleRepository::new(pool.clone()));
+    let sampling_exchange_repo =
+        Arc::new(infra::repo_sampling::SqliteSamplingExchangeRepository::new(pool.clone()));
+
+    // Responder answering sampling/createMessage for every connection
+    let sampling_responder = Arc::new(infra::sampling_responder::SamplingResponder::new(
+        sampling_rule_repo.clone(),
+        sampling_exchange_repo.clone(),
+        publisher.clone(),
+    ));
+    client_manager.set_sampling_responder(sampling_responder.clone()).await;
+
+    let sampling_cmd_handler = SamplingCommandHandler::new(
+        sampling_rule_repo.clone(),
+        sampling_exchange_repo.clone(),
+        sampling_responder.clone(),
+    );
+    let sampling_query_handler =
+        SamplingQueryHandler::new(sampling_rule_repo, sampling_exchange_repo, sampling_responder);
+
+    // --- Elicitation Domain (CQRS) ---
+    let elicitation_rule_repo =
+        Arc::new(infra::repo_elicitation::SqliteElicitationRuleRepository::new(pool.clone()));
+
+    // Responder answering elicitation/create for every connection
+    let elicitation_responder = Arc::new(infra::elicitation_responder::ElicitationResponder::new(
+        elicitation_rule_repo.clone(),
+        publisher.clone(),
+    ));
+    client_manager.set_elicitation_responder(elicitation_responder.clone()).await;
+
+    let elicitation_cmd_handler = ElicitationCommandHandler::new(
+        elicitation_rule_repo.clone(),
+        elicitation_responder.clone(),
+    );
+    let elicitation_query_handler =
+        ElicitationQueryHandler::new(elicitation_rule_repo, elicitation_responder);
+
+    // --- Server Log Domain (CQRS) ---
+    let server_log_repo =
+        Arc::new(infra::repo_server_log::SqliteMcpServerLogRepository::new(pool.clone()));
+    client_manager.set_log_repo(server_log_repo.clone()).await;
+
+    let server_log_cmd_handler =
+        ServerLogCommandHandler::new(server_log_repo.clone(), client_manager.clone());
+    let server_log_query_handler = ServerLogQueryHandler::new(server_log_repo);
+
+    // --- Traffic Domain (CQRS) ---
+    let traffic_repo = Arc::new(infra::repo_traffic::SqliteMcpTrafficRepository::new(pool.clone()));
+    client_manager.set_traffic_repo(traffic_repo.clone()).await;
+
+    let transcript_repo =
+        Arc::new(infra::repo_traffic::SqliteMcpTranscriptRepository::new(pool.clone()));
+
+    let traffic_cmd_handler =
+        TrafficCommandHandler::new(traffic_repo.clone(), transcript_repo.clone());
+    let traffic_query_handler = TrafficQueryHandler::new(
+        traffic_repo,
+        transcript_repo,
+        server_repo.clone(),
+        history_repo.clone(),
+    );
+
+    // --- Collection Domain (CQRS) ---
+    let collection_repo =
+        Arc::new(infra::repo_collection::SqliteMcpCollectionRepository::new(pool.clone()));
+    let folder_repo =
+        Arc::new(infra::repo_collection::SqliteMcpCollectionFolderRepository::new(pool.clone()));
+    let call_repo =
+        Arc::new(infra::repo_collection::SqliteMcpSavedCallRepository::new(pool.clone()));
+
+    let collection_cmd_handler = CollectionCommandHandler::new(
+        collection_repo.clone(),
+        folder_repo.clone(),
+        call_repo.clone(),
+        server_repo.clone(),
+        history_repo.clone(),
+        client_manager.clone(),
+        environment_resolver.clone(),
+    );
+    let collection_query_handler = CollectionQueryHandler::new(
+        collection_repo.clone(),
+        folder_repo.clone(),
+        call_repo.clone(),
+    );
+
+    // --- Test Run Domain (CQRS) ---
+    let test_run_repo =
+        Arc::new(infra::repo_test_run::SqliteMcpTestRunRepository::new(pool.clone()));
+
+    let test_run_cmd_handler = TestRunCommandHandler::new(
+        collection_repo,
+        folder_repo,
+        call_repo,
+        test_run_repo.clone(),
+        history_repo,
+        client_manager.clone(),
+        environment_resolver,
+    );
+    let test_run_query_handler = TestRunQueryHandler::new(test_run_repo);
+
+    // --- OAuth Domain (CQRS) ---
+    let oauth_repo = Arc::new(infra::repo_oauth::SqliteMcpOAuthRepository::new(pool));
+    let oauth_manager = Arc::new(McpOAuthManager::new(oauth_repo, publisher)?);
+    client_manager.set_oauth_manager(oauth_manager.clone()).await;
+
+    let oauth_cmd_handler = OAuthCommandHandler::new(server_repo, oauth_manager.clone());
+    let oauth_query_handler = OAuthQueryHandler::new(oauth_manager.clone());
+
+    Ok(Handlers {
+        client_manager,
+        oauth_manager,
+        config_cmd_handler,
+        config_query_handler,
+        environment_cmd_handler,
+        environment_query_handler,
+        mcp_cmd_handler,
+        mcp_query_handler,
+        sampling_cmd_handler,
+        sampling_query_handler,
+        elicitation_cmd_handler,
+        elicitation_query_handler,
+        server_log_cmd_handler,
+        server_log_query_handler,
+        traffic_cmd_handler,
+        traffic_query_handler,
+        collection_cmd_handler,
+        collection_query_handler,
+        test_run_cmd_handler,
+        test_run_query_handler,
+        oauth_cmd_handler,
+        oauth_query_handler,
+    })
+}
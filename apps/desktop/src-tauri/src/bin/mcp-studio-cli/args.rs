@@ -0,0 +1,140 @@
+//! Command-line arguments of the headless runner.
+
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+Run saved MCP Studio collections and tests without the app window (e.g. in CI).
+
+Usage:
+  mcp-studio-cli list [options]
+  mcp-studio-cli run <collection> [options]
+  mcp-studio-cli test <collection> [options]
+
+Commands:
+  list                   List the collections, servers and environments
+  run <collection>       Call every saved call of a collection (id or name)
+  test <collection>      Call them and check their assertions; the run is recorded
+
+Options:
+  --db <path>            Database file (default: the app's database)
+  --folder <folder>      Only run this folder and its subfolders (id or name)
+  --server <server>      Send every call to this server (id or name)
+  --env <environment>    Resolve {{variables}} with this environment (id or name)
+                         instead of the active one
+  --stop-on-failure      Skip the remaining calls after the first failure
+  --timeout-ms <ms>      Timeout of each call (default 30000)
+  --format <format>      Report format: json (default) or junit
+  --output <file>        Write the report to a file instead of stdout
+  -v, --verbose          Log to stderr (RUST_LOG overrides the level)
+  -h, --help             Show this help
+
+Exit status: 0 when every call passed, 1 when a call failed, 2 on usage or setup errors.";
+
+/// What to do
+#[derive(Debug, PartialEq)]
+pub enum Action {
+    List,
+    Run(String),  // Collection id or name
+    Test(String), // Collection id or name
+    Help,
+}
+
+/// Format of the report
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReportFormat {
+    Json,
+    Junit,
+}
+
+#[derive(Debug)]
+pub struct Args {
+    pub action: Action,
+    pub db: Option<PathBuf>,
+    pub folder: Option<String>,
+    pub server: Option<String>,
+    pub environment: Option<String>,
+    pub stop_on_failure: bool,
+    pub timeout_ms: Option<u64>,
+    pub format: ReportFormat,
+    pub output: Option<PathBuf>,
+    pub verbose: bool,
+}
+
+impl Args {
+    /// Parse the arguments (without the program name)
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Args {
+            action: Action::Help,
+            db: None,
+            folder: None,
+            server: None,
+            environment: None,
+            stop_on_failure: false,
+            timeout_ms: None,
+            format: ReportFormat::Json,
+            output: None,
+            verbose: false,
+        };
+        let mut positional = Vec::new();
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            // Both `--name value` and `--name=value`
+            let (name, inline) = match arg.split_once('=') {
+                Some((name, value)) if name.starts_with("--") => (name.to_string(), Some(value)),
+                _ => (arg.clone(), None),
+            };
+            let mut value = |name: &str| {
+                inline
+                    .map(str::to_string)
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("{} needs a value", name))
+            };
+
+            match name.as_str() {
+                "-h" | "--help" => return Ok(Args { action: Action::Help, ..parsed }),
+                "-v" | "--verbose" => parsed.verbose = true,
+                "--stop-on-failure" => parsed.stop_on_failure = true,
+                "--db" => parsed.db = Some(PathBuf::from(value(&name)?)),
+                "--folder" => parsed.folder = Some(value(&name)?),
+                "--server" => parsed.server = Some(value(&name)?),
+                "--env" => parsed.environment = Some(value(&name)?),
+                "--output" => parsed.output = Some(PathBuf::from(value(&name)?)),
+                "--timeout-ms" => {
+                    let ms = value(&name)?;
+                    let ms = ms.parse().ok().filter(|ms| *ms > 0);
+                    parsed.timeout_ms =
+                        Some(ms.ok_or("--timeout-ms must be a positive number of milliseconds")?);
+                }
+                "--format" => {
+                    parsed.format = match value(&name)?.as_str() {
+                        "json" => ReportFormat::Json,
+                        "junit" => ReportFormat::Junit,
+                        other => return Err(format!("Unknown format '{}' (json or junit)", other)),
+                    }
+                }
+                flag if flag.starts_with('-') && flag.len() > 1 => {
+                    return Err(format!("Unknown option {}", flag))
+                }
+                _ => positional.push(arg),
+            }
+        }
+
+        let mut positional = positional.into_iter();
+        parsed.action = match (positional.next().as_deref(), positional.next()) {
+            (None, _) => Action::Help,
+            (Some("list"), None) => Action::List,
+            (Some("run"), Some(collection)) => Action::Run(collection),
+            (Some("test"), Some(collection)) => Action::Test(collection),
+            (Some(command @ ("run" | "test")), None) => {
+                return Err(format!("{} needs a collection", command))
+            }
+            (Some("list"), Some(extra)) => return Err(format!("Unexpected argument '{}'", extra)),
+            (Some(command), _) => return Err(format!("Unknown command '{}'", command)),
+        };
+        if let Some(extra) = positional.next() {
+            return Err(format!("Unexpected argument '{}'", extra));
+        }
+        Ok(parsed)
+    }
+}
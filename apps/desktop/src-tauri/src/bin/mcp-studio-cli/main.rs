@@ -0,0 +1,383 @@
+//! Headless runner - runs saved collections and test runs without the app window (e.g. in CI).
+//!
+//! It opens the app's database (or the one given with `--db`), wires the same repositories
+//! and handlers as the app, connects the servers the calls need and writes the report as
+//! JSON or JUnit XML. The exit status tells CI whether every call passed.
+
+mod args;
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+
+use mcp_studio_lib::application::wiring::{self, Handlers};
+use mcp_studio_lib::application::{
+    CollectionCommandHandler, CollectionQueryHandler, EnvironmentQueryHandler, McpCommandHandler,
+    McpQueryHandler, TestRunCommandHandler,
+};
+use mcp_studio_lib::domain::collection::{
+    GetMcpCollectionQuery, ListMcpCollectionsQuery, McpCollectionDetail, RunMcpCollectionCmd,
+};
+use mcp_studio_lib::domain::cqrs::{CommandHandler, QueryHandler};
+use mcp_studio_lib::domain::environment::{
+    GetActiveMcpEnvironmentQuery, IMcpEnvironmentRepository, ListMcpEnvironmentsQuery,
+};
+use mcp_studio_lib::domain::mcp::{ConnectMcpServerCmd, ListMcpServersQuery};
+use mcp_studio_lib::domain::test_run::RunMcpTestsCmd;
+use mcp_studio_lib::error::AppError;
+use mcp_studio_lib::infra;
+use mcp_studio_lib::infra::event_publisher::LogEventPublisher;
+use mcp_studio_lib::infra::mcp_client::McpClientManager;
+use mcp_studio_lib::infra::repo_environment::SqliteMcpEnvironmentRepository;
+
+use args::{Action, Args, ReportFormat, USAGE};
+
+/// Same identifier as the app (tauri.conf.json), so the default database is the app's
+const APP_IDENTIFIER: &str = "com.mcpstudio.app";
+
+/// Exit status when every call passed, when a call failed and on usage or setup errors
+const EXIT_PASSED: u8 = 0;
+const EXIT_FAILED: u8 = 1;
+const EXIT_ERROR: u8 = 2;
+
+/// Handlers the runner uses, wired like the app's
+struct Runner {
+    client_manager: Arc<McpClientManager>,
+    mcp_cmd_handler: McpCommandHandler,
+    mcp_query_handler: McpQueryHandler,
+    collection_cmd_handler: CollectionCommandHandler,
+    collection_query_handler: CollectionQueryHandler,
+    test_run_cmd_handler: TestRunCommandHandler,
+    environment_query_handler: EnvironmentQueryHandler,
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            return ExitCode::from(EXIT_ERROR);
+        }
+    };
+    if args.action == Action::Help {
+        println!("{}", USAGE);
+        return ExitCode::from(EXIT_PASSED);
+    }
+
+    setup_logging(args.verbose);
+
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("error: Failed to start the runtime: {}", e);
+            return ExitCode::from(EXIT_ERROR);
+        }
+    };
+    match runtime.block_on(run(args)) {
+        Ok(true) => ExitCode::from(EXIT_PASSED),
+        Ok(false) => ExitCode::from(EXIT_FAILED),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::from(EXIT_ERROR)
+        }
+    }
+}
+
+/// Logs go to stderr, so the report on stdout stays machine-readable
+fn setup_logging(verbose: bool) {
+    let default_level = if verbose { "info" } else { "error" };
+    let filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(default_level));
+    let _ = tracing_subscriber::registry()
+        .with(fmt::layer().with_writer(std::io::stderr).with_target(true))
+        .with(filter)
+        .try_init();
+}
+
+/// Whether every call passed
+async fn run(args: Args) -> Result<bool, AppError> {
+    let pool = match &args.db {
+        Some(path) => infra::db::open_existing_db(path).await?,
+        None => infra::db::open_existing_db(&default_db_path()?).await.map_err(|e| match e {
+            AppError::NotFound(message) => {
+                AppError::NotFound(format!("{} (open the app once or pass --db)", message))
+            }
+            e => e,
+        })?,
+    };
+    let runner = Runner::new(pool, &args).await?;
+
+    let outcome = match &args.action {
+        Action::List => runner.list().await.map(|_| true),
+        Action::Run(collection) => runner.run_collection(collection, &args).await,
+        Action::Test(collection) => runner.run_tests(collection, &args).await,
+        Action::Help => Ok(true),
+    };
+
+    runner.client_manager.disconnect_all().await;
+    outcome
+}
+
+fn default_db_path() -> Result<PathBuf, AppError> {
+    dirs::data_dir()
+        .map(|dir| dir.join(APP_IDENTIFIER).join("db.sqlite"))
+        .ok_or_else(|| AppError::Unknown("No data directory on this system, pass --db".to_string()))
+}
+
+impl Runner {
+    async fn new(pool: sqlx::SqlitePool, args: &Args) -> Result<Self, AppError> {
+        // Pinned environment, resolved before wiring so every handler uses it
+        let environment_id = match &args.environment {
+            Some(wanted) => {
+                let environment_repo = SqliteMcpEnvironmentRepository::new(pool.clone());
+                let environments = environment_repo.list().await?;
+                Some(find_by_id_or_name(
+                    "Environment",
+                    wanted,
+                    environments.iter().map(|e| (e.id.as_str(), e.name.as_str())),
+                )?)
+            }
+            None => None,
+        };
+
+        // No window to notify: events are only logged. OAuth uses saved tokens only, there's
+        // no browser to sign in with
+        let Handlers {
+            client_manager,
+            mcp_cmd_handler,
+            mcp_query_handler,
+            collection_cmd_handler,
+            collection_query_handler,
+            test_run_cmd_handler,
+            environment_query_handler,
+            ..
+        } = wiring::wire(
+            pool,
+            Arc::new(LogEventPublisher),
+            Arc::new(LogEventPublisher),
+            environment_id,
+        )
+        .await?;
+
+        Ok(Self {
+            client_manager,
+            mcp_cmd_handler,
+            mcp_query_handler,
+            collection_cmd_handler,
+            collection_query_handler,
+            test_run_cmd_handler,
+            environment_query_handler,
+        })
+    }
+
+    /// Print the collections, servers and environments (tab separated: id, name)
+    async fn list(&self) -> Result<(), AppError> {
+        println!("Collections:");
+        for collection in self.collection_query_handler.handle(ListMcpCollectionsQuery).await? {
+            println!("  {}\t{}", collection.id, collection.name);
+        }
+
+        println!("Servers:");
+        for server in self.mcp_query_handler.handle(ListMcpServersQuery).await? {
+            println!("  {}\t{}", server.id, server.name);
+        }
+
+        let active = self.environment_query_handler.handle(GetActiveMcpEnvironmentQuery).await?;
+        println!("Environments:");
+        for environment in self.environment_query_handler.handle(ListMcpEnvironmentsQuery).await? {
+            let marker = match &active {
+                Some(active) if active.id == environment.id => "\t(active)",
+                _ => "",
+            };
+            println!("  {}\t{}{}", environment.id, environment.name, marker);
+        }
+        Ok(())
+    }
+
+    async fn run_collection(&self, collection: &str, args: &Args) -> Result<bool, AppError> {
+        let (detail, folder_id, server_id) = self.prepare(collection, args).await?;
+
+        let report = self
+            .collection_cmd_handler
+            .handle(RunMcpCollectionCmd {
+                collection_id: detail.collection.id,
+                folder_id,
+                server_id,
+                stop_on_failure: args.stop_on_failure,
+                timeout_ms: args.timeout_ms,
+            })
+            .await?;
+
+        eprintln!(
+            "{}: {} passed, {} failed, {} skipped in {} ms",
+            report.collection_name,
+            report.passed,
+            report.failed,
+            report.skipped,
+            report.duration_ms
+        );
+        let output = match args.format {
+            ReportFormat::Json => to_json(&report)?,
+            ReportFormat::Junit => infra::junit::collection_report(&report),
+        };
+        write_report(&output, args)?;
+        Ok(report.success)
+    }
+
+    async fn run_tests(&self, collection: &str, args: &Args) -> Result<bool, AppError> {
+        let (detail, folder_id, server_id) = self.prepare(collection, args).await?;
+
+        let detail = self
+            .test_run_cmd_handler
+            .handle(RunMcpTestsCmd {
+                collection_id: detail.collection.id,
+                folder_id,
+                saved_call_ids: None,
+                server_id,
+                stop_on_failure: args.stop_on_failure,
+                timeout_ms: args.timeout_ms,
+            })
+            .await?;
+
+        let run = &detail.run;
+        eprintln!(
+            "{}: {} passed, {} failed, {} skipped in {} ms (test run {})",
+            run.collection_name, run.passed, run.failed, run.skipped, run.duration_ms, run.id
+        );
+        let output = match args.format {
+            ReportFormat::Json => to_json(&detail)?,
+            ReportFormat::Junit => infra::junit::test_run_report(&detail),
+        };
+        write_report(&output, args)?;
+        Ok(detail.run.success)
+    }
+
+    /// Resolve the collection, folder and server, then connect the servers the calls use
+    async fn prepare(
+        &self,
+        collection: &str,
+        args: &Args,
+    ) -> Result<(McpCollectionDetail, Option<String>, Option<String>), AppError> {
+        let collections = self.collection_query_handler.handle(ListMcpCollectionsQuery).await?;
+        let collection_id = find_by_id_or_name(
+            "Collection",
+            collection,
+            collections.iter().map(|c| (c.id.as_str(), c.name.as_str())),
+        )?;
+        let detail = self
+            .collection_query_handler
+            .handle(GetMcpCollectionQuery { id: collection_id })
+            .await?;
+
+        let folder_id = match &args.folder {
+            Some(folder) => Some(find_by_id_or_name(
+                "Folder",
+                folder,
+                detail.folders.iter().map(|f| (f.id.as_str(), f.name.as_str())),
+            )?),
+            None => None,
+        };
+
+        let server_id = match &args.server {
+            Some(server) => {
+                let servers = self.mcp_query_handler.handle(ListMcpServersQuery).await?;
+                Some(find_by_id_or_name(
+                    "Server",
+                    server,
+                    servers.iter().map(|s| (s.id.as_str(), s.name.as_str())),
+                )?)
+            }
+            None => None,
+        };
+
+        // Servers of the calls that will run; a server that can't be connected is reported
+        // and its calls fail in the report
+        let server_ids: HashSet<String> = match &server_id {
+            Some(id) => HashSet::from([id.clone()]),
+            None => {
+                let folders = folder_subtree(&detail, folder_id.as_deref());
+                detail
+                    .calls
+                    .iter()
+                    .filter(|call| folder_id.is_none() || folders.contains(&call.folder_id))
+                    .filter_map(|call| call.server_id.clone())
+                    .collect()
+            }
+        };
+        for id in server_ids {
+            if let Err(e) =
+                self.mcp_cmd_handler.handle(ConnectMcpServerCmd { id: id.clone() }).await
+            {
+                eprintln!("warning: Failed to connect to server {}: {}", id, e);
+            }
+        }
+
+        Ok((detail, folder_id, server_id))
+    }
+}
+
+/// A folder and its subfolders (every folder of the collection with no folder given)
+fn folder_subtree(
+    detail: &McpCollectionDetail,
+    folder_id: Option<&str>,
+) -> HashSet<Option<String>> {
+    let Some(folder_id) = folder_id else {
+        return detail.folders.iter().map(|f| Some(f.id.clone())).chain([None]).collect();
+    };
+
+    let mut subtree = HashSet::from([Some(folder_id.to_string())]);
+    loop {
+        let before = subtree.len();
+        for folder in &detail.folders {
+            if subtree.contains(&folder.parent_id) {
+                subtree.insert(Some(folder.id.clone()));
+            }
+        }
+        if subtree.len() == before {
+            return subtree;
+        }
+    }
+}
+
+/// Id of the item whose id or (unique) name is `wanted`
+fn find_by_id_or_name<'a>(
+    kind: &str,
+    wanted: &str,
+    items: impl Iterator<Item = (&'a str, &'a str)>,
+) -> Result<String, AppError> {
+    let items: Vec<(&str, &str)> = items.collect();
+    if let Some((id, _)) = items.iter().find(|(id, _)| *id == wanted) {
+        return Ok(id.to_string());
+    }
+
+    let named: Vec<&str> =
+        items.iter().filter(|(_, name)| *name == wanted).map(|(id, _)| *id).collect();
+    match named.as_slice() {
+        [id] => Ok(id.to_string()),
+        [] => Err(AppError::NotFound(format!("{} '{}' not found", kind, wanted))),
+        ids => Err(AppError::Domain(format!(
+            "{} name '{}' is ambiguous, use one of the ids: {}",
+            kind,
+            wanted,
+            ids.join(", ")
+        ))),
+    }
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<String, AppError> {
+    serde_json::to_string_pretty(value)
+        .map_err(|e| AppError::Unknown(format!("Failed to serialize report: {}", e)))
+}
+
+fn write_report(output: &str, args: &Args) -> Result<(), AppError> {
+    match &args.output {
+        Some(path) => Ok(std::fs::write(path, output)?),
+        None => {
+            println!("{}", output.trim_end());
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,283 @@
+//! Collection domain - saved tool calls, grouped in folders like requests in an API client.
+//!
+//! A collection holds folders (which can nest) and saved calls. Folders and calls share
+//! one `position` order per container, so a collection runs top to bottom exactly as it
+//! is shown.
+
+use crate::domain::cqrs::{Command, Query};
+use crate::domain::mcp::McpToolCallResult;
+use crate::domain::test_run::McpAssertion;
+use crate::error::AppError;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// Named group of saved calls
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpCollection {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub position: i64, // Lower comes first
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Folder inside a collection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpCollectionFolder {
+    pub id: String,
+    pub collection_id: String,
+    pub parent_id: Option<String>, // None = top level of the collection
+    pub name: String,
+    pub position: i64, // Lower comes first, among the folders and calls of the same parent
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// A tool call saved for reuse
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpSavedCall {
+    pub id: String,
+    pub collection_id: String,
+    pub folder_id: Option<String>, // None = top level of the collection
+    pub name: String,
+    pub server_id: Option<String>, // None once the server is deleted
+    pub tool_name: String,
+    pub params: Option<String>, // JSON string
+    pub notes: Option<String>,
+    pub assertions: Vec<McpAssertion>, // Checked by test runs
+    pub position: i64, // Lower comes first, among the folders and calls of the same parent
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// A collection with everything in it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpCollectionDetail {
+    pub collection: McpCollection,
+    pub folders: Vec<McpCollectionFolder>,
+    pub calls: Vec<McpSavedCall>,
+}
+
+/// Outcome of one saved call in a collection run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpCollectionRunResult {
+    pub saved_call_id: String,
+    pub name: String,
+    pub folder_path: Vec<String>, // Folder names from the top of the collection
+    pub server_id: Option<String>,
+    pub tool_name: String,
+    pub status: String, // 'success', 'error', 'cancelled', 'timeout' or 'skipped'
+    /// Call succeeded and the tool didn't report an error
+    pub success: bool,
+    pub result: Option<McpToolCallResult>,
+    pub error: Option<String>,
+    pub history_id: Option<String>,
+    pub duration_ms: i64,
+}
+
+/// Report of a collection run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpCollectionRunReport {
+    pub collection_id: String,
+    pub collection_name: String,
+    pub folder_id: Option<String>, // Set when only one folder was run
+    pub started_at: String,
+    pub duration_ms: i64,
+    pub total: usize,
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub success: bool, // No call failed
+    pub results: Vec<McpCollectionRunResult>,
+}
+
+// ============ Commands ============
+
+/// Command to create a collection (added at the end)
+#[derive(Debug, Deserialize)]
+pub struct CreateMcpCollectionCmd {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+impl Command for CreateMcpCollectionCmd {}
+
+/// Command to rename or describe a collection
+#[derive(Debug, Deserialize)]
+pub struct UpdateMcpCollectionCmd {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+impl Command for UpdateMcpCollectionCmd {}
+
+/// Command to delete a collection with its folders and calls
+#[derive(Debug, Deserialize)]
+pub struct DeleteMcpCollectionCmd {
+    pub id: String,
+}
+
+impl Command for DeleteMcpCollectionCmd {}
+
+/// Command to put collections in the given order
+#[derive(Debug, Deserialize)]
+pub struct ReorderMcpCollectionsCmd {
+    pub ids: Vec<String>, // Every collection, in the new order
+}
+
+impl Command for ReorderMcpCollectionsCmd {}
+
+/// Command to create a folder (added at the end of its parent)
+#[derive(Debug, Deserialize)]
+pub struct CreateMcpCollectionFolderCmd {
+    pub collection_id: String,
+    pub parent_id: Option<String>,
+    pub name: String,
+}
+
+impl Command for CreateMcpCollectionFolderCmd {}
+
+/// Command to rename a folder or move it to another parent
+#[derive(Debug, Deserialize)]
+pub struct UpdateMcpCollectionFolderCmd {
+    pub id: String,
+    pub parent_id: Option<String>,
+    pub name: String,
+}
+
+impl Command for UpdateMcpCollectionFolderCmd {}
+
+/// Command to delete a folder with everything in it
+#[derive(Debug, Deserialize)]
+pub struct DeleteMcpCollectionFolderCmd {
+    pub id: String,
+}
+
+impl Command for DeleteMcpCollectionFolderCmd {}
+
+/// Command to save a tool call (added at the end of its folder)
+#[derive(Debug, Deserialize)]
+pub struct CreateMcpSavedCallCmd {
+    pub collection_id: String,
+    pub folder_id: Option<String>,
+    pub name: String,
+    pub server_id: Option<String>,
+    pub tool_name: String,
+    pub params: Option<serde_json::Value>,
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub assertions: Vec<McpAssertion>,
+}
+
+impl Command for CreateMcpSavedCallCmd {}
+
+/// Command to edit a saved call or move it to another folder
+#[derive(Debug, Deserialize)]
+pub struct UpdateMcpSavedCallCmd {
+    pub id: String,
+    pub folder_id: Option<String>,
+    pub name: String,
+    pub server_id: Option<String>,
+    pub tool_name: String,
+    pub params: Option<serde_json::Value>,
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub assertions: Vec<McpAssertion>,
+}
+
+impl Command for UpdateMcpSavedCallCmd {}
+
+/// Command to delete a saved call
+#[derive(Debug, Deserialize)]
+pub struct DeleteMcpSavedCallCmd {
+    pub id: String,
+}
+
+impl Command for DeleteMcpSavedCallCmd {}
+
+/// Command to put the folders and calls of one parent in the given order
+#[derive(Debug, Deserialize)]
+pub struct ReorderMcpCollectionItemsCmd {
+    pub collection_id: String,
+    pub folder_id: Option<String>, // None = top level of the collection
+    pub item_ids: Vec<String>,     // Every folder and call of the parent, in the new order
+}
+
+impl Command for ReorderMcpCollectionItemsCmd {}
+
+/// Command to run the calls of a collection one after another
+#[derive(Debug, Deserialize)]
+pub struct RunMcpCollectionCmd {
+    pub collection_id: String,
+    /// Run only this folder (and its subfolders)
+    #[serde(default)]
+    pub folder_id: Option<String>,
+    /// Send every call to this server instead of the saved one
+    #[serde(default)]
+    pub server_id: Option<String>,
+    /// Skip the remaining calls after the first failure
+    #[serde(default)]
+    pub stop_on_failure: bool,
+    /// Per call, defaults to 30 seconds
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+}
+
+impl Command for RunMcpCollectionCmd {}
+
+// ============ Queries ============
+
+/// Query to list collections in order
+#[derive(Debug)]
+pub struct ListMcpCollectionsQuery;
+
+impl Query for ListMcpCollectionsQuery {}
+
+/// Query to open a collection with its folders and calls
+#[derive(Debug)]
+pub struct GetMcpCollectionQuery {
+    pub id: String,
+}
+
+impl Query for GetMcpCollectionQuery {}
+
+// ============ Repository Interfaces ============
+
+#[async_trait]
+pub trait IMcpCollectionRepository: Send + Sync {
+    async fn create(&self, collection: McpCollection) -> Result<McpCollection, AppError>;
+    async fn update(&self, collection: McpCollection) -> Result<McpCollection, AppError>;
+    async fn delete(&self, id: &str) -> Result<(), AppError>;
+    async fn find_by_id(&self, id: &str) -> Result<Option<McpCollection>, AppError>;
+    async fn list(&self) -> Result<Vec<McpCollection>, AppError>;
+    /// Number the collections in the given order, in one transaction
+    async fn reorder(&self, ids: &[String]) -> Result<(), AppError>;
+    /// Set the positions of folders and saved calls (`(id, position)`), in one transaction
+    async fn reorder_items(
+        &self,
+        folders: &[(&str, i64)],
+        calls: &[(&str, i64)],
+    ) -> Result<(), AppError>;
+}
+
+#[async_trait]
+pub trait IMcpCollectionFolderRepository: Send + Sync {
+    async fn create(&self, folder: McpCollectionFolder) -> Result<McpCollectionFolder, AppError>;
+    async fn update(&self, folder: McpCollectionFolder) -> Result<McpCollectionFolder, AppError>;
+    async fn delete(&self, id: &str) -> Result<(), AppError>;
+    async fn find_by_id(&self, id: &str) -> Result<Option<McpCollectionFolder>, AppError>;
+    /// Folders of a collection, by position
+    async fn list(&self, collection_id: &str) -> Result<Vec<McpCollectionFolder>, AppError>;
+}
+
+#[async_trait]
+pub trait IMcpSavedCallRepository: Send + Sync {
+    async fn create(&self, call: McpSavedCall) -> Result<McpSavedCall, AppError>;
+    async fn update(&self, call: McpSavedCall) -> Result<McpSavedCall, AppError>;
+    async fn delete(&self, id: &str) -> Result<(), AppError>;
+    async fn find_by_id(&self, id: &str) -> Result<Option<McpSavedCall>, AppError>;
+    /// Calls of a collection, by position
+    async fn list(&self, collection_id: &str) -> Result<Vec<McpSavedCall>, AppError>;
+}
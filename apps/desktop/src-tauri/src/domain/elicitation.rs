@@ -0,0 +1,145 @@
+//! Elicitation domain - answering `elicitation/create` requests sent by MCP servers.
+//!
+//! The server's requested schema is shown to the user, who accepts (with content),
+//! declines or cancels. Optional rules answer automatically for unattended runs.
+
+use crate::domain::cqrs::{Command, Query};
+use crate::domain::rule_match::RuleMatchType;
+use crate::error::AppError;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// Answer sent back for an elicitation request
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ElicitationAction {
+    Accept,
+    Decline,
+    Cancel,
+}
+
+impl std::fmt::Display for ElicitationAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Accept => write!(f, "accept"),
+            Self::Decline => write!(f, "decline"),
+            Self::Cancel => write!(f, "cancel"),
+        }
+    }
+}
+
+impl From<String> for ElicitationAction {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "accept" => Self::Accept,
+            "decline" => Self::Decline,
+            _ => Self::Cancel,
+        }
+    }
+}
+
+/// User-defined rule answering elicitation requests automatically
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ElicitationRule {
+    pub id: String,
+    pub name: String,
+    pub server_id: Option<String>, // None = applies to every server
+    pub match_type: RuleMatchType,
+    pub pattern: String, // Matched against the request message
+    pub action: ElicitationAction,
+    pub content: Option<String>, // JSON string sent with 'accept'
+    pub enabled: bool,
+    pub priority: i64, // Lower runs first
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Elicitation request waiting for an answer from the UI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingElicitationRequest {
+    pub request_id: String,
+    pub server_id: String,
+    pub message: String,
+    pub requested_schema: serde_json::Value,
+    pub created_at: String,
+}
+
+// ============ Commands ============
+
+/// Command to create an elicitation rule
+#[derive(Debug, Deserialize)]
+pub struct CreateElicitationRuleCmd {
+    pub name: String,
+    pub server_id: Option<String>,
+    #[serde(default)]
+    pub match_type: RuleMatchType,
+    #[serde(default)]
+    pub pattern: String,
+    pub action: ElicitationAction,
+    pub content: Option<serde_json::Value>,
+    pub enabled: Option<bool>,
+    pub priority: Option<i64>,
+}
+
+impl Command for CreateElicitationRuleCmd {}
+
+/// Command to update an elicitation rule
+#[derive(Debug, Deserialize)]
+pub struct UpdateElicitationRuleCmd {
+    pub id: String,
+    pub name: String,
+    pub server_id: Option<String>,
+    #[serde(default)]
+    pub match_type: RuleMatchType,
+    #[serde(default)]
+    pub pattern: String,
+    pub action: ElicitationAction,
+    pub content: Option<serde_json::Value>,
+    pub enabled: bool,
+    pub priority: i64,
+}
+
+impl Command for UpdateElicitationRuleCmd {}
+
+/// Command to delete an elicitation rule
+#[derive(Debug, Deserialize)]
+pub struct DeleteElicitationRuleCmd {
+    pub id: String,
+}
+
+impl Command for DeleteElicitationRuleCmd {}
+
+/// Command to answer a pending elicitation request
+#[derive(Debug, Deserialize)]
+pub struct RespondElicitationRequestCmd {
+    pub request_id: String,
+    pub action: ElicitationAction,
+    pub content: Option<serde_json::Value>,
+}
+
+impl Command for RespondElicitationRequestCmd {}
+
+// ============ Queries ============
+
+/// Query to list elicitation rules
+#[derive(Debug)]
+pub struct ListElicitationRulesQuery;
+
+impl Query for ListElicitationRulesQuery {}
+
+/// Query to list elicitation requests waiting for an answer
+#[derive(Debug)]
+pub struct ListPendingElicitationRequestsQuery;
+
+impl Query for ListPendingElicitationRequestsQuery {}
+
+// ============ Repository Interfaces ============
+
+#[async_trait]
+pub trait IElicitationRuleRepository: Send + Sync {
+    async fn create(&self, rule: ElicitationRule) -> Result<ElicitationRule, AppError>;
+    async fn update(&self, rule: ElicitationRule) -> Result<ElicitationRule, AppError>;
+    async fn delete(&self, id: &str) -> Result<(), AppError>;
+    async fn find_by_id(&self, id: &str) -> Result<Option<ElicitationRule>, AppError>;
+    async fn list(&self) -> Result<Vec<ElicitationRule>, AppError>;
+}
@@ -0,0 +1,99 @@
+//! Environment domain - named sets of variables substituted into tool calls and server settings.
+//!
+//! `{{name}}` placeholders in tool call params and in server URLs, headers, credentials and
+//! stdio environment variables are replaced with the variables of the active environment
+//! before anything is sent. Variables flagged as secret are masked in call history.
+
+use crate::domain::cqrs::{Command, Query};
+use crate::error::AppError;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// `system_settings` key holding the id of the active environment (empty = none)
+pub const ACTIVE_ENVIRONMENT_KEY: &str = "active_environment";
+
+/// Text recorded in place of a secret value
+pub const SECRET_MASK: &str = "******";
+
+/// One variable of an environment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpEnvironmentVariable {
+    pub key: String, // Letters, digits, '_', '-' and '.'
+    pub value: String,
+    #[serde(default)]
+    pub secret: bool, // Masked in history
+}
+
+/// Named set of variables (e.g. dev, staging, prod)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpEnvironment {
+    pub id: String,
+    pub name: String,
+    pub variables: Vec<McpEnvironmentVariable>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+// ============ Commands ============
+
+/// Command to create an environment
+#[derive(Debug, Deserialize)]
+pub struct CreateMcpEnvironmentCmd {
+    pub name: String,
+    #[serde(default)]
+    pub variables: Vec<McpEnvironmentVariable>,
+}
+
+impl Command for CreateMcpEnvironmentCmd {}
+
+/// Command to update an environment
+#[derive(Debug, Deserialize)]
+pub struct UpdateMcpEnvironmentCmd {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub variables: Vec<McpEnvironmentVariable>,
+}
+
+impl Command for UpdateMcpEnvironmentCmd {}
+
+/// Command to delete an environment
+#[derive(Debug, Deserialize)]
+pub struct DeleteMcpEnvironmentCmd {
+    pub id: String,
+}
+
+impl Command for DeleteMcpEnvironmentCmd {}
+
+/// Command to choose the environment used for substitution
+#[derive(Debug, Deserialize)]
+pub struct SetActiveMcpEnvironmentCmd {
+    pub id: Option<String>, // None = no substitution
+}
+
+impl Command for SetActiveMcpEnvironmentCmd {}
+
+// ============ Queries ============
+
+/// Query to list environments
+#[derive(Debug)]
+pub struct ListMcpEnvironmentsQuery;
+
+impl Query for ListMcpEnvironmentsQuery {}
+
+/// Query to get the active environment
+#[derive(Debug)]
+pub struct GetActiveMcpEnvironmentQuery;
+
+impl Query for GetActiveMcpEnvironmentQuery {}
+
+// ============ Repository Interfaces ============
+
+#[async_trait]
+pub trait IMcpEnvironmentRepository: Send + Sync {
+    async fn create(&self, environment: McpEnvironment) -> Result<McpEnvironment, AppError>;
+    async fn update(&self, environment: McpEnvironment) -> Result<McpEnvironment, AppError>;
+    async fn delete(&self, id: &str) -> Result<(), AppError>;
+    async fn find_by_id(&self, id: &str) -> Result<Option<McpEnvironment>, AppError>;
+    async fn list(&self) -> Result<Vec<McpEnvironment>, AppError>;
+}
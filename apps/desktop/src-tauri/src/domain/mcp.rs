@@ -6,6 +6,7 @@ use crate::domain::cqrs::{Command, Query};
 use crate::error::AppError;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 // ============ Entities ============
 
@@ -55,6 +56,8 @@ pub enum McpServerType {
     Stdio,
     #[serde(rename = "streamable_http")]
     StreamableHttp,
+    /// JSON-RPC over a WebSocket (`ws://` / `wss://`)
+    WebSocket,
 }
 
 impl Default for McpServerType {
@@ -69,6 +72,7 @@ impl std::fmt::Display for McpServerType {
             McpServerType::Sse => write!(f, "sse"),
             McpServerType::Stdio => write!(f, "stdio"),
             McpServerType::StreamableHttp => write!(f, "streamable_http"),
+            McpServerType::WebSocket => write!(f, "websocket"),
         }
     }
 }
@@ -78,11 +82,96 @@ impl From<String> for McpServerType {
         match s.to_lowercase().as_str() {
             "stdio" => McpServerType::Stdio,
             "streamable_http" => McpServerType::StreamableHttp,
+            "websocket" => McpServerType::WebSocket,
             "sse" | _ => McpServerType::Sse,
         }
     }
 }
 
+/// How the health of a connection is checked
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HeartbeatStrategy {
+    /// MCP `ping` request
+    #[default]
+    Ping,
+    /// Full `tools/list` (for servers that don't answer ping)
+    ListTools,
+    /// No heartbeat; only transport errors end the connection
+    None,
+}
+
+impl std::fmt::Display for HeartbeatStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ping => write!(f, "ping"),
+            Self::ListTools => write!(f, "list_tools"),
+            Self::None => write!(f, "none"),
+        }
+    }
+}
+
+impl From<String> for HeartbeatStrategy {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "list_tools" => Self::ListTools,
+            "none" => Self::None,
+            _ => Self::Ping,
+        }
+    }
+}
+
+/// Authentication applied to every HTTP request of a remote server
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum McpServerAuth {
+    #[default]
+    None,
+    /// `Authorization: Bearer <token>`
+    Bearer { token: String },
+    /// `Authorization: Basic <base64(username:password)>`
+    Basic { username: String, password: String },
+    /// OAuth 2.1 authorization code flow; the bearer token comes from the stored credentials
+    #[serde(rename = "oauth")]
+    OAuth {
+        /// Pre-registered client (None = dynamic client registration)
+        #[serde(default)]
+        client_id: Option<String>,
+        #[serde(default)]
+        client_secret: Option<String>,
+        /// Requested scopes (None = scopes advertised by the server)
+        #[serde(default)]
+        scope: Option<String>,
+    },
+}
+
+/// Automatic reconnect after an unexpected connection loss
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct McpReconnectPolicy {
+    pub enabled: bool,
+    /// Attempts before giving up
+    pub max_attempts: u32,
+    /// Delay before the first attempt; doubled after every failure
+    pub initial_delay_ms: u64,
+    /// Upper bound of the delay between attempts
+    pub max_delay_ms: u64,
+}
+
+impl Default for McpReconnectPolicy {
+    fn default() -> Self {
+        Self { enabled: false, max_attempts: 5, initial_delay_ms: 1_000, max_delay_ms: 30_000 }
+    }
+}
+
+impl McpReconnectPolicy {
+    /// Backoff delay (without jitter) before the given attempt, starting at 1
+    pub fn delay_ms(&self, attempt: u32) -> u64 {
+        let factor = 2u64.saturating_pow(attempt.saturating_sub(1));
+        self.initial_delay_ms.saturating_mul(factor).min(self.max_delay_ms)
+    }
+}
+
 /// MCP Server entity
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct McpServer {
@@ -90,12 +179,62 @@ pub struct McpServer {
     pub name: String,
     pub url: String,
     pub server_type: McpServerType,
+    /// Executable to spawn (stdio servers only)
+    #[serde(default)]
+    pub command: Option<String>,
+    /// Command line arguments (stdio servers only)
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Extra environment variables for the child process (stdio servers only)
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+    /// Working directory for the child process (stdio servers only)
+    #[serde(default)]
+    pub cwd: Option<String>,
+    /// Roots returned to the server for roots/list
+    #[serde(default)]
+    pub roots: Vec<McpRoot>,
+    /// Extra HTTP headers sent with every request (remote transports only)
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    /// Authentication (remote transports only)
+    #[serde(default)]
+    pub auth: McpServerAuth,
+    /// Health check used while connected
+    #[serde(default)]
+    pub heartbeat_strategy: HeartbeatStrategy,
+    /// Seconds between heartbeats (None = global `heartbeat_interval` setting, else 10)
+    #[serde(default)]
+    pub heartbeat_interval_secs: Option<i64>,
+    /// Consecutive failed heartbeats before disconnecting (None = 1, the first failure)
+    #[serde(default)]
+    pub heartbeat_max_failures: Option<i64>,
+    /// Reconnect policy applied when the connection drops
+    #[serde(default)]
+    pub reconnect_policy: McpReconnectPolicy,
+    /// Open the standalone GET SSE stream for server-initiated messages (streamable HTTP only)
+    #[serde(default = "default_true")]
+    pub standalone_sse: bool,
+    /// Reopen a dropped GET stream instead of ending the connection (legacy SSE only)
+    #[serde(default)]
+    pub sse_reconnect: bool,
     pub status: McpServerStatus,
     pub last_error: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
 
+fn default_true() -> bool {
+    true
+}
+
+/// Root (file URI plus optional name) exposed to a server
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct McpRoot {
+    pub uri: String,
+    pub name: Option<String>,
+}
+
 /// MCP Tool entity (cached from server)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct McpTool {
@@ -109,13 +248,153 @@ pub struct McpTool {
     pub created_at: String,
 }
 
+/// MCP Resource entity (cached from server)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpResource {
+    pub server_id: String,
+    pub uri: String,
+    pub name: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub mime_type: Option<String>,
+    pub size: Option<u32>,
+    pub extra: Option<String>, // JSON string (annotations, _meta, etc.)
+}
+
+/// MCP Resource template entity (cached from server)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpResourceTemplate {
+    pub server_id: String,
+    pub uri_template: String,
+    pub name: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub mime_type: Option<String>,
+    pub extra: Option<String>, // JSON string (annotations, _meta, etc.)
+}
+
+/// MCP Prompt entity (cached from server)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpPrompt {
+    pub server_id: String,
+    pub name: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub arguments: Vec<McpPromptArgument>,
+    pub extra: Option<String>, // JSON string (icons, _meta, etc.)
+}
+
+/// Argument declared by an MCP prompt
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpPromptArgument {
+    pub name: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub required: bool,
+}
+
 /// MCP Call History entry
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct McpCallHistory {
     pub id: String,
     pub server_id: String,
     pub tool_name: String,
-    pub input_params: Option<String>,  // JSON string
+    pub input_params: Option<String>, // JSON string, resolved with secrets masked
+    pub output_result: Option<String>, // JSON string (raw response)
+    pub status: String,               // 'success', 'error', 'cancelled' or 'timeout'
+    pub error_message: Option<String>,
+    pub duration_ms: Option<i64>,
+    pub created_at: String,
+    /// History entry this call re-ran, if it was a replay
+    #[serde(default)]
+    pub replayed_from: Option<String>,
+    /// Params as written, when they had `{{variable}}` placeholders (JSON string)
+    #[serde(default)]
+    pub input_template: Option<String>,
+}
+
+/// One difference between two JSON documents
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpJsonChange {
+    pub path: String, // JSONPath of the value, e.g. `$.content[0].text`
+    pub kind: String, // 'added', 'removed' or 'changed'
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+}
+
+/// Output of a replayed call compared with the original
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpCallDiff {
+    pub identical: bool,
+    pub status_changed: bool,
+    pub changes: Vec<McpJsonChange>,
+}
+
+/// Result of replaying a call from history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpCallReplay {
+    pub original: McpCallHistory,
+    /// New history entry, linked to the original
+    pub replay: McpCallHistory,
+    pub result: McpToolCallResult,
+    pub diff: McpCallDiff,
+}
+
+/// One heartbeat round trip
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpHeartbeat {
+    pub id: String,
+    pub server_id: String,
+    pub strategy: HeartbeatStrategy,
+    pub success: bool,
+    pub rtt_ms: i64,
+    pub error_message: Option<String>,
+    pub created_at: String,
+}
+
+/// Live details of a connection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpConnectionInfo {
+    pub server_id: String,
+    pub server_type: McpServerType,
+    /// Initialize result (protocol version, capabilities, implementation, instructions)
+    pub server_info: serde_json::Value,
+    /// Streamable HTTP session (None for other transports)
+    pub session: Option<McpStreamableSession>,
+    /// Id the connection's traffic frames are recorded under
+    pub connection_id: String,
+}
+
+/// Session state of a streamable HTTP connection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpStreamableSession {
+    /// `Mcp-Session-Id` assigned by the server (None = stateless server)
+    pub session_id: Option<String>,
+    pub standalone_sse: bool,
+    /// Id of the last SSE event received, sent as `Last-Event-ID` when a stream is resumed
+    pub last_event_id: Option<String>,
+    /// Streams resumed after being dropped
+    pub resumptions: u32,
+    /// Session ended with `DELETE`
+    pub terminated: bool,
+}
+
+/// Outcome of an explicit session termination
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpSessionTermination {
+    pub server_id: String,
+    pub session_id: String,
+    /// HTTP status of the `DELETE` (405 = server doesn't allow clients to end sessions)
+    pub status: u16,
+}
+
+/// MCP Prompt History entry (one per prompts/get)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpPromptHistory {
+    pub id: String,
+    pub server_id: String,
+    pub prompt_name: String,
+    pub arguments: Option<String>,     // JSON string
     pub output_result: Option<String>, // JSON string (raw response)
     pub status: String,                // 'success' or 'error'
     pub error_message: Option<String>,
@@ -123,6 +402,21 @@ pub struct McpCallHistory {
     pub created_at: String,
 }
 
+/// Raw JSON-RPC exchange (one per message sent from the console)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpRawRpcHistory {
+    pub id: String,
+    pub server_id: String,
+    pub kind: String, // 'request', 'notification' or 'invalid' (not JSON-RPC)
+    pub method: Option<String>, // None when the message has no method
+    pub request: String, // Raw text as sent
+    pub response: Option<String>, // Raw reply (or body of a rejected HTTP request)
+    pub status: String, // 'success', 'error', 'timeout' or 'sent' (notification)
+    pub error_message: Option<String>,
+    pub duration_ms: Option<i64>,
+    pub created_at: String,
+}
+
 /// HTTP Received Message entity
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HttpReceivedMessage {
@@ -145,6 +439,32 @@ pub struct CreateMcpServerCmd {
     pub url: String,
     #[serde(default)]
     pub server_type: McpServerType,
+    #[serde(default)]
+    pub command: Option<String>,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+    #[serde(default)]
+    pub cwd: Option<String>,
+    #[serde(default)]
+    pub roots: Vec<McpRoot>,
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    #[serde(default)]
+    pub auth: McpServerAuth,
+    #[serde(default)]
+    pub heartbeat_strategy: HeartbeatStrategy,
+    #[serde(default)]
+    pub heartbeat_interval_secs: Option<i64>,
+    #[serde(default)]
+    pub heartbeat_max_failures: Option<i64>,
+    #[serde(default)]
+    pub reconnect_policy: McpReconnectPolicy,
+    #[serde(default = "default_true")]
+    pub standalone_sse: bool,
+    #[serde(default)]
+    pub sse_reconnect: bool,
 }
 
 impl Command for CreateMcpServerCmd {}
@@ -157,10 +477,58 @@ pub struct UpdateMcpServerCmd {
     pub url: String,
     #[serde(default)]
     pub server_type: McpServerType,
+    #[serde(default)]
+    pub command: Option<String>,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+    #[serde(default)]
+    pub cwd: Option<String>,
+    // Fields below keep their stored value when omitted
+    #[serde(default)]
+    pub roots: Option<Vec<McpRoot>>,
+    #[serde(default)]
+    pub headers: Option<HashMap<String, String>>,
+    #[serde(default)]
+    pub auth: Option<McpServerAuth>,
+    #[serde(default)]
+    pub standalone_sse: Option<bool>,
+    #[serde(default)]
+    pub sse_reconnect: Option<bool>,
 }
 
 impl Command for UpdateMcpServerCmd {}
 
+/// Command to replace the roots of an MCP server
+#[derive(Debug, Deserialize)]
+pub struct SetMcpServerRootsCmd {
+    pub server_id: String,
+    pub roots: Vec<McpRoot>,
+}
+
+impl Command for SetMcpServerRootsCmd {}
+
+/// Command to change the heartbeat settings of an MCP server (applied to a live connection)
+#[derive(Debug, Deserialize)]
+pub struct SetMcpServerHeartbeatCmd {
+    pub server_id: String,
+    pub strategy: HeartbeatStrategy,
+    pub interval_secs: Option<i64>,
+    pub max_failures: Option<i64>,
+}
+
+impl Command for SetMcpServerHeartbeatCmd {}
+
+/// Command to change the reconnect policy of an MCP server
+#[derive(Debug, Deserialize)]
+pub struct SetMcpServerReconnectPolicyCmd {
+    pub server_id: String,
+    pub policy: McpReconnectPolicy,
+}
+
+impl Command for SetMcpServerReconnectPolicyCmd {}
+
 /// Command to delete an MCP server
 #[derive(Debug, Deserialize)]
 pub struct DeleteMcpServerCmd {
@@ -185,6 +553,14 @@ pub struct DisconnectMcpServerCmd {
 
 impl Command for DisconnectMcpServerCmd {}
 
+/// Command to end the streamable HTTP session of a server with `DELETE` and disconnect
+#[derive(Debug, Deserialize)]
+pub struct TerminateMcpSessionCmd {
+    pub server_id: String,
+}
+
+impl Command for TerminateMcpSessionCmd {}
+
 /// Command to mark MCP server as disconnected (used when connection is lost)
 #[derive(Debug, Deserialize)]
 pub struct MarkMcpServerDisconnectedCmd {
@@ -200,10 +576,38 @@ pub struct CallMcpToolCmd {
     pub server_id: String,
     pub tool_name: String,
     pub params: Option<serde_json::Value>,
+    /// Caller-chosen id used to cancel the call and match progress events (generated if absent)
+    #[serde(default)]
+    pub call_id: Option<String>,
+    /// Defaults to 30 seconds
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
 }
 
 impl Command for CallMcpToolCmd {}
 
+/// Command to re-run a tool call from history
+#[derive(Debug, Deserialize)]
+pub struct ReplayMcpCallCmd {
+    pub history_id: String,
+    /// Server to run it on (defaults to the one the original call went to)
+    #[serde(default)]
+    pub server_id: Option<String>,
+    /// Defaults to 30 seconds
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+}
+
+impl Command for ReplayMcpCallCmd {}
+
+/// Command to cancel an in-flight tool call
+#[derive(Debug, Deserialize)]
+pub struct CancelMcpToolCallCmd {
+    pub call_id: String,
+}
+
+impl Command for CancelMcpToolCallCmd {}
+
 /// Command to refresh tools list from server
 #[derive(Debug, Deserialize)]
 pub struct RefreshMcpToolsCmd {
@@ -212,6 +616,70 @@ pub struct RefreshMcpToolsCmd {
 
 impl Command for RefreshMcpToolsCmd {}
 
+/// Command to refresh resources (and resource templates) from server
+#[derive(Debug, Deserialize)]
+pub struct RefreshMcpResourcesCmd {
+    pub server_id: String,
+}
+
+impl Command for RefreshMcpResourcesCmd {}
+
+/// Command to read a resource
+#[derive(Debug, Deserialize)]
+pub struct ReadMcpResourceCmd {
+    pub server_id: String,
+    pub uri: String,
+}
+
+impl Command for ReadMcpResourceCmd {}
+
+/// Command to subscribe to resource updates
+#[derive(Debug, Deserialize)]
+pub struct SubscribeMcpResourceCmd {
+    pub server_id: String,
+    pub uri: String,
+}
+
+impl Command for SubscribeMcpResourceCmd {}
+
+/// Command to unsubscribe from resource updates
+#[derive(Debug, Deserialize)]
+pub struct UnsubscribeMcpResourceCmd {
+    pub server_id: String,
+    pub uri: String,
+}
+
+impl Command for UnsubscribeMcpResourceCmd {}
+
+/// Command to refresh prompts list from server
+#[derive(Debug, Deserialize)]
+pub struct RefreshMcpPromptsCmd {
+    pub server_id: String,
+}
+
+impl Command for RefreshMcpPromptsCmd {}
+
+/// Command to get (render) a prompt with arguments
+#[derive(Debug, Deserialize)]
+pub struct GetMcpPromptCmd {
+    pub server_id: String,
+    pub prompt_name: String,
+    pub arguments: Option<serde_json::Value>,
+}
+
+impl Command for GetMcpPromptCmd {}
+
+/// Command to send a hand-written JSON-RPC message over an existing connection
+#[derive(Debug, Deserialize)]
+pub struct SendMcpRawRpcCmd {
+    pub server_id: String,
+    /// Sent as is; need not be valid JSON-RPC
+    pub message: String,
+    pub timeout_ms: Option<u64>,
+}
+
+impl Command for SendMcpRawRpcCmd {}
+
 /// Command to save HTTP received message
 #[derive(Debug, Deserialize)]
 pub struct SaveHttpReceivedMessageCmd {
@@ -257,6 +725,30 @@ pub struct GetMcpToolsQuery {
 
 impl Query for GetMcpToolsQuery {}
 
+/// Query to get resources for a specific server
+#[derive(Debug)]
+pub struct GetMcpResourcesQuery {
+    pub server_id: String,
+}
+
+impl Query for GetMcpResourcesQuery {}
+
+/// Query to get resource templates for a specific server
+#[derive(Debug)]
+pub struct GetMcpResourceTemplatesQuery {
+    pub server_id: String,
+}
+
+impl Query for GetMcpResourceTemplatesQuery {}
+
+/// Query to get the resource URIs subscribed to on a server
+#[derive(Debug)]
+pub struct GetMcpResourceSubscriptionsQuery {
+    pub server_id: String,
+}
+
+impl Query for GetMcpResourceSubscriptionsQuery {}
+
 /// Query to get call history
 #[derive(Debug)]
 pub struct GetMcpCallHistoryQuery {
@@ -266,6 +758,49 @@ pub struct GetMcpCallHistoryQuery {
 
 impl Query for GetMcpCallHistoryQuery {}
 
+/// Query to get prompts for a specific server
+#[derive(Debug)]
+pub struct GetMcpPromptsQuery {
+    pub server_id: String,
+}
+
+impl Query for GetMcpPromptsQuery {}
+
+/// Query to get prompt history
+#[derive(Debug)]
+pub struct GetMcpPromptHistoryQuery {
+    pub server_id: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl Query for GetMcpPromptHistoryQuery {}
+
+/// Query to get raw JSON-RPC history
+#[derive(Debug)]
+pub struct GetMcpRawRpcHistoryQuery {
+    pub server_id: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl Query for GetMcpRawRpcHistoryQuery {}
+
+/// Query to get recent heartbeats of a server (newest first)
+#[derive(Debug)]
+pub struct GetMcpHeartbeatsQuery {
+    pub server_id: String,
+    pub limit: Option<i64>,
+}
+
+impl Query for GetMcpHeartbeatsQuery {}
+
+/// Query to get the live connection details of a server
+#[derive(Debug)]
+pub struct GetMcpConnectionInfoQuery {
+    pub server_id: String,
+}
+
+impl Query for GetMcpConnectionInfoQuery {}
+
 /// Query to list HTTP received messages
 #[derive(Debug)]
 pub struct ListHttpReceivedMessagesQuery {
@@ -279,6 +814,8 @@ impl Query for ListHttpReceivedMessagesQuery {}
 /// Result of calling an MCP tool
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct McpToolCallResult {
+    pub call_id: String,
+    pub status: String, // 'success', 'error', 'cancelled' or 'timeout'
     pub success: bool,
     pub raw_response: String, // Raw JSON response for debugging
     pub result: Option<serde_json::Value>,
@@ -290,7 +827,57 @@ pub struct McpToolCallResult {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct McpToolsListResult {
     pub tools: Vec<McpTool>,
+    pub raw_response: String, // Raw JSON response for debugging (array of pages if paged)
+    pub paging: McpListPaging,
+}
+
+/// Paging details of a cursor-paginated list call
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct McpListPaging {
+    pub page_count: usize,
+    pub raw_pages: Vec<String>, // Raw JSON response of every page, in order
+    pub truncated: bool,        // Stopped at the page cap or on a repeated cursor
+    pub duration_ms: i64,
+}
+
+/// Result of listing resources (includes raw JSON)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpResourcesListResult {
+    pub resources: Vec<McpResource>,
+    pub resource_templates: Vec<McpResourceTemplate>,
+    pub raw_response: String, // Raw resources/list JSON response for debugging
+    pub templates_raw_response: Option<String>, // Raw resources/templates/list JSON response
+    pub paging: McpListPaging,
+    pub templates_paging: Option<McpListPaging>,
+}
+
+/// Result of reading a resource
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpResourceReadResult {
+    pub success: bool,
+    pub raw_response: String, // Raw JSON response for debugging
+    pub contents: Option<serde_json::Value>, // Text / blob contents as returned by the server
+    pub error: Option<String>,
+    pub duration_ms: i64,
+}
+
+/// Result of listing prompts (includes raw JSON)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpPromptsListResult {
+    pub prompts: Vec<McpPrompt>,
+    pub raw_response: String, // Raw JSON response for debugging (array of pages if paged)
+    pub paging: McpListPaging,
+}
+
+/// Result of getting a prompt
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpPromptGetResult {
+    pub success: bool,
     pub raw_response: String, // Raw JSON response for debugging
+    pub description: Option<String>,
+    pub messages: Option<serde_json::Value>, // Rendered prompt messages
+    pub error: Option<String>,
+    pub duration_ms: i64,
 }
 
 // ============ Repository Interfaces ============
@@ -307,6 +894,7 @@ pub trait IMcpServerRepository: Send + Sync {
 #[async_trait]
 pub trait IMcpCallHistoryRepository: Send + Sync {
     async fn create(&self, history: McpCallHistory) -> Result<McpCallHistory, AppError>;
+    async fn find_by_id(&self, id: &str) -> Result<Option<McpCallHistory>, AppError>;
     async fn list(
         &self,
         server_id: Option<&str>,
@@ -315,6 +903,38 @@ pub trait IMcpCallHistoryRepository: Send + Sync {
     async fn clear(&self, server_id: Option<&str>) -> Result<(), AppError>;
 }
 
+#[async_trait]
+pub trait IMcpPromptHistoryRepository: Send + Sync {
+    async fn create(&self, history: McpPromptHistory) -> Result<McpPromptHistory, AppError>;
+    async fn list(
+        &self,
+        server_id: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<Vec<McpPromptHistory>, AppError>;
+    async fn clear(&self, server_id: Option<&str>) -> Result<(), AppError>;
+}
+
+#[async_trait]
+pub trait IMcpRawRpcHistoryRepository: Send + Sync {
+    async fn create(&self, history: McpRawRpcHistory) -> Result<McpRawRpcHistory, AppError>;
+    async fn list(
+        &self,
+        server_id: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<Vec<McpRawRpcHistory>, AppError>;
+    async fn clear(&self, server_id: Option<&str>) -> Result<(), AppError>;
+}
+
+#[async_trait]
+pub trait IMcpHeartbeatRepository: Send + Sync {
+    async fn create(&self, heartbeat: McpHeartbeat) -> Result<McpHeartbeat, AppError>;
+    async fn list(
+        &self,
+        server_id: &str,
+        limit: Option<i64>,
+    ) -> Result<Vec<McpHeartbeat>, AppError>;
+}
+
 #[async_trait]
 pub trait IHttpReceivedMessageRepository: Send + Sync {
     async fn create(&self, message: HttpReceivedMessage) -> Result<HttpReceivedMessage, AppError>;
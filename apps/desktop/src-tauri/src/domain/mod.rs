@@ -1,4 +1,13 @@
+pub mod collection;
 pub mod config;
 pub mod cqrs;
+pub mod elicitation;
+pub mod environment;
 pub mod events;
 pub mod mcp;
+pub mod oauth;
+pub mod rule_match;
+pub mod sampling;
+pub mod server_log;
+pub mod test_run;
+pub mod traffic;
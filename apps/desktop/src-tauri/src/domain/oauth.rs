@@ -0,0 +1,114 @@
+//! OAuth domain - authorization of remote MCP servers per the MCP authorization spec.
+//!
+//! Studio discovers the authorization server from the MCP server's protected-resource
+//! metadata, registers itself as a public client when needed and runs the PKCE
+//! authorization code flow through a loopback redirect. Credentials are kept per server.
+
+use crate::domain::cqrs::{Command, Query};
+use crate::error::AppError;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// OAuth client registration and current tokens of one server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpOAuthCredentials {
+    pub server_id: String,
+    pub authorization_server: String, // Issuer URL
+    pub token_endpoint: String,
+    pub resource: String, // Resource indicator (RFC 8707) sent with every request
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    pub redirect_uri: String,
+    pub access_token: Option<String>,
+    pub refresh_token: Option<String>,
+    pub token_type: Option<String>,
+    pub scope: Option<String>,
+    pub expires_at: Option<i64>, // Unix seconds
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Authorization state of a server as shown in the UI (no secrets)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpOAuthStatus {
+    pub server_id: String,
+    pub authorized: bool,
+    pub authorization_server: Option<String>,
+    pub client_id: Option<String>,
+    pub scope: Option<String>,
+    pub expires_at: Option<i64>,
+    pub has_refresh_token: bool,
+    pub updated_at: Option<String>,
+}
+
+impl McpOAuthStatus {
+    pub fn from_credentials(server_id: &str, credentials: Option<&McpOAuthCredentials>) -> Self {
+        Self {
+            server_id: server_id.to_string(),
+            authorized: credentials.is_some_and(|c| c.access_token.is_some()),
+            authorization_server: credentials.map(|c| c.authorization_server.clone()),
+            client_id: credentials.map(|c| c.client_id.clone()),
+            scope: credentials.and_then(|c| c.scope.clone()),
+            expires_at: credentials.and_then(|c| c.expires_at),
+            has_refresh_token: credentials.is_some_and(|c| c.refresh_token.is_some()),
+            updated_at: credentials.map(|c| c.updated_at.clone()),
+        }
+    }
+}
+
+/// Authorization flow waiting for the browser to hit the loopback redirect
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpOAuthStart {
+    pub server_id: String,
+    pub state: String,
+    pub authorization_url: String, // Opened in the system browser
+    pub redirect_uri: String,
+}
+
+// ============ Commands ============
+
+/// Command to start the authorization code flow of a server
+#[derive(Debug, Deserialize)]
+pub struct StartMcpOAuthCmd {
+    pub server_id: String,
+    pub redirect_uri: String,
+}
+
+impl Command for StartMcpOAuthCmd {}
+
+/// Command to exchange the stored refresh token for a new access token
+#[derive(Debug, Deserialize)]
+pub struct RefreshMcpOAuthTokenCmd {
+    pub server_id: String,
+}
+
+impl Command for RefreshMcpOAuthTokenCmd {}
+
+/// Command to forget the tokens and client registration of a server
+#[derive(Debug, Deserialize)]
+pub struct ClearMcpOAuthCmd {
+    pub server_id: String,
+}
+
+impl Command for ClearMcpOAuthCmd {}
+
+// ============ Queries ============
+
+/// Query to get the authorization state of a server
+#[derive(Debug)]
+pub struct GetMcpOAuthStatusQuery {
+    pub server_id: String,
+}
+
+impl Query for GetMcpOAuthStatusQuery {}
+
+// ============ Repository Interfaces ============
+
+#[async_trait]
+pub trait IMcpOAuthRepository: Send + Sync {
+    /// Insert or replace the credentials of a server
+    async fn save(&self, credentials: McpOAuthCredentials)
+        -> Result<McpOAuthCredentials, AppError>;
+    async fn find(&self, server_id: &str) -> Result<Option<McpOAuthCredentials>, AppError>;
+    async fn delete(&self, server_id: &str) -> Result<(), AppError>;
+}
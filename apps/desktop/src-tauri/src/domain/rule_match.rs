@@ -0,0 +1,54 @@
+//! Matching shared by the auto-answer rules (sampling, elicitation).
+
+use crate::error::AppError;
+use serde::{Deserialize, Serialize};
+
+/// How a rule's pattern is matched against the text of an incoming request
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleMatchType {
+    Any,
+    #[default]
+    Contains,
+    Regex,
+}
+
+impl RuleMatchType {
+    /// Check that a pattern can be used with this match type
+    pub fn validate_pattern(self, pattern: &str) -> Result<(), AppError> {
+        if self == Self::Regex {
+            regex::Regex::new(pattern)
+                .map_err(|e| AppError::Domain(format!("Invalid regex pattern: {}", e)))?;
+        }
+        Ok(())
+    }
+
+    /// Whether `text` matches `pattern` (invalid regex patterns never match)
+    pub fn matches(self, pattern: &str, text: &str) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Contains => text.contains(pattern),
+            Self::Regex => regex::Regex::new(pattern).map(|re| re.is_match(text)).unwrap_or(false),
+        }
+    }
+}
+
+impl std::fmt::Display for RuleMatchType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Any => write!(f, "any"),
+            Self::Contains => write!(f, "contains"),
+            Self::Regex => write!(f, "regex"),
+        }
+    }
+}
+
+impl From<String> for RuleMatchType {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "any" => Self::Any,
+            "regex" => Self::Regex,
+            _ => Self::Contains,
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! Sampling domain - answering `sampling/createMessage` requests sent by MCP servers.
+//!
+//! Requests are answered either by the first matching rule or, when no rule matches,
+//! by a manual reply submitted from the UI. Every exchange is persisted.
+
+use crate::domain::cqrs::{Command, Query};
+use crate::domain::rule_match::RuleMatchType;
+use crate::error::AppError;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// User-defined rule returning a canned sampling response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SamplingRule {
+    pub id: String,
+    pub name: String,
+    pub server_id: Option<String>, // None = applies to every server
+    pub match_type: RuleMatchType,
+    pub pattern: String, // Matched against the text content of the request messages
+    pub response_text: String,
+    pub model: String,
+    pub stop_reason: Option<String>,
+    pub enabled: bool,
+    pub priority: i64, // Lower runs first
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// One `sampling/createMessage` request and the response sent back
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SamplingExchange {
+    pub id: String,
+    pub server_id: String,
+    pub request: String,          // JSON string (CreateMessageRequestParam)
+    pub response: Option<String>, // JSON string (CreateMessageResult)
+    pub status: String,           // 'pending', 'answered', 'rejected', 'timeout', 'cancelled'
+    pub source: Option<String>,   // 'rule' or 'manual'
+    pub rule_id: Option<String>,
+    pub error_message: Option<String>,
+    pub duration_ms: Option<i64>,
+    pub created_at: String,
+}
+
+/// Sampling request waiting for a manual reply
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingSamplingRequest {
+    pub request_id: String,
+    pub server_id: String,
+    pub request: serde_json::Value,
+    pub created_at: String,
+}
+
+// ============ Commands ============
+
+/// Command to create a sampling rule
+#[derive(Debug, Deserialize)]
+pub struct CreateSamplingRuleCmd {
+    pub name: String,
+    pub server_id: Option<String>,
+    #[serde(default)]
+    pub match_type: RuleMatchType,
+    #[serde(default)]
+    pub pattern: String,
+    pub response_text: String,
+    pub model: Option<String>,
+    pub stop_reason: Option<String>,
+    pub enabled: Option<bool>,
+    pub priority: Option<i64>,
+}
+
+impl Command for CreateSamplingRuleCmd {}
+
+/// Command to update a sampling rule
+#[derive(Debug, Deserialize)]
+pub struct UpdateSamplingRuleCmd {
+    pub id: String,
+    pub name: String,
+    pub server_id: Option<String>,
+    #[serde(default)]
+    pub match_type: RuleMatchType,
+    #[serde(default)]
+    pub pattern: String,
+    pub response_text: String,
+    pub model: Option<String>,
+    pub stop_reason: Option<String>,
+    pub enabled: bool,
+    pub priority: i64,
+}
+
+impl Command for UpdateSamplingRuleCmd {}
+
+/// Command to delete a sampling rule
+#[derive(Debug, Deserialize)]
+pub struct DeleteSamplingRuleCmd {
+    pub id: String,
+}
+
+impl Command for DeleteSamplingRuleCmd {}
+
+/// Command to answer a pending sampling request from the UI
+#[derive(Debug, Deserialize)]
+pub struct RespondSamplingRequestCmd {
+    pub request_id: String,
+    pub text: String,
+    pub model: Option<String>,
+    pub stop_reason: Option<String>,
+}
+
+impl Command for RespondSamplingRequestCmd {}
+
+/// Command to reject a pending sampling request (the server receives an error)
+#[derive(Debug, Deserialize)]
+pub struct RejectSamplingRequestCmd {
+    pub request_id: String,
+    pub message: Option<String>,
+}
+
+impl Command for RejectSamplingRequestCmd {}
+
+/// Command to clear sampling exchanges
+#[derive(Debug, Deserialize)]
+pub struct ClearSamplingExchangesCmd {
+    pub server_id: Option<String>,
+}
+
+impl Command for ClearSamplingExchangesCmd {}
+
+// ============ Queries ============
+
+/// Query to list sampling rules
+#[derive(Debug)]
+pub struct ListSamplingRulesQuery;
+
+impl Query for ListSamplingRulesQuery {}
+
+/// Query to list persisted sampling exchanges
+#[derive(Debug)]
+pub struct ListSamplingExchangesQuery {
+    pub server_id: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl Query for ListSamplingExchangesQuery {}
+
+/// Query to list sampling requests waiting for a manual reply
+#[derive(Debug)]
+pub struct ListPendingSamplingRequestsQuery;
+
+impl Query for ListPendingSamplingRequestsQuery {}
+
+// ============ Repository Interfaces ============
+
+#[async_trait]
+pub trait ISamplingRuleRepository: Send + Sync {
+    async fn create(&self, rule: SamplingRule) -> Result<SamplingRule, AppError>;
+    async fn update(&self, rule: SamplingRule) -> Result<SamplingRule, AppError>;
+    async fn delete(&self, id: &str) -> Result<(), AppError>;
+    async fn find_by_id(&self, id: &str) -> Result<Option<SamplingRule>, AppError>;
+    async fn list(&self) -> Result<Vec<SamplingRule>, AppError>;
+}
+
+#[async_trait]
+pub trait ISamplingExchangeRepository: Send + Sync {
+    async fn create(&self, exchange: SamplingExchange) -> Result<SamplingExchange, AppError>;
+    async fn update(&self, exchange: SamplingExchange) -> Result<SamplingExchange, AppError>;
+    async fn list(
+        &self,
+        server_id: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<Vec<SamplingExchange>, AppError>;
+    async fn clear(&self, server_id: Option<&str>) -> Result<(), AppError>;
+}
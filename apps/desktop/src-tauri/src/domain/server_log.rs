@@ -0,0 +1,114 @@
+//! Server log domain - log records MCP servers send through `notifications/message`.
+
+use crate::domain::cqrs::{Command, Query};
+use crate::error::AppError;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// MCP log level (RFC 5424 severities, lowest first)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum McpLogLevel {
+    Debug,
+    #[default]
+    Info,
+    Notice,
+    Warning,
+    Error,
+    Critical,
+    Alert,
+    Emergency,
+}
+
+impl McpLogLevel {
+    /// Numeric severity stored alongside the level name (0 = debug)
+    pub fn severity(self) -> i64 {
+        self as i64
+    }
+}
+
+impl std::fmt::Display for McpLogLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Debug => write!(f, "debug"),
+            Self::Info => write!(f, "info"),
+            Self::Notice => write!(f, "notice"),
+            Self::Warning => write!(f, "warning"),
+            Self::Error => write!(f, "error"),
+            Self::Critical => write!(f, "critical"),
+            Self::Alert => write!(f, "alert"),
+            Self::Emergency => write!(f, "emergency"),
+        }
+    }
+}
+
+impl From<String> for McpLogLevel {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "debug" => Self::Debug,
+            "notice" => Self::Notice,
+            "warning" => Self::Warning,
+            "error" => Self::Error,
+            "critical" => Self::Critical,
+            "alert" => Self::Alert,
+            "emergency" => Self::Emergency,
+            _ => Self::Info,
+        }
+    }
+}
+
+/// One log record received from a server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpServerLog {
+    pub id: String,
+    pub server_id: String,
+    pub level: McpLogLevel,
+    pub logger: Option<String>,
+    pub data: String, // JSON string
+    pub created_at: String,
+}
+
+// ============ Commands ============
+
+/// Command to send `logging/setLevel` to a connected server
+#[derive(Debug, Deserialize)]
+pub struct SetMcpLogLevelCmd {
+    pub server_id: String,
+    pub level: McpLogLevel,
+}
+
+impl Command for SetMcpLogLevelCmd {}
+
+/// Command to clear stored log records
+#[derive(Debug, Deserialize)]
+pub struct ClearMcpServerLogsCmd {
+    pub server_id: Option<String>,
+}
+
+impl Command for ClearMcpServerLogsCmd {}
+
+// ============ Queries ============
+
+/// Query to list stored log records (newest first)
+#[derive(Debug)]
+pub struct ListMcpServerLogsQuery {
+    pub server_id: Option<String>,
+    pub min_level: Option<McpLogLevel>, // Only records at this level or more severe
+    pub limit: Option<i64>,
+}
+
+impl Query for ListMcpServerLogsQuery {}
+
+// ============ Repository Interfaces ============
+
+#[async_trait]
+pub trait IMcpServerLogRepository: Send + Sync {
+    async fn create(&self, log: McpServerLog) -> Result<McpServerLog, AppError>;
+    async fn list(
+        &self,
+        server_id: Option<&str>,
+        min_level: Option<McpLogLevel>,
+        limit: Option<i64>,
+    ) -> Result<Vec<McpServerLog>, AppError>;
+    async fn clear(&self, server_id: Option<&str>) -> Result<(), AppError>;
+}
@@ -0,0 +1,149 @@
+//! Test Run domain - assertions on saved calls and recorded runs of them.
+//!
+//! A saved call can carry assertions on its result. A test run calls the saved calls of a
+//! collection like a collection run, evaluates their assertions and keeps the outcome of
+//! every assertion, so regressions of a server show up as failed runs.
+
+use crate::domain::cqrs::{Command, Query};
+use crate::error::AppError;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// A check on the result of a saved call
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum McpAssertion {
+    /// The call completed and the tool didn't report an error
+    Success,
+    /// The call completed and the tool reported an error (`isError: true`)
+    IsError,
+    /// A value of the result (e.g. `$.structuredContent.count`) equals `value`
+    JsonPathEquals { path: String, value: serde_json::Value },
+    /// A value of the result matches a regex (non-string values as JSON text)
+    JsonPathMatches { path: String, pattern: String },
+    /// The call took less than `max_ms`
+    DurationUnder { max_ms: i64 },
+    /// `structuredContent` validates against the tool's output schema
+    OutputSchema,
+}
+
+/// Outcome of one assertion
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpAssertionResult {
+    pub assertion: McpAssertion,
+    pub passed: bool,
+    pub message: Option<String>, // Why it failed
+}
+
+/// A recorded test run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpTestRun {
+    pub id: String,
+    pub collection_id: Option<String>, // None once the collection is deleted
+    pub collection_name: String,
+    pub folder_id: Option<String>, // Set when only one folder was run
+    pub server_id: Option<String>, // Set when every call was sent to one server
+    pub started_at: String,
+    pub duration_ms: i64,
+    pub total: usize,
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub success: bool, // No call failed
+    pub created_at: String,
+}
+
+/// Outcome of one saved call in a test run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpTestResult {
+    pub id: String,
+    pub run_id: String,
+    pub saved_call_id: Option<String>, // None once the saved call is deleted
+    pub name: String,
+    pub folder_path: Vec<String>, // Folder names from the top of the collection
+    pub server_id: Option<String>,
+    pub tool_name: String,
+    pub status: String, // 'success', 'error', 'cancelled', 'timeout' or 'skipped'
+    /// Every assertion passed
+    pub passed: bool,
+    pub error: Option<String>,
+    pub history_id: Option<String>,
+    pub duration_ms: i64,
+    pub assertions: Vec<McpAssertionResult>,
+    pub position: i64, // Order in the run
+}
+
+/// A test run with the outcome of every call
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpTestRunDetail {
+    pub run: McpTestRun,
+    pub results: Vec<McpTestResult>,
+}
+
+// ============ Commands ============
+
+/// Command to run the saved calls of a collection and check their assertions.
+/// Calls without assertions are checked for success.
+#[derive(Debug, Deserialize)]
+pub struct RunMcpTestsCmd {
+    pub collection_id: String,
+    /// Run only this folder (and its subfolders)
+    #[serde(default)]
+    pub folder_id: Option<String>,
+    /// Run only these saved calls (still in collection order)
+    #[serde(default)]
+    pub saved_call_ids: Option<Vec<String>>,
+    /// Send every call to this server instead of the saved one
+    #[serde(default)]
+    pub server_id: Option<String>,
+    /// Skip the remaining calls after the first failure
+    #[serde(default)]
+    pub stop_on_failure: bool,
+    /// Per call, defaults to 30 seconds
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+}
+
+impl Command for RunMcpTestsCmd {}
+
+/// Command to delete a recorded test run
+#[derive(Debug, Deserialize)]
+pub struct DeleteMcpTestRunCmd {
+    pub id: String,
+}
+
+impl Command for DeleteMcpTestRunCmd {}
+
+// ============ Queries ============
+
+/// Query to list test runs, newest first
+#[derive(Debug)]
+pub struct ListMcpTestRunsQuery {
+    pub collection_id: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl Query for ListMcpTestRunsQuery {}
+
+/// Query to open a test run with its results
+#[derive(Debug)]
+pub struct GetMcpTestRunQuery {
+    pub id: String,
+}
+
+impl Query for GetMcpTestRunQuery {}
+
+// ============ Repository Interfaces ============
+
+#[async_trait]
+pub trait IMcpTestRunRepository: Send + Sync {
+    /// Save a run with its results
+    async fn create(&self, detail: McpTestRunDetail) -> Result<McpTestRunDetail, AppError>;
+    async fn delete(&self, id: &str) -> Result<(), AppError>;
+    async fn find_by_id(&self, id: &str) -> Result<Option<McpTestRunDetail>, AppError>;
+    async fn list(
+        &self,
+        collection_id: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<Vec<McpTestRun>, AppError>;
+}
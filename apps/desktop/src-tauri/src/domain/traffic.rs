@@ -0,0 +1,249 @@
+//! Traffic domain - JSON-RPC frames exactly as they went over the wire, per connection,
+//! and their export as HAR or a JSON-RPC transcript that another Studio can import.
+
+use crate::domain::cqrs::{Command, Query};
+use crate::domain::mcp::{McpCallHistory, McpServerType};
+use crate::error::AppError;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// Which way a frame travelled
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum McpTrafficDirection {
+    /// Client to server
+    Outbound,
+    /// Server to client
+    Inbound,
+}
+
+impl std::fmt::Display for McpTrafficDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Outbound => write!(f, "outbound"),
+            Self::Inbound => write!(f, "inbound"),
+        }
+    }
+}
+
+impl From<String> for McpTrafficDirection {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "inbound" => Self::Inbound,
+            _ => Self::Outbound,
+        }
+    }
+}
+
+/// One frame written to or read from a transport
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpTrafficFrame {
+    pub id: String,
+    pub server_id: String,
+    /// Changes on every connect, so reconnects show up as separate conversations
+    pub connection_id: String,
+    pub transport: McpServerType,
+    pub direction: McpTrafficDirection,
+    pub kind: String, // 'request', 'response', 'error', 'notification', 'batch', 'invalid'
+    pub method: Option<String>,
+    /// JSON-RPC id as JSON text (`1`, `"abc"`), shared by a request and its response
+    pub correlation_id: Option<String>,
+    pub frame: String, // Raw text as sent or received
+    pub size_bytes: i64,
+    pub created_at: String, // Millisecond precision
+}
+
+/// File format of a traffic export
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum McpTrafficExportFormat {
+    /// HTTP Archive (SSE and streamable HTTP only)
+    Har,
+    /// Newline-delimited JSON-RPC transcript (all transports)
+    Transcript,
+}
+
+/// Contents of an export, to be saved by the UI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpTrafficExport {
+    pub format: McpTrafficExportFormat,
+    pub file_name: String,
+    pub content: String,
+    pub frame_count: usize,
+    pub call_count: usize,
+}
+
+/// `format` of the first transcript line
+pub const TRANSCRIPT_FORMAT: &str = "mcp-studio-transcript";
+
+/// Newest transcript version this build reads and writes
+pub const TRANSCRIPT_VERSION: u32 = 1;
+
+/// Server a transcript was recorded against
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpTranscriptServer {
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    pub transport: McpServerType,
+}
+
+/// First line of a transcript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpTranscriptHeader {
+    pub format: String,
+    pub version: u32,
+    pub server: McpTranscriptServer,
+    pub connection_id: String,
+    /// Initialize result as the server sent it (None if the handshake wasn't captured)
+    pub server_info: Option<serde_json::Value>,
+    pub started_at: Option<String>,
+    pub ended_at: Option<String>,
+    pub exported_at: String,
+}
+
+/// One frame of a transcript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpTranscriptFrame {
+    pub direction: McpTrafficDirection,
+    pub kind: String,
+    pub method: Option<String>,
+    pub correlation_id: Option<String>,
+    pub timestamp: String,
+    /// Since the first frame of the connection
+    pub elapsed_ms: i64,
+    /// Responses only: time since the request with the same id went the other way
+    pub latency_ms: Option<i64>,
+    pub size_bytes: i64,
+    pub frame: String, // Raw text as sent or received
+}
+
+/// One line of a transcript file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum McpTranscriptLine {
+    Header(McpTranscriptHeader),
+    Frame(McpTranscriptFrame),
+    /// Tool call from the history made during the connection
+    Call(McpCallHistory),
+}
+
+/// A transcript imported from a file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpTranscript {
+    pub id: String,
+    pub name: String,
+    pub server_name: String,
+    pub server_url: String,
+    pub transport: McpServerType,
+    pub connection_id: String,
+    pub frame_count: i64,
+    pub call_count: i64,
+    pub started_at: Option<String>,
+    pub created_at: String,
+}
+
+/// An imported transcript with its contents
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpTranscriptDetail {
+    pub transcript: McpTranscript,
+    pub header: McpTranscriptHeader,
+    pub frames: Vec<McpTranscriptFrame>,
+    pub calls: Vec<McpCallHistory>,
+}
+
+// ============ Commands ============
+
+/// Command to clear recorded traffic
+#[derive(Debug, Deserialize)]
+pub struct ClearMcpTrafficCmd {
+    pub server_id: Option<String>,
+}
+
+impl Command for ClearMcpTrafficCmd {}
+
+/// Command to import a transcript file
+#[derive(Debug, Deserialize)]
+pub struct ImportMcpTranscriptCmd {
+    pub name: Option<String>, // Defaults to the server name and start time
+    pub content: String,
+}
+
+impl Command for ImportMcpTranscriptCmd {}
+
+/// Command to delete an imported transcript
+#[derive(Debug, Deserialize)]
+pub struct DeleteMcpTranscriptCmd {
+    pub id: String,
+}
+
+impl Command for DeleteMcpTranscriptCmd {}
+
+// ============ Queries ============
+
+/// Query to list recorded frames (newest first)
+#[derive(Debug)]
+pub struct ListMcpTrafficQuery {
+    pub server_id: Option<String>,
+    pub connection_id: Option<String>,
+    pub direction: Option<McpTrafficDirection>,
+    pub correlation_id: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl Query for ListMcpTrafficQuery {}
+
+/// Query to export the traffic of one connection
+#[derive(Debug)]
+pub struct ExportMcpTrafficQuery {
+    pub server_id: String,
+    pub connection_id: Option<String>, // Defaults to the most recent connection
+    pub format: McpTrafficExportFormat,
+}
+
+impl Query for ExportMcpTrafficQuery {}
+
+/// Query to list imported transcripts (newest first)
+#[derive(Debug)]
+pub struct ListMcpTranscriptsQuery;
+
+impl Query for ListMcpTranscriptsQuery {}
+
+/// Query to open an imported transcript
+#[derive(Debug)]
+pub struct GetMcpTranscriptQuery {
+    pub id: String,
+}
+
+impl Query for GetMcpTranscriptQuery {}
+
+// ============ Repository Interfaces ============
+
+#[async_trait]
+pub trait IMcpTrafficRepository: Send + Sync {
+    async fn create(&self, frame: McpTrafficFrame) -> Result<McpTrafficFrame, AppError>;
+    async fn list(
+        &self,
+        server_id: Option<&str>,
+        connection_id: Option<&str>,
+        direction: Option<McpTrafficDirection>,
+        correlation_id: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<Vec<McpTrafficFrame>, AppError>;
+    async fn clear(&self, server_id: Option<&str>) -> Result<(), AppError>;
+    /// Drop a server's oldest frames beyond the retention limit
+    async fn prune(&self, server_id: &str) -> Result<(), AppError>;
+}
+
+#[async_trait]
+pub trait IMcpTranscriptRepository: Send + Sync {
+    /// Store a transcript together with the file it was read from
+    async fn create(
+        &self,
+        transcript: McpTranscript,
+        content: &str,
+    ) -> Result<McpTranscript, AppError>;
+    async fn list(&self) -> Result<Vec<McpTranscript>, AppError>;
+    async fn find_by_id(&self, id: &str) -> Result<Option<(McpTranscript, String)>, AppError>;
+    async fn delete(&self, id: &str) -> Result<(), AppError>;
+}
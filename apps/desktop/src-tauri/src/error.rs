@@ -55,6 +55,7 @@ impl From<std::io::Error> for AppError {
     }
 }
 
+#[cfg(feature = "desktop")]
 impl From<tauri::Error> for AppError {
     fn from(err: tauri::Error) -> Self {
         AppError::Tauri(err.to_string())
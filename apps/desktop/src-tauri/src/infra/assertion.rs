@@ -0,0 +1,225 @@
+//! Assertion evaluation - checks a saved call's assertions against the outcome of a run.
+//!
+//! JSONPath assertions look at the whole `tools/call` result (`$.content`,
+//! `$.structuredContent`, `$.isError`, ...) and pass when any selected value matches.
+
+use serde_json::Value;
+
+use crate::domain::collection::McpCollectionRunResult;
+use crate::domain::test_run::{McpAssertion, McpAssertionResult};
+use crate::error::AppError;
+use crate::infra::{json_path, json_schema};
+
+/// Schema errors listed in a failed output schema assertion
+const MAX_SCHEMA_ERRORS: usize = 5;
+
+/// Checked for calls saved without assertions
+const DEFAULT_ASSERTIONS: &[McpAssertion] = &[McpAssertion::Success];
+
+/// The assertions a call is checked with (calls without any are checked for success)
+pub fn effective(assertions: &[McpAssertion]) -> &[McpAssertion] {
+    if assertions.is_empty() {
+        DEFAULT_ASSERTIONS
+    } else {
+        assertions
+    }
+}
+
+/// Check that assertions can be evaluated (paths parse, patterns compile)
+pub fn validate(assertions: &[McpAssertion]) -> Result<(), AppError> {
+    for assertion in assertions {
+        match assertion {
+            McpAssertion::JsonPathEquals { path, .. } => json_path::validate(path)?,
+            McpAssertion::JsonPathMatches { path, pattern } => {
+                json_path::validate(path)?;
+                regex::Regex::new(pattern)
+                    .map_err(|e| AppError::Domain(format!("Invalid regex pattern: {}", e)))?;
+            }
+            McpAssertion::DurationUnder { max_ms } if *max_ms <= 0 => {
+                return Err(AppError::Domain("Duration limit must be positive".to_string()));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Evaluate one assertion; `output_schema` is the tool's, when the server declared one
+pub fn evaluate(
+    assertion: &McpAssertion,
+    run: &McpCollectionRunResult,
+    output_schema: Option<&Value>,
+) -> McpAssertionResult {
+    let outcome = match assertion {
+        McpAssertion::Success if run.success => Ok(()),
+        McpAssertion::Success => Err(match completed(run) {
+            Ok(_) => "The tool reported an error (isError)".to_string(),
+            Err(reason) => reason,
+        }),
+        McpAssertion::IsError => completed(run).and_then(|_| {
+            if run.success {
+                Err("The tool didn't report an error".to_string())
+            } else {
+                Ok(())
+            }
+        }),
+        McpAssertion::DurationUnder { max_ms } => completed(run).and_then(|_| {
+            if run.duration_ms < *max_ms {
+                Ok(())
+            } else {
+                Err(format!("Took {} ms, limit {} ms", run.duration_ms, max_ms))
+            }
+        }),
+        McpAssertion::JsonPathEquals { path, value } => completed(run).and_then(|result| {
+            check_path(&result, path, |actual| actual == value, &format!("expected {}", value))
+        }),
+        McpAssertion::JsonPathMatches { path, pattern } => completed(run).and_then(|result| {
+            let re = regex::Regex::new(pattern).map_err(|e| format!("Invalid regex: {}", e))?;
+            let matches = |actual: &Value| match actual {
+                Value::String(text) => re.is_match(text),
+                other => re.is_match(&other.to_string()),
+            };
+            check_path(&result, path, matches, &format!("expected to match {}", pattern))
+        }),
+        McpAssertion::OutputSchema => completed(run).and_then(|result| {
+            let schema = output_schema
+                .ok_or_else(|| format!("Tool {} declares no output schema", run.tool_name))?;
+            let structured = result
+                .get("structuredContent")
+                .ok_or_else(|| "The result has no structuredContent".to_string())?;
+            let errors = json_schema::validate(schema, structured);
+            match errors.len() {
+                0 => Ok(()),
+                n if n > MAX_SCHEMA_ERRORS => Err(format!(
+                    "{} (and {} more)",
+                    errors[..MAX_SCHEMA_ERRORS].join("; "),
+                    n - MAX_SCHEMA_ERRORS
+                )),
+                _ => Err(errors.join("; ")),
+            }
+        }),
+    };
+
+    McpAssertionResult {
+        assertion: assertion.clone(),
+        passed: outcome.is_ok(),
+        message: outcome.err(),
+    }
+}
+
+/// The `tools/call` result, when the call completed
+fn completed(run: &McpCollectionRunResult) -> Result<Value, String> {
+    match &run.result {
+        Some(result) if result.status == "success" => serde_json::from_str(&result.raw_response)
+            .map_err(|e| format!("The result is not JSON: {}", e)),
+        _ => Err(match &run.error {
+            Some(error) => format!("The call didn't complete ({}): {}", run.status, error),
+            None => format!("The call didn't complete ({})", run.status),
+        }),
+    }
+}
+
+/// Pass when any value selected by `path` satisfies `check`
+fn check_path(
+    result: &Value,
+    path: &str,
+    check: impl Fn(&Value) -> bool,
+    expectation: &str,
+) -> Result<(), String> {
+    let selected = json_path::select(result, path).map_err(|e| e.to_string())?;
+    match selected.as_slice() {
+        [] => Err(format!("{} selects nothing", path)),
+        values if values.iter().any(|v| check(v)) => Ok(()),
+        [value] => Err(format!("{} is {}, {}", path, value, expectation)),
+        values => {
+            let actual = Value::Array(values.iter().map(|v| (*v).clone()).collect());
+            Err(format!("{} are {}, {}", path, actual, expectation))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::mcp::McpToolCallResult;
+    use serde_json::json;
+
+    /// A run of `echo` that completed with `response` (the `tools/call` result)
+    fn completed_run(response: Value) -> McpCollectionRunResult {
+        let success = response.get("isError") != Some(&Value::Bool(true));
+        McpCollectionRunResult {
+            saved_call_id: "call-1".to_string(),
+            name: "Echo".to_string(),
+            folder_path: Vec::new(),
+            server_id: Some("server-1".to_string()),
+            tool_name: "echo".to_string(),
+            status: "success".to_string(),
+            success,
+            result: Some(McpToolCallResult {
+                call_id: "run-1".to_string(),
+                status: "success".to_string(),
+                success,
+                raw_response: response.to_string(),
+                result: response.get("content").cloned(),
+                error: None,
+                duration_ms: 12,
+            }),
+            error: None,
+            history_id: None,
+            duration_ms: 12,
+        }
+    }
+
+    /// A run that didn't complete
+    fn failed_run(status: &str, error: &str) -> McpCollectionRunResult {
+        McpCollectionRunResult {
+            status: status.to_string(),
+            success: false,
+            result: None,
+            error: Some(error.to_string()),
+            ..completed_run(json!({}))
+        }
+    }
+
+    fn message(assertion: &McpAssertion, run: &McpCollectionRunResult) -> Option<String> {
+        let result = evaluate(assertion, run, None);
+        assert_eq!(result.passed, result.message.is_none());
+        result.message
+    }
+
+    #[test]
+    fn calls_without_assertions_are_checked_for_success() {
+        assert_eq!(effective(&[]), &[McpAssertion::Success]);
+        let assertions = [McpAssertion::IsError];
+        assert_eq!(effective(&assertions), &assertions);
+    }
+
+    #[test]
+    fn success_passes_when_the_tool_reports_no_error() {
+        let run = completed_run(json!({"content": [{"type": "text", "text": "hi"}]}));
+        assert_eq!(message(&McpAssertion::Success, &run), None);
+    }
+
+    #[test]
+    fn success_fails_when_the_tool_reports_an_error() {
+        let run = completed_run(json!({"content": [], "isError": true}));
+        assert_eq!(
+            message(&McpAssertion::Success, &run).as_deref(),
+            Some("The tool reported an error (isError)")
+        );
+    }
+
+    #[test]
+    fn success_fails_when_the_call_did_not_complete() {
+        assert_eq!(
+            message(&McpAssertion::Success, &failed_run("timeout", "No response in 30000 ms"))
+                .as_deref(),
+            Some("The call didn't complete (timeout): No response in 30000 ms")
+        );
+        let skipped = McpCollectionRunResult { error: None, ..failed_run("skipped", "") };
+        assert_eq!(
+            message(&McpAssertion::Success, &skipped).as_deref(),
+            Some("The call didn't complete (skipped)")
+        );
+    }
+}
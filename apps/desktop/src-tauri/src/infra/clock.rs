@@ -0,0 +1,22 @@
+//! Clock - current time in the formats stored in the database and sent to servers.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Current UTC time in the same format SQLite's CURRENT_TIMESTAMP uses
+pub fn now_timestamp() -> String {
+    let format = time::macros::format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+    time::OffsetDateTime::now_utc().format(&format).unwrap_or_default()
+}
+
+/// Current UTC time with milliseconds, so frames of one exchange keep their order
+pub fn now_timestamp_millis() -> String {
+    let format = time::macros::format_description!(
+        "[year]-[month]-[day] [hour]:[minute]:[second].[subsecond digits:3]"
+    );
+    time::OffsetDateTime::now_utc().format(&format).unwrap_or_default()
+}
+
+/// Seconds since the Unix epoch (token expiry)
+pub fn unix_now() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs() as i64).unwrap_or_default()
+}
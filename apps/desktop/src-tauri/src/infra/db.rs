@@ -1,8 +1,9 @@
 use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
 use std::fs;
-use std::path::PathBuf;
+use std::path::Path;
+#[cfg(feature = "desktop")]
 use tauri::{AppHandle, Manager, Runtime};
-use tracing::{error, info};
+use tracing::info;
 
 use crate::error::AppError;
 
@@ -11,15 +12,28 @@ use crate::error::AppError;
 /// 2. Create database file.
 /// 3. Connect.
 /// 4. Run migrations.
+#[cfg(feature = "desktop")]
 pub async fn init_db<R: Runtime>(app: &AppHandle<R>) -> Result<SqlitePool, AppError> {
     let app_data_dir = app.path().app_data_dir()?;
+    open_db(&app_data_dir.join("db.sqlite")).await
+}
+
+/// Open (and migrate) an existing database, without creating a missing one; used by the
+/// headless runner so a mistyped path isn't silently replaced by an empty database
+pub async fn open_existing_db(db_path: &Path) -> Result<SqlitePool, AppError> {
+    if !db_path.is_file() {
+        return Err(AppError::NotFound(format!("No database at {}", db_path.display())));
+    }
+    open_db(db_path).await
+}
 
-    // Ensure app_data_dir exists
-    if !app_data_dir.exists() {
-        fs::create_dir_all(&app_data_dir)?;
+/// Open (and create or migrate) the database at a path
+pub async fn open_db(db_path: &Path) -> Result<SqlitePool, AppError> {
+    // Ensure the directory exists
+    if let Some(dir) = db_path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir)?;
     }
 
-    let db_path = app_data_dir.join("db.sqlite");
     let db_url = format!("sqlite:{}", db_path.to_string_lossy());
 
     info!("Connecting to database at: {}", db_url);
@@ -28,7 +42,7 @@ pub async fn init_db<R: Runtime>(app: &AppHandle<R>) -> Result<SqlitePool, AppEr
     // but using sqlx::migrate! often handles setup. However, standard practice is ensuring the file exists
     // for local embedded usage to avoid connection errors).
     if !db_path.exists() {
-        fs::File::create(&db_path)?;
+        fs::File::create(db_path)?;
     }
 
     let pool = SqlitePoolOptions::new()
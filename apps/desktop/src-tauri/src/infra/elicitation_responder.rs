@@ -0,0 +1,186 @@
+//! Elicitation Responder - answers `elicitation/create` requests sent by MCP servers.
+//!
+//! The first enabled rule (by priority) matching the request message answers it;
+//! otherwise the request and its JSON schema are sent to the UI and the server waits
+//! for an accept / decline / cancel answer.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{oneshot, Mutex};
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use rmcp::model::{
+    CreateElicitationRequestParam, CreateElicitationResult,
+    ElicitationAction as McpElicitationAction,
+};
+
+use crate::domain::elicitation::{
+    ElicitationAction, ElicitationRule, IElicitationRuleRepository, PendingElicitationRequest,
+};
+use crate::error::AppError;
+use crate::infra::clock::now_timestamp;
+use crate::infra::event_publisher::EventPublisher;
+
+/// How long a request waits for an answer before it is cancelled
+const ANSWER_TIMEOUT: Duration = Duration::from_secs(300);
+
+struct PendingAnswer {
+    info: PendingElicitationRequest,
+    sender: oneshot::Sender<CreateElicitationResult>,
+}
+
+pub struct ElicitationResponder {
+    rule_repo: Arc<dyn IElicitationRuleRepository>,
+    event_publisher: Arc<dyn EventPublisher>,
+    /// Requests waiting for an answer, keyed by request id
+    pending: Mutex<HashMap<String, PendingAnswer>>,
+}
+
+impl ElicitationResponder {
+    pub fn new(
+        rule_repo: Arc<dyn IElicitationRuleRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self { rule_repo, event_publisher, pending: Mutex::new(HashMap::new()) }
+    }
+
+    /// Answer an elicitation request from a server (called by the connection's ClientHandler)
+    pub async fn handle_create_elicitation(
+        &self,
+        server_id: &str,
+        params: CreateElicitationRequestParam,
+        ct: CancellationToken,
+    ) -> CreateElicitationResult {
+        let request_id = Uuid::new_v4().to_string();
+
+        info!(target: "elicitation", "Elicitation request {} from server {}: {}", request_id, server_id, params.message);
+
+        let (result, source) = match self.find_matching_rule(server_id, &params.message).await {
+            Some(rule) => {
+                info!(target: "elicitation", "Elicitation request {} answered by rule '{}'", request_id, rule.name);
+                let content = rule.content.as_deref().and_then(|c| serde_json::from_str(c).ok());
+                (build_result(rule.action, content), "rule")
+            }
+            None => (self.wait_for_answer(&request_id, server_id, params, ct).await, "manual"),
+        };
+
+        let event_data = serde_json::json!({
+            "request_id": request_id,
+            "server_id": server_id,
+            "action": result.action,
+            "content": result.content,
+            "source": source,
+        });
+        self.event_publisher.publish("mcp:elicitation_completed", event_data).await;
+
+        result
+    }
+
+    /// Submit the user's answer for a pending request
+    pub async fn respond(
+        &self,
+        request_id: &str,
+        action: ElicitationAction,
+        content: Option<serde_json::Value>,
+    ) -> Result<(), AppError> {
+        let pending = self.pending.lock().await.remove(request_id);
+        let pending = pending.ok_or_else(|| {
+            AppError::NotFound(format!("Elicitation request {} is not pending", request_id))
+        })?;
+
+        pending.sender.send(build_result(action, content)).map_err(|_| {
+            AppError::Domain(format!("Elicitation request {} is no longer waiting", request_id))
+        })
+    }
+
+    /// Requests currently waiting for an answer (oldest first)
+    pub async fn pending_requests(&self) -> Vec<PendingElicitationRequest> {
+        let pending = self.pending.lock().await;
+        let mut requests: Vec<PendingElicitationRequest> =
+            pending.values().map(|p| p.info.clone()).collect();
+        requests.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        requests
+    }
+
+    /// First enabled rule (by priority) that applies to the server and matches the message
+    async fn find_matching_rule(&self, server_id: &str, message: &str) -> Option<ElicitationRule> {
+        let rules = match self.rule_repo.list().await {
+            Ok(rules) => rules,
+            Err(e) => {
+                error!(target: "elicitation", "Failed to load elicitation rules: {}", e);
+                return None;
+            }
+        };
+
+        rules.into_iter().find(|rule| {
+            rule.enabled
+                && rule.server_id.as_deref().is_none_or(|sid| sid == server_id)
+                && rule.match_type.matches(&rule.pattern, message)
+        })
+    }
+
+    async fn wait_for_answer(
+        &self,
+        request_id: &str,
+        server_id: &str,
+        params: CreateElicitationRequestParam,
+        ct: CancellationToken,
+    ) -> CreateElicitationResult {
+        let (sender, receiver) = oneshot::channel();
+        let info = PendingElicitationRequest {
+            request_id: request_id.to_string(),
+            server_id: server_id.to_string(),
+            message: params.message,
+            requested_schema: serde_json::to_value(&params.requested_schema)
+                .unwrap_or(serde_json::Value::Null),
+            created_at: now_timestamp(),
+        };
+
+        self.pending
+            .lock()
+            .await
+            .insert(request_id.to_string(), PendingAnswer { info: info.clone(), sender });
+
+        let event_data = serde_json::to_value(&info).unwrap_or(serde_json::Value::Null);
+        self.event_publisher.publish("mcp:elicitation_request", event_data).await;
+
+        let result = tokio::select! {
+            answer = receiver => answer.unwrap_or_else(|_| build_result(ElicitationAction::Cancel, None)),
+            _ = ct.cancelled() => {
+                info!(target: "elicitation", "Elicitation request {} cancelled by server", request_id);
+                build_result(ElicitationAction::Cancel, None)
+            }
+            _ = tokio::time::sleep(ANSWER_TIMEOUT) => {
+                warn!(target: "elicitation", "Elicitation request {} timed out, cancelling", request_id);
+                build_result(ElicitationAction::Cancel, None)
+            }
+        };
+
+        // Answered requests were already removed by `respond`
+        self.pending.lock().await.remove(request_id);
+
+        result
+    }
+}
+
+/// Content is only sent along with 'accept'
+fn build_result(
+    action: ElicitationAction,
+    content: Option<serde_json::Value>,
+) -> CreateElicitationResult {
+    match action {
+        ElicitationAction::Accept => CreateElicitationResult {
+            action: McpElicitationAction::Accept,
+            content: Some(content.unwrap_or_else(|| serde_json::json!({}))),
+        },
+        ElicitationAction::Decline => {
+            CreateElicitationResult { action: McpElicitationAction::Decline, content: None }
+        }
+        ElicitationAction::Cancel => {
+            CreateElicitationResult { action: McpElicitationAction::Cancel, content: None }
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! Environment Resolver - replaces `{{name}}` placeholders with the variables of the active
+//! environment.
+//!
+//! Only well-formed placeholders are touched: `{{name}}` or `{{ name }}`, where the name is
+//! letters, digits, '_', '-' or '.'. With no active environment nothing is replaced; with
+//! one active, a placeholder naming an undefined variable is an error rather than being
+//! sent to the server as-is.
+
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::warn;
+
+use crate::domain::config::IConfigRepository;
+use crate::domain::environment::{
+    IMcpEnvironmentRepository, McpEnvironmentVariable, ACTIVE_ENVIRONMENT_KEY, SECRET_MASK,
+};
+use crate::domain::mcp::{McpServer, McpServerAuth};
+use crate::error::AppError;
+
+/// Secrets shorter than this are not masked inside responses (they would hide unrelated text)
+const MIN_MASKED_SECRET_LEN: usize = 4;
+
+/// Looks up the active (or pinned) environment for each call or connect
+pub struct EnvironmentResolver {
+    environment_repo: Arc<dyn IMcpEnvironmentRepository>,
+    config_repo: Arc<dyn IConfigRepository>,
+    environment_id: Option<String>, // Used instead of the active one
+}
+
+impl EnvironmentResolver {
+    pub fn new(
+        environment_repo: Arc<dyn IMcpEnvironmentRepository>,
+        config_repo: Arc<dyn IConfigRepository>,
+    ) -> Self {
+        Self { environment_repo, config_repo, environment_id: None }
+    }
+
+    /// Always use this environment, whichever is active (headless runs)
+    pub fn with_environment(mut self, environment_id: String) -> Self {
+        self.environment_id = Some(environment_id);
+        self
+    }
+
+    /// Variables of the active environment (none when no environment is active)
+    pub async fn variables(&self) -> Result<Variables, AppError> {
+        let active = match &self.environment_id {
+            Some(id) => Some(id.clone()),
+            None => self.config_repo.get(ACTIVE_ENVIRONMENT_KEY).await?,
+        };
+        let Some(id) = active.filter(|id| !id.is_empty()) else {
+            return Ok(Variables::default());
+        };
+
+        match self.environment_repo.find_by_id(&id).await? {
+            Some(environment) => Ok(Variables {
+                environment: Some(environment.name),
+                variables: environment.variables,
+            }),
+            None if self.environment_id.is_some() => {
+                Err(AppError::NotFound(format!("Environment {} not found", id)))
+            }
+            None => {
+                warn!(target: "environment", "Active environment {} no longer exists", id);
+                Ok(Variables::default())
+            }
+        }
+    }
+}
+
+/// Variables of one environment, ready for substitution
+#[derive(Debug, Clone, Default)]
+pub struct Variables {
+    environment: Option<String>, // Name, None = no active environment
+    variables: Vec<McpEnvironmentVariable>,
+}
+
+impl Variables {
+    /// Replace the placeholders of a text; with `masked`, secrets become the mask
+    pub fn resolve(&self, text: &str, masked: bool) -> Result<String, AppError> {
+        let Some(environment) = &self.environment else {
+            return Ok(text.to_string());
+        };
+
+        let mut resolved = String::with_capacity(text.len());
+        let mut undefined = Vec::new();
+        let mut rest = text;
+        while let Some(start) = rest.find("{{") {
+            resolved.push_str(&rest[..start]);
+            let after = &rest[start + 2..];
+            let Some(end) = after.find("}}") else {
+                rest = &rest[start..];
+                break;
+            };
+
+            let name = after[..end].trim();
+            if !is_variable_name(name) {
+                resolved.push_str("{{");
+                rest = after;
+                continue;
+            }
+            match self.variables.iter().find(|v| v.key == name) {
+                Some(v) if masked && v.secret => resolved.push_str(SECRET_MASK),
+                Some(v) => resolved.push_str(&v.value),
+                None => {
+                    undefined.push(name.to_string());
+                    resolved.push_str(&rest[start..start + end + 4]);
+                }
+            }
+            rest = &after[end + 2..];
+        }
+        resolved.push_str(rest);
+
+        if !undefined.is_empty() {
+            return Err(AppError::Domain(format!(
+                "Undefined variables in environment {}: {}",
+                environment,
+                undefined.join(", ")
+            )));
+        }
+        Ok(resolved)
+    }
+
+    /// Replace the placeholders in every string of a JSON value
+    pub fn resolve_json(&self, value: &Value, masked: bool) -> Result<Value, AppError> {
+        Ok(match value {
+            Value::String(text) => Value::String(self.resolve(text, masked)?),
+            Value::Array(items) => Value::Array(
+                items
+                    .iter()
+                    .map(|item| self.resolve_json(item, masked))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Value::Object(fields) => Value::Object(
+                fields
+                    .iter()
+                    .map(|(key, item)| Ok((key.clone(), self.resolve_json(item, masked)?)))
+                    .collect::<Result<_, AppError>>()?,
+            ),
+            other => other.clone(),
+        })
+    }
+
+    /// Hide secret values that appear in a text, e.g. a response echoing its arguments
+    pub fn mask_secrets(&self, text: &str) -> String {
+        self.variables
+            .iter()
+            .filter(|v| v.secret && v.value.len() >= MIN_MASKED_SECRET_LEN)
+            .fold(text.to_string(), |text, v| text.replace(&v.value, SECRET_MASK))
+    }
+
+    /// A copy of the server with its URL, headers, credentials and (stdio) environment resolved
+    pub fn resolve_server(&self, server: &McpServer) -> Result<McpServer, AppError> {
+        let mut resolved = server.clone();
+        resolved.url = self.resolve(&server.url, false)?;
+        for value in resolved.headers.values_mut().chain(resolved.env.values_mut()) {
+            *value = self.resolve(value, false)?;
+        }
+        match &mut resolved.auth {
+            McpServerAuth::None => {}
+            McpServerAuth::Bearer { token } => *token = self.resolve(token, false)?,
+            McpServerAuth::Basic { username, password } => {
+                *username = self.resolve(username, false)?;
+                *password = self.resolve(password, false)?;
+            }
+            McpServerAuth::OAuth { client_id, client_secret, .. } => {
+                for value in [client_id, client_secret].into_iter().flatten() {
+                    *value = self.resolve(value, false)?;
+                }
+            }
+        }
+        Ok(resolved)
+    }
+}
+
+/// Letters, digits, '_', '-' and '.'
+pub fn is_variable_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || "_-.".contains(c))
+}
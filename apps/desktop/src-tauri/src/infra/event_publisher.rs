@@ -1,17 +1,21 @@
 use crate::domain::events::{DomainEvent, IEventPublisher};
 use async_trait::async_trait;
+#[cfg(feature = "desktop")]
 use tauri::{AppHandle, Emitter, Runtime};
 
+#[cfg(feature = "desktop")]
 pub struct TauriEventPublisher<R: Runtime> {
     app_handle: AppHandle<R>,
 }
 
+#[cfg(feature = "desktop")]
 impl<R: Runtime> TauriEventPublisher<R> {
     pub fn new(app_handle: AppHandle<R>) -> Self {
         Self { app_handle }
     }
 }
 
+#[cfg(feature = "desktop")]
 impl<R: Runtime> IEventPublisher for TauriEventPublisher<R> {
     fn publish(&self, event: DomainEvent) {
         // We emit to all windows (broadcast)
@@ -34,16 +38,19 @@ pub trait EventPublisher: Send + Sync {
 }
 
 /// Tauri-based implementation of generic EventPublisher
+#[cfg(feature = "desktop")]
 pub struct TauriGenericEventPublisher<R: Runtime> {
     app_handle: AppHandle<R>,
 }
 
+#[cfg(feature = "desktop")]
 impl<R: Runtime> TauriGenericEventPublisher<R> {
     pub fn new(app_handle: AppHandle<R>) -> Self {
         Self { app_handle }
     }
 }
 
+#[cfg(feature = "desktop")]
 #[async_trait]
 impl<R: Runtime> EventPublisher for TauriGenericEventPublisher<R> {
     async fn publish(&self, event_name: &str, data: serde_json::Value) {
@@ -54,3 +61,19 @@ impl<R: Runtime> EventPublisher for TauriGenericEventPublisher<R> {
         }
     }
 }
+
+/// Publisher without a window (headless runner): events are only logged
+pub struct LogEventPublisher;
+
+impl IEventPublisher for LogEventPublisher {
+    fn publish(&self, event: DomainEvent) {
+        tracing::debug!(target: "backend", event = event.name(), ?event, "Event without a window");
+    }
+}
+
+#[async_trait]
+impl EventPublisher for LogEventPublisher {
+    async fn publish(&self, event_name: &str, data: serde_json::Value) {
+        tracing::debug!(target: "backend", event = event_name, %data, "Event without a window");
+    }
+}
@@ -5,13 +5,14 @@
 //! - Accepts POST requests with multipart/form-data
 //! - Extracts requestId and file data
 //! - Saves files and notifies the application
+//! - Serves `/oauth/callback`, the loopback redirect of MCP OAuth flows
 
 use axum::{
     body::Body,
-    extract::{FromRequest, Json as ExtractJson, Multipart, State},
+    extract::{FromRequest, Json as ExtractJson, Multipart, Query, State},
     http::{header::CONTENT_TYPE, Request, StatusCode},
-    response::Json,
-    routing::post,
+    response::{Html, Json},
+    routing::{get, post},
     Router,
 };
 use serde::{Deserialize, Serialize};
@@ -54,10 +55,23 @@ pub struct ReceivedMessageInfo {
 /// Callback for handling received messages
 pub type MessageCallback = Arc<dyn Fn(ReceivedMessageInfo) + Send + Sync>;
 
+/// Query parameters of an OAuth authorization response
+#[derive(Debug, Clone, Deserialize)]
+pub struct OAuthCallbackParams {
+    pub code: Option<String>,
+    pub state: Option<String>,
+    pub error: Option<String>,
+    pub error_description: Option<String>,
+}
+
+/// Callback for OAuth redirects arriving at `/oauth/callback`
+pub type OAuthCallback = Arc<dyn Fn(OAuthCallbackParams) + Send + Sync>;
+
 /// HTTP Server state
 struct ServerState {
     config: HttpServerConfig,
     callback: Option<MessageCallback>,
+    oauth_callback: Option<OAuthCallback>,
 }
 
 /// HTTP Server manager
@@ -65,6 +79,7 @@ pub struct HttpServerManager {
     state: RwLock<Option<ServerHandle>>,
     config: RwLock<HttpServerConfig>,
     callback: RwLock<Option<MessageCallback>>,
+    oauth_callback: RwLock<Option<OAuthCallback>>,
 }
 
 struct ServerHandle {
@@ -77,6 +92,7 @@ impl HttpServerManager {
             state: RwLock::new(None),
             config: RwLock::new(HttpServerConfig::default()),
             callback: RwLock::new(None),
+            oauth_callback: RwLock::new(None),
         }
     }
 
@@ -92,6 +108,21 @@ impl HttpServerManager {
         *cb = Some(callback);
     }
 
+    /// Set the callback for OAuth redirects
+    pub async fn set_oauth_callback(&self, callback: OAuthCallback) {
+        let mut cb = self.oauth_callback.write().await;
+        *cb = Some(callback);
+    }
+
+    /// Start the HTTP server on its configured port unless it is already running
+    pub async fn ensure_started(&self) -> Result<u16, AppError> {
+        if self.is_running().await {
+            return Ok(self.get_port().await);
+        }
+        let port = self.get_port().await;
+        self.start(port).await
+    }
+
     /// Start the HTTP server
     pub async fn start(&self, port: u16) -> Result<u16, AppError> {
         // Check if already running
@@ -110,6 +141,7 @@ impl HttpServerManager {
 
         let config = self.config.read().await.clone();
         let callback = self.callback.read().await.clone();
+        let oauth_callback = self.oauth_callback.read().await.clone();
 
         // Ensure storage directory exists
         if let Err(e) = fs::create_dir_all(&config.storage_path).await {
@@ -118,12 +150,13 @@ impl HttpServerManager {
         }
 
         // Create shared state
-        let state = Arc::new(ServerState { config: config.clone(), callback });
+        let state = Arc::new(ServerState { config: config.clone(), callback, oauth_callback });
 
         // Build router
         let app = Router::new()
             .route("/webhook/agent", post(handle_receive))
-            .route("/health", axum::routing::get(health_check))
+            .route("/health", get(health_check))
+            .route("/oauth/callback", get(handle_oauth_callback))
             .layer(CorsLayer::new().allow_origin(Any).allow_methods(Any).allow_headers(Any))
             .with_state(state);
 
@@ -213,6 +246,26 @@ async fn health_check() -> &'static str {
     "OK"
 }
 
+/// OAuth redirect endpoint; the flow is finished in the background
+async fn handle_oauth_callback(
+    State(state): State<Arc<ServerState>>,
+    Query(params): Query<OAuthCallbackParams>,
+) -> Html<&'static str> {
+    info!(target: "http_server", "Received OAuth callback (state: {:?})", params.state);
+
+    let denied = params.error.is_some();
+    match &state.oauth_callback {
+        Some(callback) => callback(params),
+        None => error!(target: "http_server", "OAuth callback received but no handler is set"),
+    }
+
+    if denied {
+        Html("<html><body><h3>Authorization was not granted.</h3><p>You can close this window and return to MCP Studio.</p></body></html>")
+    } else {
+        Html("<html><body><h3>Authorization received.</h3><p>You can close this window and return to MCP Studio.</p></body></html>")
+    }
+}
+
 /// Response for receive endpoint
 #[derive(Serialize)]
 struct ReceiveResponse {
@@ -462,7 +515,5 @@ fn extract_request_id(value: &Value) -> Option<String> {
 
 /// Generate a simple timestamp string without external deps
 fn chrono_lite_timestamp() -> String {
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let duration = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
-    format!("{}", duration.as_secs())
+    crate::infra::clock::unix_now().to_string()
 }
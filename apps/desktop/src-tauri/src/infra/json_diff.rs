@@ -0,0 +1,85 @@
+//! JSON Diff - structural comparison of two tool call outputs.
+//!
+//! Objects are compared key by key and arrays index by index, down to the leaves, so a
+//! change deep inside `content` is reported once at its own path rather than as a
+//! different top-level document.
+
+use serde_json::Value;
+
+use crate::domain::mcp::{McpCallDiff, McpCallHistory, McpJsonChange};
+
+/// Compare the output of a replayed call with the original one
+pub fn call_diff(original: &McpCallHistory, replay: &McpCallHistory) -> McpCallDiff {
+    let changes = diff(&call_output(original), &call_output(replay));
+    let status_changed = original.status != replay.status;
+
+    McpCallDiff { identical: changes.is_empty() && !status_changed, status_changed, changes }
+}
+
+/// What a call returned: its raw response, or the error when it never got one
+fn call_output(call: &McpCallHistory) -> Value {
+    match &call.output_result {
+        Some(output) => {
+            serde_json::from_str(output).unwrap_or_else(|_| Value::String(output.clone()))
+        }
+        None => serde_json::json!({ "error": call.error_message }),
+    }
+}
+
+/// Every leaf that differs between `before` and `after`
+pub fn diff(before: &Value, after: &Value) -> Vec<McpJsonChange> {
+    let mut changes = Vec::new();
+    diff_at("$".to_string(), before, after, &mut changes);
+    changes
+}
+
+fn diff_at(path: String, before: &Value, after: &Value, changes: &mut Vec<McpJsonChange>) {
+    match (before, after) {
+        (Value::Object(before), Value::Object(after)) => {
+            for (key, old) in before {
+                let key_path = object_path(&path, key);
+                match after.get(key) {
+                    Some(new) => diff_at(key_path, old, new, changes),
+                    None => changes.push(change(key_path, "removed", Some(old), None)),
+                }
+            }
+            for (key, new) in after.iter().filter(|(key, _)| !before.contains_key(*key)) {
+                changes.push(change(object_path(&path, key), "added", None, Some(new)));
+            }
+        }
+        (Value::Array(before), Value::Array(after)) => {
+            for index in 0..before.len().max(after.len()) {
+                let index_path = format!("{}[{}]", path, index);
+                match (before.get(index), after.get(index)) {
+                    (Some(old), Some(new)) => diff_at(index_path, old, new, changes),
+                    (Some(old), None) => {
+                        changes.push(change(index_path, "removed", Some(old), None))
+                    }
+                    (None, new) => changes.push(change(index_path, "added", None, new)),
+                }
+            }
+        }
+        _ if before != after => changes.push(change(path, "changed", Some(before), Some(after))),
+        _ => {}
+    }
+}
+
+fn change(
+    path: String,
+    kind: &str,
+    before: Option<&Value>,
+    after: Option<&Value>,
+) -> McpJsonChange {
+    McpJsonChange { path, kind: kind.to_string(), before: before.cloned(), after: after.cloned() }
+}
+
+/// `$.key`, or `$['odd key']` when the key isn't a plain identifier
+pub(crate) fn object_path(parent: &str, key: &str) -> String {
+    let plain = key.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if plain {
+        format!("{}.{}", parent, key)
+    } else {
+        format!("{}['{}']", parent, key.replace('\\', "\\\\").replace('\'', "\\'"))
+    }
+}
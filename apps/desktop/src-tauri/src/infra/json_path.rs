@@ -0,0 +1,193 @@
+//! JSON Path - the subset of JSONPath used by assertions on tool call results.
+//!
+//! Supported: `$`, `.key`, `['key']` / `["key"]`, `[0]`, `[-1]` (from the end) and the
+//! wildcards `.*` / `[*]`, e.g. `$.content[0].text` or `$.structuredContent.items[*].id`.
+//! Filters, slices and recursive descent are not supported.
+
+use serde_json::Value;
+
+use crate::error::AppError;
+
+/// One step of a path
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Key(String),
+    Index(i64),
+    Wildcard,
+}
+
+/// Values selected by `path` (empty when nothing matches)
+pub fn select<'a>(value: &'a Value, path: &str) -> Result<Vec<&'a Value>, AppError> {
+    let mut selected = vec![value];
+    for segment in parse(path)? {
+        selected = selected.into_iter().flat_map(|value| step(value, &segment)).collect();
+    }
+    Ok(selected)
+}
+
+/// Check that a path can be used
+pub fn validate(path: &str) -> Result<(), AppError> {
+    parse(path).map(|_| ())
+}
+
+fn step<'a>(value: &'a Value, segment: &Segment) -> Vec<&'a Value> {
+    match (segment, value) {
+        (Segment::Key(key), Value::Object(fields)) => fields.get(key).into_iter().collect(),
+        (Segment::Index(index), Value::Array(items)) => {
+            let index = if *index < 0 { items.len() as i64 + index } else { *index };
+            usize::try_from(index).ok().and_then(|i| items.get(i)).into_iter().collect()
+        }
+        (Segment::Wildcard, Value::Object(fields)) => fields.values().collect(),
+        (Segment::Wildcard, Value::Array(items)) => items.iter().collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn parse(path: &str) -> Result<Vec<Segment>, AppError> {
+    let invalid =
+        |reason: &str| AppError::Domain(format!("Invalid JSONPath '{}': {}", path, reason));
+
+    let mut rest = path.trim().strip_prefix('$').ok_or_else(|| invalid("must start with $"))?;
+    let mut segments = Vec::new();
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('.') {
+            if after.starts_with('.') {
+                return Err(invalid("recursive descent (..) is not supported"));
+            }
+            if let Some(after) = after.strip_prefix('*') {
+                segments.push(Segment::Wildcard);
+                rest = after;
+                continue;
+            }
+            let end = after.find(['.', '[']).unwrap_or(after.len());
+            if end == 0 {
+                return Err(invalid("empty key after '.'"));
+            }
+            segments.push(Segment::Key(after[..end].to_string()));
+            rest = &after[end..];
+        } else if let Some(after) = rest.strip_prefix('[') {
+            let (segment, after) = parse_bracket(after).ok_or_else(|| invalid("bad [...]"))?;
+            segments.push(segment);
+            rest = after;
+        } else {
+            return Err(invalid("expected '.' or '['"));
+        }
+    }
+    Ok(segments)
+}
+
+/// Inside `[...]`: a quoted key, an index or `*`; returns the text after `]`
+fn parse_bracket(text: &str) -> Option<(Segment, &str)> {
+    let quote = text.chars().next().filter(|c| *c == '\'' || *c == '"');
+    let Some(quote) = quote else {
+        let end = text.find(']')?;
+        let inner = text[..end].trim();
+        let segment = match inner {
+            "*" => Segment::Wildcard,
+            _ => Segment::Index(inner.parse().ok()?),
+        };
+        return Some((segment, &text[end + 1..]));
+    };
+
+    // Quoted key, with \' \" and \\ escapes
+    let mut key = String::new();
+    let mut chars = text.char_indices().skip(1);
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => key.push(chars.next()?.1),
+            c if c == quote => {
+                let after = text[i + 1..].trim_start().strip_prefix(']')?;
+                return Some((Segment::Key(key), after));
+            }
+            c => key.push(c),
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn doc() -> Value {
+        json!({
+            "content": [{"type": "text", "text": "a"}, {"type": "text", "text": "b"}],
+            "structuredContent": {"items": [{"id": "x"}, {"id": "y"}], "a.b": 1, "it's": 2},
+        })
+    }
+
+    fn texts(path: &str) -> Vec<Value> {
+        select(&doc(), path).unwrap().into_iter().cloned().collect()
+    }
+
+    #[test]
+    fn parses_dots_brackets_and_wildcards() {
+        assert_eq!(
+            parse("$.content[0]['text']").unwrap(),
+            vec![Segment::Key("content".into()), Segment::Index(0), Segment::Key("text".into())]
+        );
+        assert_eq!(
+            parse(r#"$.*[*]["a\"b"][ -1 ]"#).unwrap(),
+            vec![
+                Segment::Wildcard,
+                Segment::Wildcard,
+                Segment::Key("a\"b".into()),
+                Segment::Index(-1)
+            ]
+        );
+        assert_eq!(parse(" $ ").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn selects_keys_and_indexes() {
+        assert_eq!(texts("$"), vec![doc()]);
+        assert_eq!(texts("$.content[1].text"), vec![json!("b")]);
+        assert_eq!(texts("$['structuredContent']['a.b']"), vec![json!(1)]);
+        assert_eq!(texts(r"$.structuredContent['it\'s']"), vec![json!(2)]);
+    }
+
+    #[test]
+    fn counts_negative_indexes_from_the_end() {
+        assert_eq!(texts("$.content[-1].text"), vec![json!("b")]);
+        assert_eq!(texts("$.content[-2].text"), vec![json!("a")]);
+    }
+
+    #[test]
+    fn selects_nothing_out_of_range_or_on_a_type_mismatch() {
+        assert!(texts("$.content[2]").is_empty());
+        assert!(texts("$.content[-3]").is_empty());
+        assert!(texts("$.content.text").is_empty());
+        assert!(texts("$.structuredContent[0]").is_empty());
+        assert!(texts("$.missing[*]").is_empty());
+    }
+
+    #[test]
+    fn wildcards_select_every_item_and_field() {
+        assert_eq!(texts("$.content[*].text"), vec![json!("a"), json!("b")]);
+        assert_eq!(texts("$.structuredContent.items.*.id"), vec![json!("x"), json!("y")]);
+        assert_eq!(texts("$.structuredContent.items[0].*"), vec![json!("x")]);
+    }
+
+    #[test]
+    fn rejects_malformed_paths() {
+        for path in [
+            "",
+            "content",
+            "$content",
+            "$..id",
+            "$.",
+            "$.a.",
+            "$.a[",
+            "$.a[0",
+            "$.a[x]",
+            "$.a[1.5]",
+            "$.a[]",
+            "$.a['b]",
+            "$.a['b'",
+            "$.a['b'x]",
+        ] {
+            assert!(validate(path).is_err(), "{} should be rejected", path);
+        }
+    }
+}
@@ -0,0 +1,411 @@
+//! JSON Schema - validation of structured tool results against a tool's `outputSchema`.
+//!
+//! Covers the keywords tool schemas use in practice: `type`, `enum`, `const`, `properties`,
+//! `required`, `additionalProperties`, `items`, `minItems`/`maxItems`, `minLength`/
+//! `maxLength`, `pattern`, `minimum`/`maximum` (and their exclusive forms), `allOf`/
+//! `anyOf`/`oneOf`/`not` and local `$ref`s (`#/$defs/...`). Other keywords are ignored.
+
+use serde_json::{Map, Value};
+
+use crate::infra::json_diff::object_path;
+
+/// `$ref`s followed before giving up (guards against schemas that refer to themselves)
+const MAX_DEPTH: usize = 64;
+
+/// Every way `instance` fails `schema`, as `path: message` (empty when valid)
+pub fn validate(schema: &Value, instance: &Value) -> Vec<String> {
+    let mut errors = Vec::new();
+    Validator { root: schema }.check(schema, instance, "$", 0, &mut errors);
+    errors
+}
+
+struct Validator<'a> {
+    root: &'a Value,
+}
+
+impl Validator<'_> {
+    fn check(
+        &self,
+        schema: &Value,
+        instance: &Value,
+        path: &str,
+        depth: usize,
+        errors: &mut Vec<String>,
+    ) {
+        let schema = match schema {
+            Value::Bool(true) => return,
+            Value::Bool(false) => return errors.push(format!("{}: no value is allowed", path)),
+            Value::Object(schema) => schema,
+            _ => return,
+        };
+        let mut fail = |message: String| errors.push(format!("{}: {}", path, message));
+
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            match reference.strip_prefix('#').and_then(|p| self.root.pointer(p)) {
+                Some(_) if depth >= MAX_DEPTH => fail(format!("$ref {} nests too deep", reference)),
+                Some(target) => self.check(target, instance, path, depth + 1, errors),
+                None => fail(format!("$ref {} can't be resolved", reference)),
+            }
+            return;
+        }
+
+        if let Some(expected) = schema.get("type") {
+            let types: Vec<&str> = match expected {
+                Value::String(t) => vec![t.as_str()],
+                Value::Array(ts) => ts.iter().filter_map(Value::as_str).collect(),
+                _ => Vec::new(),
+            };
+            if !types.is_empty() && !types.iter().any(|t| has_type(instance, t)) {
+                fail(format!("expected {}, got {}", types.join(" or "), type_name(instance)));
+                return;
+            }
+        }
+        if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+            if !allowed.contains(instance) {
+                fail(format!("{} is not one of {}", instance, Value::Array(allowed.clone())));
+            }
+        }
+        if let Some(constant) = schema.get("const") {
+            if constant != instance {
+                fail(format!("expected {}, got {}", constant, instance));
+            }
+        }
+
+        match instance {
+            Value::Object(fields) => self.check_object(schema, fields, path, depth, errors),
+            Value::Array(items) => self.check_array(schema, items, path, depth, errors),
+            Value::String(text) => check_string(schema, text, &mut fail),
+            Value::Number(number) => {
+                if let Some(number) = number.as_f64() {
+                    check_number(schema, number, &mut fail);
+                }
+            }
+            _ => {}
+        }
+
+        self.check_combinators(schema, instance, path, depth, errors);
+    }
+
+    fn check_object(
+        &self,
+        schema: &Map<String, Value>,
+        fields: &Map<String, Value>,
+        path: &str,
+        depth: usize,
+        errors: &mut Vec<String>,
+    ) {
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for required in schema.get("required").and_then(Value::as_array).into_iter().flatten() {
+            if let Some(key) = required.as_str().filter(|key| !fields.contains_key(*key)) {
+                errors.push(format!("{}: missing required property '{}'", path, key));
+            }
+        }
+        for (key, value) in fields {
+            let key_path = object_path(path, key);
+            match (properties.and_then(|p| p.get(key)), schema.get("additionalProperties")) {
+                (Some(property), _) => self.check(property, value, &key_path, depth, errors),
+                (None, Some(Value::Bool(false))) => {
+                    errors.push(format!("{}: property is not allowed", key_path))
+                }
+                (None, Some(additional)) => self.check(additional, value, &key_path, depth, errors),
+                (None, None) => {}
+            }
+        }
+    }
+
+    fn check_array(
+        &self,
+        schema: &Map<String, Value>,
+        items: &[Value],
+        path: &str,
+        depth: usize,
+        errors: &mut Vec<String>,
+    ) {
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+            if (items.len() as u64) < min {
+                errors.push(format!(
+                    "{}: expected at least {} items, got {}",
+                    path,
+                    min,
+                    items.len()
+                ));
+            }
+        }
+        if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+            if items.len() as u64 > max {
+                errors.push(format!(
+                    "{}: expected at most {} items, got {}",
+                    path,
+                    max,
+                    items.len()
+                ));
+            }
+        }
+        if let Some(item_schema) = schema.get("items").filter(|s| !s.is_array()) {
+            for (index, item) in items.iter().enumerate() {
+                self.check(item_schema, item, &format!("{}[{}]", path, index), depth, errors);
+            }
+        }
+    }
+
+    fn check_combinators(
+        &self,
+        schema: &Map<String, Value>,
+        instance: &Value,
+        path: &str,
+        depth: usize,
+        errors: &mut Vec<String>,
+    ) {
+        let passes = |sub: &Value| {
+            let mut sub_errors = Vec::new();
+            self.check(sub, instance, path, depth, &mut sub_errors);
+            sub_errors.is_empty()
+        };
+
+        for sub in schema.get("allOf").and_then(Value::as_array).into_iter().flatten() {
+            self.check(sub, instance, path, depth, errors);
+        }
+        if let Some(subs) = schema.get("anyOf").and_then(Value::as_array) {
+            if !subs.iter().any(passes) {
+                errors.push(format!("{}: matches none of the anyOf schemas", path));
+            }
+        }
+        if let Some(subs) = schema.get("oneOf").and_then(Value::as_array) {
+            let matching = subs.iter().filter(|sub| passes(sub)).count();
+            if matching != 1 {
+                errors.push(format!(
+                    "{}: must match exactly one oneOf schema, matches {}",
+                    path, matching
+                ));
+            }
+        }
+        if let Some(sub) = schema.get("not") {
+            if passes(sub) {
+                errors.push(format!("{}: must not match the 'not' schema", path));
+            }
+        }
+    }
+}
+
+fn check_string(schema: &Map<String, Value>, text: &str, fail: &mut impl FnMut(String)) {
+    let length = text.chars().count() as u64;
+    if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+        if length < min {
+            fail(format!("expected at least {} characters, got {}", min, length));
+        }
+    }
+    if let Some(max) = schema.get("maxLength").and_then(Value::as_u64) {
+        if length > max {
+            fail(format!("expected at most {} characters, got {}", max, length));
+        }
+    }
+    if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+        // Invalid patterns in the tool's schema are not the result's fault
+        if regex::Regex::new(pattern).is_ok_and(|re| !re.is_match(text)) {
+            fail(format!("'{}' doesn't match pattern {}", text, pattern));
+        }
+    }
+}
+
+fn check_number(schema: &Map<String, Value>, number: f64, fail: &mut impl FnMut(String)) {
+    let limit = |key: &str| schema.get(key).and_then(Value::as_f64);
+    if let Some(min) = limit("minimum").filter(|min| number < *min) {
+        fail(format!("{} is less than the minimum {}", number, min));
+    }
+    if let Some(max) = limit("maximum").filter(|max| number > *max) {
+        fail(format!("{} is greater than the maximum {}", number, max));
+    }
+    if let Some(min) = limit("exclusiveMinimum").filter(|min| number <= *min) {
+        fail(format!("{} is not greater than {}", number, min));
+    }
+    if let Some(max) = limit("exclusiveMaximum").filter(|max| number >= *max) {
+        fail(format!("{} is not less than {}", number, max));
+    }
+}
+
+fn has_type(instance: &Value, expected: &str) -> bool {
+    match expected {
+        "integer" => match instance {
+            Value::Number(n) => {
+                n.is_i64() || n.is_u64() || n.as_f64().is_some_and(|f| f.fract() == 0.0)
+            }
+            _ => false,
+        },
+        "number" => instance.is_number(),
+        other => type_name(instance) == other,
+    }
+}
+
+fn type_name(instance: &Value) -> &'static str {
+    match instance {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn errors(schema: Value, instance: Value) -> Vec<String> {
+        validate(&schema, &instance)
+    }
+
+    #[test]
+    fn boolean_schemas() {
+        assert!(errors(json!(true), json!(1)).is_empty());
+        assert_eq!(errors(json!(false), json!(1)), vec!["$: no value is allowed"]);
+    }
+
+    #[test]
+    fn types() {
+        assert!(errors(json!({"type": "string"}), json!("a")).is_empty());
+        assert!(errors(json!({"type": "integer"}), json!(2.0)).is_empty());
+        assert!(errors(json!({"type": "number"}), json!(2)).is_empty());
+        assert!(errors(json!({"type": ["string", "null"]}), json!(null)).is_empty());
+        assert_eq!(
+            errors(json!({"type": "integer"}), json!(2.5)),
+            vec!["$: expected integer, got number"]
+        );
+        assert_eq!(
+            errors(json!({"type": ["object", "array"]}), json!(true)),
+            vec!["$: expected object or array, got boolean"]
+        );
+    }
+
+    #[test]
+    fn enum_and_const() {
+        assert!(errors(json!({"enum": ["a", 1]}), json!(1)).is_empty());
+        assert_eq!(
+            errors(json!({"enum": ["a", 1]}), json!("b")),
+            vec![r#"$: "b" is not one of ["a",1]"#]
+        );
+        assert!(errors(json!({"const": {"a": 1}}), json!({"a": 1})).is_empty());
+        assert_eq!(errors(json!({"const": 1}), json!(2)), vec!["$: expected 1, got 2"]);
+    }
+
+    #[test]
+    fn properties_and_required() {
+        let schema = json!({
+            "properties": {"id": {"type": "string"}},
+            "required": ["id", "name"],
+        });
+        assert!(errors(schema.clone(), json!({"id": "a", "name": 1, "extra": true})).is_empty());
+        assert_eq!(
+            errors(schema, json!({"id": 1})),
+            vec!["$: missing required property 'name'", "$.id: expected string, got number"]
+        );
+    }
+
+    #[test]
+    fn additional_properties() {
+        let closed = json!({"properties": {"id": {}}, "additionalProperties": false});
+        assert!(errors(closed.clone(), json!({"id": 1})).is_empty());
+        assert_eq!(errors(closed, json!({"id": 1, "x": 2})), vec!["$.x: property is not allowed"]);
+
+        let typed = json!({"properties": {"id": {}}, "additionalProperties": {"type": "integer"}});
+        assert!(errors(typed.clone(), json!({"id": "a", "x": 2})).is_empty());
+        assert_eq!(errors(typed, json!({"x": "2"})), vec!["$.x: expected integer, got string"]);
+    }
+
+    #[test]
+    fn items_and_item_counts() {
+        let schema = json!({"items": {"type": "integer"}, "minItems": 1, "maxItems": 2});
+        assert!(errors(schema.clone(), json!([1, 2])).is_empty());
+        assert_eq!(errors(schema.clone(), json!([])), vec!["$: expected at least 1 items, got 0"]);
+        assert_eq!(
+            errors(schema, json!([1, "b", 3])),
+            vec!["$: expected at most 2 items, got 3", "$[1]: expected integer, got string"]
+        );
+    }
+
+    #[test]
+    fn string_length_and_pattern() {
+        let schema = json!({"minLength": 2, "maxLength": 3, "pattern": "^[a-zé]+$"});
+        assert!(errors(schema.clone(), json!("éé")).is_empty());
+        assert_eq!(
+            errors(schema.clone(), json!("a")),
+            vec!["$: expected at least 2 characters, got 1"]
+        );
+        assert_eq!(
+            errors(schema.clone(), json!("abcd")),
+            vec!["$: expected at most 3 characters, got 4"]
+        );
+        assert_eq!(errors(schema, json!("AB")), vec!["$: 'AB' doesn't match pattern ^[a-zé]+$"]);
+        // An invalid pattern is ignored
+        assert!(errors(json!({"pattern": "("}), json!("a")).is_empty());
+    }
+
+    #[test]
+    fn number_limits() {
+        let inclusive = json!({"minimum": 1, "maximum": 3});
+        assert!(errors(inclusive.clone(), json!(1)).is_empty());
+        assert!(errors(inclusive.clone(), json!(3)).is_empty());
+        assert_eq!(
+            errors(inclusive.clone(), json!(0.5)),
+            vec!["$: 0.5 is less than the minimum 1"]
+        );
+        assert_eq!(errors(inclusive, json!(4)), vec!["$: 4 is greater than the maximum 3"]);
+
+        let exclusive = json!({"exclusiveMinimum": 1, "exclusiveMaximum": 3});
+        assert!(errors(exclusive.clone(), json!(2)).is_empty());
+        assert_eq!(errors(exclusive.clone(), json!(1)), vec!["$: 1 is not greater than 1"]);
+        assert_eq!(errors(exclusive, json!(3)), vec!["$: 3 is not less than 3"]);
+    }
+
+    #[test]
+    fn all_of_and_any_of() {
+        let all = json!({"allOf": [{"type": "integer"}, {"minimum": 2}]});
+        assert!(errors(all.clone(), json!(2)).is_empty());
+        assert_eq!(errors(all, json!(1)), vec!["$: 1 is less than the minimum 2"]);
+
+        let any = json!({"anyOf": [{"type": "string"}, {"minimum": 2}]});
+        assert!(errors(any.clone(), json!("a")).is_empty());
+        assert!(errors(any.clone(), json!(3)).is_empty());
+        assert_eq!(errors(any, json!(1)), vec!["$: matches none of the anyOf schemas"]);
+    }
+
+    #[test]
+    fn one_of_and_not() {
+        let one = json!({"oneOf": [{"type": "integer"}, {"minimum": 2}]});
+        assert!(errors(one.clone(), json!(1)).is_empty());
+        assert_eq!(
+            errors(one.clone(), json!(3)),
+            vec!["$: must match exactly one oneOf schema, matches 2"]
+        );
+        assert_eq!(
+            errors(one, json!(0.5)),
+            vec!["$: must match exactly one oneOf schema, matches 0"]
+        );
+
+        let not = json!({"not": {"type": "null"}});
+        assert!(errors(not.clone(), json!(0)).is_empty());
+        assert_eq!(errors(not, json!(null)), vec!["$: must not match the 'not' schema"]);
+    }
+
+    #[test]
+    fn local_refs() {
+        let schema = json!({
+            "type": "array",
+            "items": {"$ref": "#/$defs/item"},
+            "$defs": {"item": {"type": "object", "required": ["id"]}},
+        });
+        assert!(errors(schema.clone(), json!([{"id": 1}])).is_empty());
+        assert_eq!(errors(schema, json!([{}])), vec!["$[0]: missing required property 'id'"]);
+        assert_eq!(
+            errors(json!({"$ref": "#/$defs/missing"}), json!(1)),
+            vec!["$: $ref #/$defs/missing can't be resolved"]
+        );
+        assert_eq!(errors(json!({"$ref": "#"}), json!(1)), vec!["$: $ref # nests too deep"]);
+    }
+
+    #[test]
+    fn ignores_unknown_keywords() {
+        assert!(errors(json!({"format": "email", "title": "x"}), json!("not an email")).is_empty());
+    }
+}
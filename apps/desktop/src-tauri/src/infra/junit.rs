@@ -0,0 +1,207 @@
+//! JUnit XML - reports of collection runs and test runs for CI systems.
+//!
+//! One `<testsuite>` per run and one `<testcase>` per saved call. The classname is the
+//! collection name followed by the folder path, so CI tools group calls like the sidebar
+//! does. Failed calls get a `<failure>` (test runs list every assertion in its body) and
+//! skipped calls a `<skipped/>`.
+
+use std::fmt::Write;
+
+use crate::domain::collection::McpCollectionRunReport;
+use crate::domain::test_run::McpTestRunDetail;
+
+/// One `<testcase>`
+struct Case<'a> {
+    name: &'a str,
+    classname: String,
+    duration_ms: i64,
+    status: &'a str,
+    failure: Option<Failure>,
+}
+
+/// A `<failure>`
+struct Failure {
+    kind: String, // The call's status, or what failed when the call completed
+    message: String,
+    body: String,
+}
+
+/// Report of a collection run: a call fails when it didn't succeed
+pub fn collection_report(report: &McpCollectionRunReport) -> String {
+    let cases: Vec<Case> = report
+        .results
+        .iter()
+        .map(|result| Case {
+            name: &result.name,
+            classname: classname(&report.collection_name, &result.folder_path),
+            duration_ms: result.duration_ms,
+            status: &result.status,
+            failure: (!result.success && result.status != "skipped").then(|| {
+                let message = result
+                    .error
+                    .clone()
+                    .unwrap_or_else(|| "The tool reported an error (isError)".to_string());
+                let body = result
+                    .result
+                    .as_ref()
+                    .map(|r| r.raw_response.clone())
+                    .unwrap_or_else(|| message.clone());
+                Failure { kind: failure_kind(&result.status, "is_error"), message, body }
+            }),
+        })
+        .collect();
+
+    document(
+        &report.collection_name,
+        &report.started_at,
+        report.duration_ms,
+        report.skipped,
+        &cases,
+    )
+}
+
+/// Report of a test run: a call fails when any of its assertions failed
+pub fn test_run_report(detail: &McpTestRunDetail) -> String {
+    let run = &detail.run;
+    let cases: Vec<Case> = detail
+        .results
+        .iter()
+        .map(|result| Case {
+            name: &result.name,
+            classname: classname(&run.collection_name, &result.folder_path),
+            duration_ms: result.duration_ms,
+            status: &result.status,
+            failure: (!result.passed && result.status != "skipped").then(|| {
+                let failed: Vec<&str> = result
+                    .assertions
+                    .iter()
+                    .filter(|a| !a.passed)
+                    .filter_map(|a| a.message.as_deref())
+                    .collect();
+                let message = match (&result.error, failed.first()) {
+                    (_, Some(first)) => first.to_string(),
+                    (Some(error), None) => error.clone(),
+                    (None, None) => "Assertions failed".to_string(),
+                };
+                let body = result
+                    .assertions
+                    .iter()
+                    .map(|a| {
+                        let outcome = if a.passed { "PASS" } else { "FAIL" };
+                        let kind = serde_json::to_string(&a.assertion).unwrap_or_default();
+                        match &a.message {
+                            Some(reason) => format!("{} {}: {}", outcome, kind, reason),
+                            None => format!("{} {}", outcome, kind),
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Failure { kind: failure_kind(&result.status, "assertion"), message, body }
+            }),
+        })
+        .collect();
+
+    document(&run.collection_name, &run.started_at, run.duration_ms, run.skipped, &cases)
+}
+
+fn document(
+    suite: &str,
+    started_at: &str,
+    duration_ms: i64,
+    skipped: usize,
+    cases: &[Case],
+) -> String {
+    let failures = cases.iter().filter(|c| c.failure.is_some()).count();
+    let time = seconds(duration_ms);
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{}\">",
+        escape(suite),
+        cases.len(),
+        failures,
+        skipped,
+        time
+    );
+    let _ = writeln!(
+        xml,
+        "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"{}\" time=\"{}\" timestamp=\"{}\">",
+        escape(suite),
+        cases.len(),
+        failures,
+        skipped,
+        time,
+        escape(started_at)
+    );
+
+    for case in cases {
+        let _ = write!(
+            xml,
+            "    <testcase name=\"{}\" classname=\"{}\" time=\"{}\"",
+            escape(case.name),
+            escape(&case.classname),
+            seconds(case.duration_ms)
+        );
+        match (&case.failure, case.status) {
+            (Some(failure), _) => {
+                let _ = writeln!(xml, ">");
+                let _ = writeln!(
+                    xml,
+                    "      <failure message=\"{}\" type=\"{}\">{}</failure>",
+                    escape(&failure.message),
+                    escape(&failure.kind),
+                    escape(&failure.body)
+                );
+                let _ = writeln!(xml, "    </testcase>");
+            }
+            (None, "skipped") => {
+                let _ = writeln!(xml, ">\n      <skipped/>\n    </testcase>");
+            }
+            (None, _) => {
+                let _ = writeln!(xml, "/>");
+            }
+        }
+    }
+
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
+}
+
+/// The call's status, or `completed` when the call went through but still failed
+fn failure_kind(status: &str, completed: &str) -> String {
+    match status {
+        "success" => completed.to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Collection name and folder path, dot separated
+fn classname(collection_name: &str, folder_path: &[String]) -> String {
+    std::iter::once(collection_name)
+        .chain(folder_path.iter().map(String::as_str))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn seconds(duration_ms: i64) -> String {
+    format!("{:.3}", duration_ms.max(0) as f64 / 1000.0)
+}
+
+/// Escape text for attributes and element content (control characters XML forbids are dropped)
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' | '\r' | '\t' => escaped.push(c),
+            c if (c as u32) < 0x20 => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -1,10 +1,18 @@
 //! MCP Client Manager - manages connections to MCP servers using rmcp SDK.
 //!
 //! This module provides:
-//! - HTTP streaming client connections to MCP servers
+//! - HTTP streaming, SSE and stdio client connections to MCP servers
 //! - Tool listing with raw JSON responses (cached in memory)
-//! - Tool calling with raw JSON responses
-//! - Heartbeat monitoring for connection health
+//! - Tool calling with raw JSON responses, progress events and cancellation
+//! - Resource listing, reading and subscriptions (resources cached in memory)
+//! - Prompt listing and rendering via prompts/get (prompts cached in memory)
+//! - Answering server-initiated sampling requests through the `SamplingResponder`
+//! - Answering server-initiated elicitation requests through the `ElicitationResponder`
+//! - Answering roots/list from the server's configured roots
+//! - Per-server HTTP headers and auth (bearer, basic, OAuth tokens from `McpOAuthManager`)
+//! - Persisting server log records (`notifications/message`) and sending logging/setLevel
+//! - Heartbeat monitoring (`ping` by default, per-server strategy and threshold) with RTT history
+//! - Recording every JSON-RPC frame on the wire through a per-connection `TrafficTap`
 //! - In-memory caching of tools (not persisted to database)
 //!
 //! ## Important Design Decision:
@@ -13,43 +21,17 @@
 //! 2. Cached in memory for performance
 //! 3. Automatically refreshed when server sends `ToolsListChanged` notification
 //!
-//! ## TODO: Implement ToolsListChanged Notification Listener
-//! To automatically refresh tools when the server notifies changes, we need to:
-//! 1. Implement a custom ClientHandler trait from rmcp::handler
-//! 2. Override the notification handler to listen for `notifications/tools/list_changed`
-//! 3. When notification is received, automatically call `list_tools()` to refresh cache
-//! 4. Emit an event to frontend via EventPublisher so UI can update
-//!
-//! Example implementation:
-//! ```rust,ignore
-//! struct McpNotificationHandler {
-//!     server_id: String,
-//!     manager: Arc<McpClientManager>,
-//! }
-//!
-//! impl ClientHandler for McpNotificationHandler {
-//!     async fn handle_notification(&self, method: &str, params: serde_json::Value) {
-//!         match method {
-//!             "notifications/tools/list_changed" => {
-//!                 info!("Tools changed for server {}", self.server_id);
-//!                 // Refresh tools cache
-//!                 if let Ok(result) = self.manager.list_tools(&self.server_id).await {
-//!                     // Publish event to frontend
-//!                     let event_data = serde_json::json!({
-//!                         "server_id": self.server_id,
-//!                         "tools_count": result.tools.len(),
-//!                     });
-//!                     self.manager.event_publisher.publish("mcp:tools_changed", event_data).await;
-//!                 }
-//!             }
-//!             _ => {}
-//!         }
-//!     }
-//! }
-//! ```
-
+//! ## Notifications
+//! Each connection is served by an `McpClientHandler` (see `mcp_handler.rs`). When a server
+//! sends `notifications/tools/list_changed`, the handler re-runs `tools/list`, updates the
+//! cache and publishes an `mcp:tools_changed` event with the before/after tool names.
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use futures::future::BoxFuture;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tokio::time::{interval, Duration};
@@ -58,29 +40,114 @@ use tracing::{debug, error, info, warn};
 
 use rmcp::{
     model::{
-        CallToolRequestParam, ClientCapabilities, ClientInfo, Implementation,
-        InitializeRequestParam,
+        CallToolRequestParam, CancelledNotificationParam, ClientCapabilities, ClientInfo,
+        ClientRequest, CreateElicitationRequestParam, CreateElicitationResult,
+        CreateMessageRequestParam, CreateMessageResult, ElicitationAction, ErrorData as McpError,
+        GetPromptRequestParam, Implementation, ListRootsResult, LoggingLevel,
+        LoggingMessageNotificationParam, Meta, NumberOrString, PaginatedRequestParam, PingRequest,
+        ProgressNotificationParam, ProgressToken, ReadResourceRequestParam, Request, Root,
+        RootsCapabilities, ServerResult, SetLevelRequestParam, SubscribeRequestParam,
+        UnsubscribeRequestParam,
     },
-    service::RunningService,
+    service::{Peer, PeerRequestOptions, RunningService, ServiceError},
     transport::streamable_http_client::{
         StreamableHttpClientTransport, StreamableHttpClientTransportConfig,
     },
     RoleClient, ServiceExt,
 };
 
-use crate::domain::mcp::McpToolCallResult;
+use crate::domain::mcp::{
+    HeartbeatStrategy, IMcpHeartbeatRepository, McpConnectionInfo, McpHeartbeat, McpListPaging,
+    McpPromptArgument, McpPromptGetResult, McpResourceReadResult, McpRoot, McpServer,
+    McpServerAuth, McpServerType, McpSessionTermination, McpToolCallResult,
+};
+use crate::domain::server_log::{IMcpServerLogRepository, McpLogLevel, McpServerLog};
+use crate::domain::traffic::IMcpTrafficRepository;
 use crate::error::AppError;
+use crate::infra::clock::now_timestamp;
+use crate::infra::elicitation_responder::ElicitationResponder;
+use crate::infra::environment::Variables;
 use crate::infra::event_publisher::EventPublisher;
-use tauri::async_runtime;
+use crate::infra::mcp_handler::McpClientHandler;
+use crate::infra::oauth::McpOAuthManager;
+use crate::infra::raw_rpc::{raw_rpc_channel, RawRpcReply, RawRpcSender};
+use crate::infra::sampling_responder::SamplingResponder;
+use crate::infra::sse_transport::{DisconnectCallback, StartupError};
+use crate::infra::streamable_http::SessionHttpClient;
+use crate::infra::traffic::TrafficTap;
+
+/// Upper bound on pages fetched by one list call, in case a server never stops paging
+const MAX_LIST_PAGES: usize = 100;
+
+/// Heartbeat interval used when neither the server nor the global setting specifies one
+const DEFAULT_HEARTBEAT_INTERVAL_SECS: u64 = 10;
+
+/// Disconnect after the first failed heartbeat (to react quickly), unless the server overrides it
+const DEFAULT_HEARTBEAT_MAX_FAILURES: u32 = 1;
+
+/// Error for a failed initialize; a 401 from any remote transport points at the OAuth flow.
+/// `startup_error` is the worker's own error, when it stopped before connecting.
+fn initialize_error(e: impl std::fmt::Display, startup_error: Option<&StartupError>) -> AppError {
+    let cause = startup_error.and_then(|cause| cause.lock().ok()?.take());
+    let message = cause.unwrap_or_else(|| e.to_string());
+    error!(target: "mcp_client", "Failed to initialize MCP client: {}", message);
+    // rmcp reports a 401 with a Bearer challenge as "Auth required"; the SSE stream and the
+    // WebSocket handshake report the HTTP status
+    if message.contains("401 Unauthorized") || message.contains("Auth required") {
+        return AppError::Domain(
+            "Server requires authorization (401 Unauthorized); authorize it and reconnect"
+                .to_string(),
+        );
+    }
+    AppError::Io(format!("Failed to initialize MCP connection: {}", message))
+}
 
-/// Create a reqwest client without proxy
-fn create_no_proxy_client() -> Result<reqwest::Client, AppError> {
+/// Create a reqwest client without proxy that sends the server's headers and auth on every request
+fn create_no_proxy_client(
+    server: &McpServer,
+    auth: &McpServerAuth,
+) -> Result<reqwest::Client, AppError> {
     reqwest::Client::builder()
         .no_proxy()
+        .default_headers(request_headers(&server.headers, auth)?)
         .build()
         .map_err(|e| AppError::Io(format!("Failed to create reqwest client: {}", e)))
 }
 
+/// Build the default headers of a remote server; auth overrides a custom `Authorization` header
+pub(crate) fn request_headers(
+    headers: &HashMap<String, String>,
+    auth: &McpServerAuth,
+) -> Result<HeaderMap, AppError> {
+    let mut header_map = HeaderMap::new();
+
+    for (name, value) in headers {
+        let header_name = HeaderName::from_bytes(name.trim().as_bytes())
+            .map_err(|_| AppError::Domain(format!("Invalid header name: {}", name)))?;
+        let header_value = HeaderValue::from_str(value.trim())
+            .map_err(|_| AppError::Domain(format!("Invalid value for header {}", name)))?;
+        header_map.insert(header_name, header_value);
+    }
+
+    let authorization = match auth {
+        // OAuth tokens are resolved to `Bearer` before connecting
+        McpServerAuth::None | McpServerAuth::OAuth { .. } => None,
+        McpServerAuth::Bearer { token } => Some(format!("Bearer {}", token.trim())),
+        McpServerAuth::Basic { username, password } => {
+            Some(format!("Basic {}", BASE64.encode(format!("{}:{}", username, password))))
+        }
+    };
+
+    if let Some(authorization) = authorization {
+        let mut value = HeaderValue::from_str(&authorization)
+            .map_err(|_| AppError::Domain("Invalid characters in credentials".to_string()))?;
+        value.set_sensitive(true);
+        header_map.insert(AUTHORIZATION, value);
+    }
+
+    Ok(header_map)
+}
+
 /// Tool information from MCP server
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct McpToolInfo {
@@ -96,12 +163,95 @@ pub struct McpToolInfo {
 pub struct McpToolsListResultInternal {
     pub tools: Vec<McpToolInfo>,
     pub raw_response: String,
+    pub paging: McpListPaging,
+}
+
+/// Resource information from MCP server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpResourceInfo {
+    pub uri: String,
+    pub name: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub mime_type: Option<String>,
+    pub size: Option<u32>,
+    pub extra: Option<String>, // JSON string
+}
+
+/// Resource template information from MCP server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpResourceTemplateInfo {
+    pub uri_template: String,
+    pub name: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub mime_type: Option<String>,
+    pub extra: Option<String>, // JSON string
+}
+
+/// Result of listing resources
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpResourcesListResultInternal {
+    pub resources: Vec<McpResourceInfo>,
+    pub raw_response: String,
+    pub paging: McpListPaging,
+}
+
+/// Result of listing resource templates
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpResourceTemplatesListResultInternal {
+    pub resource_templates: Vec<McpResourceTemplateInfo>,
+    pub raw_response: String,
+    pub paging: McpListPaging,
+}
+
+/// Prompt information from MCP server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpPromptInfo {
+    pub name: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub arguments: Vec<McpPromptArgument>,
+    pub extra: Option<String>, // JSON string
+}
+
+/// Result of listing prompts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpPromptsListResultInternal {
+    pub prompts: Vec<McpPromptInfo>,
+    pub raw_response: String,
+    pub paging: McpListPaging,
 }
 
 /// MCP Client connection wrapper
 struct McpConnection {
-    client: RunningService<RoleClient, InitializeRequestParam>,
+    client: RunningService<RoleClient, McpClientHandler>,
     heartbeat_cancel: CancellationToken,
+    /// Config the connection was opened with (reused by automatic reconnects)
+    server: McpServer,
+    /// Environment the config was resolved with; its secrets are masked in recorded traffic
+    variables: Variables,
+    /// HTTP client holding the session (streamable HTTP only)
+    session: Option<SessionHttpClient>,
+    /// Raw JSON-RPC channel into the transport worker (None for streamable HTTP, which uses the session)
+    raw_rpc: Option<RawRpcSender>,
+    /// Id the traffic tap records this connection's frames under
+    connection_id: String,
+}
+
+/// Resolved heartbeat settings of one connection
+#[derive(Clone, Copy)]
+struct HeartbeatSettings {
+    strategy: HeartbeatStrategy,
+    interval: Duration,
+    max_failures: u32,
+}
+
+/// Tool call awaiting a response
+struct InFlightCall {
+    server_id: String,
+    progress_token: String,
+    cancel: CancellationToken,
 }
 
 /// Manages multiple MCP client connections
@@ -109,6 +259,32 @@ pub struct McpClientManager {
     connections: Arc<RwLock<HashMap<String, McpConnection>>>,
     /// In-memory cache of tools per server (runtime data, not persisted)
     tools_cache: Arc<RwLock<HashMap<String, Vec<McpToolInfo>>>>,
+    /// In-memory cache of resources per server (runtime data, not persisted)
+    resources_cache: Arc<RwLock<HashMap<String, Vec<McpResourceInfo>>>>,
+    /// In-memory cache of resource templates per server (runtime data, not persisted)
+    resource_templates_cache: Arc<RwLock<HashMap<String, Vec<McpResourceTemplateInfo>>>>,
+    /// Resource URIs the user subscribed to, per server
+    resource_subscriptions: Arc<RwLock<HashMap<String, HashSet<String>>>>,
+    /// In-memory cache of prompts per server (runtime data, not persisted)
+    prompts_cache: Arc<RwLock<HashMap<String, Vec<McpPromptInfo>>>>,
+    /// Roots served to each server through roots/list
+    roots: Arc<RwLock<HashMap<String, Vec<McpRoot>>>>,
+    /// Answers sampling/createMessage requests (set once the database is ready)
+    sampling_responder: Arc<RwLock<Option<Arc<SamplingResponder>>>>,
+    /// Answers elicitation/create requests (set once the database is ready)
+    elicitation_responder: Arc<RwLock<Option<Arc<ElicitationResponder>>>>,
+    /// Tool calls awaiting a response, keyed by call id
+    in_flight_calls: Arc<RwLock<HashMap<String, InFlightCall>>>,
+    /// Stores log records from notifications/message (set once the database is ready)
+    log_repo: Arc<RwLock<Option<Arc<dyn IMcpServerLogRepository>>>>,
+    /// Repository for heartbeat round trips (set after database init)
+    heartbeat_repo: Arc<RwLock<Option<Arc<dyn IMcpHeartbeatRepository>>>>,
+    /// Stores frames recorded by the traffic taps (set after database init)
+    traffic_repo: Arc<RwLock<Option<Arc<dyn IMcpTrafficRepository>>>>,
+    /// OAuth tokens for servers using `McpServerAuth::OAuth`
+    oauth_manager: Arc<RwLock<Option<Arc<McpOAuthManager>>>>,
+    /// Pending automatic reconnects, keyed by server id
+    reconnect_tasks: Arc<RwLock<HashMap<String, CancellationToken>>>,
     event_publisher: Arc<dyn EventPublisher>,
     config_repo: Arc<RwLock<Option<Arc<dyn crate::domain::config::IConfigRepository>>>>,
 }
@@ -118,6 +294,19 @@ impl McpClientManager {
         Self {
             connections: Arc::new(RwLock::new(HashMap::new())),
             tools_cache: Arc::new(RwLock::new(HashMap::new())),
+            resources_cache: Arc::new(RwLock::new(HashMap::new())),
+            resource_templates_cache: Arc::new(RwLock::new(HashMap::new())),
+            resource_subscriptions: Arc::new(RwLock::new(HashMap::new())),
+            prompts_cache: Arc::new(RwLock::new(HashMap::new())),
+            roots: Arc::new(RwLock::new(HashMap::new())),
+            sampling_responder: Arc::new(RwLock::new(None)),
+            elicitation_responder: Arc::new(RwLock::new(None)),
+            in_flight_calls: Arc::new(RwLock::new(HashMap::new())),
+            log_repo: Arc::new(RwLock::new(None)),
+            heartbeat_repo: Arc::new(RwLock::new(None)),
+            traffic_repo: Arc::new(RwLock::new(None)),
+            oauth_manager: Arc::new(RwLock::new(None)),
+            reconnect_tasks: Arc::new(RwLock::new(HashMap::new())),
             event_publisher,
             config_repo: Arc::new(RwLock::new(None)),
         }
@@ -132,16 +321,62 @@ impl McpClientManager {
         *repo = Some(config_repo);
     }
 
+    /// Set the responder used for server-initiated sampling requests
+    pub async fn set_sampling_responder(&self, responder: Arc<SamplingResponder>) {
+        let mut sampling_responder = self.sampling_responder.write().await;
+        *sampling_responder = Some(responder);
+    }
+
+    /// Set the responder used for server-initiated elicitation requests
+    pub async fn set_elicitation_responder(&self, responder: Arc<ElicitationResponder>) {
+        let mut elicitation_responder = self.elicitation_responder.write().await;
+        *elicitation_responder = Some(responder);
+    }
+
+    /// Set the repository used to store server log records
+    pub async fn set_log_repo(&self, log_repo: Arc<dyn IMcpServerLogRepository>) {
+        let mut repo = self.log_repo.write().await;
+        *repo = Some(log_repo);
+    }
+
+    /// Set the repository used to store heartbeat round trips
+    pub async fn set_heartbeat_repo(&self, heartbeat_repo: Arc<dyn IMcpHeartbeatRepository>) {
+        let mut repo = self.heartbeat_repo.write().await;
+        *repo = Some(heartbeat_repo);
+    }
+
+    /// Set the repository used to store recorded traffic frames
+    pub async fn set_traffic_repo(&self, traffic_repo: Arc<dyn IMcpTrafficRepository>) {
+        let mut repo = self.traffic_repo.write().await;
+        *repo = Some(traffic_repo);
+    }
+
+    /// Set the OAuth manager providing access tokens
+    pub async fn set_oauth_manager(&self, oauth_manager: Arc<McpOAuthManager>) {
+        let mut manager = self.oauth_manager.write().await;
+        *manager = Some(oauth_manager);
+    }
+
     /// Connect to an MCP server (auto-select transport based on server_type)
-    pub async fn connect(
+    ///
+    /// `server` has its placeholders resolved with `variables`, whose secrets are masked in
+    /// the recorded traffic.
+    pub async fn connect(&self, server: &McpServer, variables: Variables) -> Result<(), AppError> {
+        // A manual connect supersedes a pending automatic reconnect
+        self.cancel_reconnect(&server.id).await;
+        self.open_connection(server, &variables).await.map(|_| ())
+    }
+
+    /// Open a connection and start its heartbeat; returns the id of the new connection
+    async fn open_connection(
         &self,
-        server_id: &str,
-        url: &str,
-        server_type: &str,
-    ) -> Result<(), AppError> {
-        info!(target: "mcp_client", "Connecting to MCP server {} at {} (type: {})", server_id, url, server_type);
+        server: &McpServer,
+        variables: &Variables,
+    ) -> Result<String, AppError> {
+        let server_id = server.id.as_str();
+        info!(target: "mcp_client", "Connecting to MCP server {} at {} (type: {})", server_id, server.url, server.server_type);
 
-        // Check if already connected
+        // Check if already connected (checked again when storing the connection)
         {
             let connections = self.connections.read().await;
             if connections.contains_key(server_id) {
@@ -152,7 +387,12 @@ impl McpClientManager {
         // Create client info
         let client_info = ClientInfo {
             protocol_version: Default::default(),
-            capabilities: ClientCapabilities::default(),
+            capabilities: ClientCapabilities {
+                sampling: Some(Default::default()),
+                elicitation: Some(Default::default()),
+                roots: Some(RootsCapabilities { list_changed: Some(true) }),
+                ..Default::default()
+            },
             client_info: Implementation {
                 name: "MCP Studio".to_string(),
                 title: Some("MCP Studio Debug Client".to_string()),
@@ -162,42 +402,111 @@ impl McpClientManager {
             },
         };
 
+        // Roots must be in place before initialize: servers may ask for them right away
+        self.roots.write().await.insert(server_id.to_string(), server.roots.clone());
+
+        // Each connection gets its own handler so notifications can be routed back to it
+        let handler =
+            McpClientHandler::new(server_id.to_string(), client_info, self.clone_manager_ref());
+
+        // Frames are recorded from the first byte on, so the initialize handshake is included
+        let traffic = TrafficTap::new(
+            server_id,
+            server.server_type.clone(),
+            self.traffic_repo.read().await.clone(),
+            self.event_publisher.clone(),
+            variables.clone(),
+        );
+        let connection_id = traffic.connection_id().to_string();
+
         // Select transport based on server_type
-        let client = match server_type {
-            "streamable_http" => {
+        let mut session = None;
+        let mut raw_rpc = None;
+        let startup_error = StartupError::default();
+        let client = match server.server_type {
+            McpServerType::StreamableHttp => {
                 info!(target: "mcp_client", "Using Streamable HTTP transport");
-                let http_client = create_no_proxy_client()?;
-                let config = StreamableHttpClientTransportConfig::with_uri(url);
+                let http_client = SessionHttpClient::new(
+                    create_no_proxy_client(server, &self.resolve_auth(server).await?)?,
+                    server_id,
+                    server.standalone_sse,
+                    traffic,
+                );
+                session = Some(http_client.clone());
+                let config = StreamableHttpClientTransportConfig::with_uri(server.url.as_str());
                 let transport = StreamableHttpClientTransport::with_client(http_client, config);
 
-                client_info.serve(transport).await.map_err(|e| {
-                    error!(target: "mcp_client", "Failed to initialize MCP client: {}", e);
-                    AppError::Io(format!("Failed to initialize MCP connection: {}", e))
-                })?
+                handler.serve(transport).await.map_err(|e| initialize_error(e, None))?
             }
-            "sse" => {
+            McpServerType::Sse => {
                 info!(target: "mcp_client", "Using SSE transport");
                 use crate::infra::sse_transport::SseWorker;
 
-                let manager_for_disconnect = self.clone_manager_ref();
-                let server_id_for_disconnect = server_id.to_string();
-                let disconnect_callback = Arc::new(move |reason: String| {
-                    let manager = manager_for_disconnect.clone_manager_ref();
-                    let server_id = server_id_for_disconnect.clone();
-                    async_runtime::spawn(async move {
-                        manager.handle_transport_disconnect(&server_id, reason).await;
-                    });
-                });
-
-                let worker = SseWorker::new(url, server_id.to_string(), Some(disconnect_callback));
-
-                client_info.serve(worker).await.map_err(|e| {
-                    error!(target: "mcp_client", "Failed to initialize MCP client: {}", e);
-                    AppError::Io(format!("Failed to initialize MCP connection: {}", e))
-                })?
+                let (raw_rpc_sender, raw_rpc_channel) = raw_rpc_channel();
+                raw_rpc = Some(raw_rpc_sender);
+
+                let worker = SseWorker::new(
+                    server.url.as_str(),
+                    create_no_proxy_client(server, &self.resolve_auth(server).await?)?,
+                    server_id.to_string(),
+                    server.sse_reconnect,
+                    raw_rpc_channel,
+                    traffic,
+                    Some(self.disconnect_callback(server_id, &connection_id)),
+                    startup_error.clone(),
+                );
+
+                handler
+                    .serve(worker)
+                    .await
+                    .map_err(|e| initialize_error(e, Some(&startup_error)))?
             }
-            _ => {
-                return Err(AppError::Domain(format!("Unsupported server type: {}", server_type)));
+            McpServerType::WebSocket => {
+                info!(target: "mcp_client", "Using WebSocket transport");
+                use crate::infra::websocket_transport::WebSocketWorker;
+
+                let (raw_rpc_sender, raw_rpc_channel) = raw_rpc_channel();
+                raw_rpc = Some(raw_rpc_sender);
+
+                let worker = WebSocketWorker::new(
+                    server.url.as_str(),
+                    request_headers(&server.headers, &self.resolve_auth(server).await?)?,
+                    server_id.to_string(),
+                    raw_rpc_channel,
+                    traffic,
+                    Some(self.disconnect_callback(server_id, &connection_id)),
+                    startup_error.clone(),
+                );
+
+                handler
+                    .serve(worker)
+                    .await
+                    .map_err(|e| initialize_error(e, Some(&startup_error)))?
+            }
+            McpServerType::Stdio => {
+                info!(target: "mcp_client", "Using stdio transport");
+                use crate::infra::stdio_transport::StdioWorker;
+
+                let (raw_rpc_sender, raw_rpc_channel) = raw_rpc_channel();
+                raw_rpc = Some(raw_rpc_sender);
+
+                let command =
+                    server.command.as_deref().map(str::trim).filter(|c| !c.is_empty()).ok_or_else(
+                        || AppError::Domain("Stdio server has no command configured".to_string()),
+                    )?;
+
+                let worker = StdioWorker::new(
+                    command,
+                    server.args.clone(),
+                    server.env.clone(),
+                    server.cwd.clone(),
+                    server_id.to_string(),
+                    raw_rpc_channel,
+                    traffic,
+                    Some(self.disconnect_callback(server_id, &connection_id)),
+                );
+
+                handler.serve(worker).await.map_err(|e| initialize_error(e, None))?
             }
         };
 
@@ -208,23 +517,71 @@ impl McpClientManager {
         // Create heartbeat cancellation token
         let heartbeat_cancel = CancellationToken::new();
 
-        // Store connection
-        {
-            let mut connections = self.connections.write().await;
-            connections.insert(
-                server_id.to_string(),
-                McpConnection { client, heartbeat_cancel: heartbeat_cancel.clone() },
-            );
+        // Store connection, unless another connect finished first
+        let duplicate = match self.connections.write().await.entry(server_id.to_string()) {
+            Entry::Occupied(_) => Some(client),
+            Entry::Vacant(entry) => {
+                entry.insert(McpConnection {
+                    client,
+                    heartbeat_cancel: heartbeat_cancel.clone(),
+                    server: server.clone(),
+                    variables: variables.clone(),
+                    session,
+                    raw_rpc,
+                    connection_id: connection_id.clone(),
+                });
+                None
+            }
+        };
+        if let Some(client) = duplicate {
+            warn!(target: "mcp_client", "Server {} was connected concurrently, closing the new connection", server_id);
+            if let Err(e) = client.cancel().await {
+                error!(target: "mcp_client", "Error closing duplicate connection: {}", e);
+            }
+            return Err(AppError::Domain("Already connected".to_string()));
         }
 
         // Start heartbeat task
-        let server_id_clone = server_id.to_string();
-        let manager_ref = Arc::new(self.clone_manager_ref());
-        tokio::spawn(async move {
-            manager_ref.run_heartbeat(&server_id_clone, heartbeat_cancel).await;
-        });
+        self.spawn_heartbeat(server, &connection_id, heartbeat_cancel).await;
 
-        Ok(())
+        Ok(connection_id)
+    }
+
+    /// Auth to send for a server; OAuth becomes a bearer token from the stored credentials
+    async fn resolve_auth(&self, server: &McpServer) -> Result<McpServerAuth, AppError> {
+        if !matches!(server.auth, McpServerAuth::OAuth { .. }) {
+            return Ok(server.auth.clone());
+        }
+
+        let oauth_manager = self.oauth_manager.read().await.clone();
+        let token = match oauth_manager {
+            Some(oauth_manager) => oauth_manager.access_token(&server.id).await?,
+            None => None,
+        };
+
+        match token {
+            Some(token) => Ok(McpServerAuth::Bearer { token }),
+            None => {
+                warn!(target: "mcp_client", "Server {} uses OAuth but has no access token", server.id);
+                Ok(McpServerAuth::None)
+            }
+        }
+    }
+
+    /// Build a transport disconnect callback that routes into `handle_transport_disconnect`
+    /// for the connection the transport belongs to
+    fn disconnect_callback(&self, server_id: &str, connection_id: &str) -> DisconnectCallback {
+        let manager_for_disconnect = self.clone_manager_ref();
+        let server_id_for_disconnect = server_id.to_string();
+        let connection_id_for_disconnect = connection_id.to_string();
+        Arc::new(move |reason: String| {
+            let manager = manager_for_disconnect.clone_manager_ref();
+            let server_id = server_id_for_disconnect.clone();
+            let connection_id = connection_id_for_disconnect.clone();
+            tokio::spawn(async move {
+                manager.handle_transport_disconnect(&server_id, &connection_id, reason).await;
+            });
+        })
     }
 
     /// Clone manager reference for heartbeat task
@@ -232,47 +589,283 @@ impl McpClientManager {
         Self {
             connections: self.connections.clone(),
             tools_cache: self.tools_cache.clone(),
+            resources_cache: self.resources_cache.clone(),
+            resource_templates_cache: self.resource_templates_cache.clone(),
+            resource_subscriptions: self.resource_subscriptions.clone(),
+            prompts_cache: self.prompts_cache.clone(),
+            roots: self.roots.clone(),
+            sampling_responder: self.sampling_responder.clone(),
+            elicitation_responder: self.elicitation_responder.clone(),
+            in_flight_calls: self.in_flight_calls.clone(),
+            log_repo: self.log_repo.clone(),
+            heartbeat_repo: self.heartbeat_repo.clone(),
+            traffic_repo: self.traffic_repo.clone(),
+            oauth_manager: self.oauth_manager.clone(),
+            reconnect_tasks: self.reconnect_tasks.clone(),
             event_publisher: self.event_publisher.clone(),
             config_repo: self.config_repo.clone(),
         }
     }
 
-    /// Run heartbeat task to monitor connection health
-    async fn run_heartbeat(&self, server_id: &str, cancel_token: CancellationToken) {
-        const DEFAULT_HEARTBEAT_INTERVAL_SECS: u64 = 10;
-        const MAX_FAILURES: u32 = 1; // Disconnect after first failure to react quickly
-
-        // Read heartbeat interval from config
-        let heartbeat_interval = {
-            let config_repo_lock = self.config_repo.read().await;
-            if let Some(config_repo) = config_repo_lock.as_ref() {
-                match config_repo.get("heartbeat_interval").await {
-                    Ok(Some(value)) => {
-                        value.parse::<u64>().unwrap_or(DEFAULT_HEARTBEAT_INTERVAL_SECS)
-                    }
-                    _ => DEFAULT_HEARTBEAT_INTERVAL_SECS,
+    /// Start the heartbeat task of a connection (no-op for the `none` strategy)
+    async fn spawn_heartbeat(
+        &self,
+        server: &McpServer,
+        connection_id: &str,
+        cancel_token: CancellationToken,
+    ) {
+        let settings = self.heartbeat_settings(server).await;
+        if settings.strategy == HeartbeatStrategy::None {
+            info!(target: "mcp_client", "Heartbeat disabled for server {}", server.id);
+            return;
+        }
+
+        let server_id = server.id.clone();
+        let connection_id = connection_id.to_string();
+        let manager_ref = Arc::new(self.clone_manager_ref());
+        tokio::spawn(async move {
+            manager_ref.run_heartbeat(&server_id, &connection_id, settings, cancel_token).await;
+        });
+    }
+
+    /// Resolve a server's heartbeat settings. The interval falls back to the global
+    /// `heartbeat_interval` setting, then to 10 seconds; the threshold falls back to 1 failure.
+    async fn heartbeat_settings(&self, server: &McpServer) -> HeartbeatSettings {
+        let interval_secs = match server.heartbeat_interval_secs {
+            Some(secs) if secs > 0 => secs as u64,
+            _ => {
+                let config_repo_lock = self.config_repo.read().await;
+                match config_repo_lock.as_ref() {
+                    Some(config_repo) => match config_repo.get("heartbeat_interval").await {
+                        Ok(Some(value)) => {
+                            value.parse::<u64>().unwrap_or(DEFAULT_HEARTBEAT_INTERVAL_SECS)
+                        }
+                        _ => DEFAULT_HEARTBEAT_INTERVAL_SECS,
+                    },
+                    None => DEFAULT_HEARTBEAT_INTERVAL_SECS,
                 }
-            } else {
-                DEFAULT_HEARTBEAT_INTERVAL_SECS
             }
         };
 
-        let mut ticker = interval(Duration::from_secs(heartbeat_interval));
+        let max_failures = match server.heartbeat_max_failures {
+            Some(n) if n > 0 => n as u32,
+            _ => DEFAULT_HEARTBEAT_MAX_FAILURES,
+        };
+
+        HeartbeatSettings {
+            strategy: server.heartbeat_strategy,
+            interval: Duration::from_secs(interval_secs.max(1)),
+            max_failures,
+        }
+    }
+
+    /// Apply changed heartbeat settings to a live connection by restarting its heartbeat task
+    ///
+    /// Only the heartbeat fields are copied: the connection keeps its resolved server, which
+    /// an automatic reconnect uses as is.
+    pub async fn update_heartbeat(&self, server: &McpServer) {
+        let (connected, connection_id, cancel_token) = {
+            let mut connections = self.connections.write().await;
+            let Some(conn) = connections.get_mut(&server.id) else {
+                return;
+            };
+            conn.heartbeat_cancel.cancel();
+            conn.heartbeat_cancel = CancellationToken::new();
+            conn.server.heartbeat_strategy = server.heartbeat_strategy;
+            conn.server.heartbeat_interval_secs = server.heartbeat_interval_secs;
+            conn.server.heartbeat_max_failures = server.heartbeat_max_failures;
+            (conn.server.clone(), conn.connection_id.clone(), conn.heartbeat_cancel.clone())
+        };
+
+        self.spawn_heartbeat(&connected, &connection_id, cancel_token).await;
+    }
+
+    /// Apply a changed reconnect policy; disabling it stops a pending reconnect
+    pub async fn update_reconnect_policy(&self, server: &McpServer) {
+        if let Some(conn) = self.connections.write().await.get_mut(&server.id) {
+            conn.server.reconnect_policy = server.reconnect_policy.clone();
+        }
+
+        if !server.reconnect_policy.enabled {
+            self.cancel_reconnect(&server.id).await;
+        }
+    }
+
+    /// Start reconnecting a lost server in the background (replaces a pending reconnect)
+    async fn schedule_reconnect(
+        &self,
+        server: McpServer,
+        variables: Variables,
+        tools: Option<Vec<McpToolInfo>>,
+    ) {
+        let cancel_token = CancellationToken::new();
+        let previous =
+            self.reconnect_tasks.write().await.insert(server.id.clone(), cancel_token.clone());
+        if let Some(previous) = previous {
+            previous.cancel();
+        }
+
+        // Spawned on the current runtime, so it runs under the app and the headless runner alike
+        let manager_ref = Arc::new(self.clone_manager_ref());
+        tokio::spawn(async move {
+            manager_ref.run_reconnect(server, variables, tools, cancel_token).await;
+        });
+    }
+
+    /// Stop a pending automatic reconnect
+    async fn cancel_reconnect(&self, server_id: &str) {
+        if let Some(cancel_token) = self.reconnect_tasks.write().await.remove(server_id) {
+            info!(target: "mcp_client", "Cancelling automatic reconnect for server {}", server_id);
+            cancel_token.cancel();
+        }
+    }
+
+    /// Reconnect with exponential backoff until connected, out of attempts or cancelled
+    ///
+    /// Boxed because connect, heartbeat, disconnect and reconnect spawn each other in a cycle.
+    fn run_reconnect(
+        &self,
+        server: McpServer,
+        variables: Variables,
+        tools: Option<Vec<McpToolInfo>>,
+        cancel_token: CancellationToken,
+    ) -> BoxFuture<'_, ()> {
+        Box::pin(async move {
+            let server_id = server.id.as_str();
+            let policy = &server.reconnect_policy;
+            let mut last_error = None;
+
+            for attempt in 1..=policy.max_attempts {
+                let delay_ms = policy.delay_ms(attempt);
+                let delay_ms = delay_ms + jitter_ms(delay_ms);
+                info!(target: "mcp_client", "Reconnecting to server {} in {}ms (attempt {}/{})",
+                    server_id, delay_ms, attempt, policy.max_attempts);
+                self.publish_reconnecting(&server, attempt, "scheduled", Some(delay_ms), None)
+                    .await;
+
+                tokio::select! {
+                    _ = tokio::time::sleep(Duration::from_millis(delay_ms)) => {}
+                    _ = cancel_token.cancelled() => {
+                        info!(target: "mcp_client", "Reconnect cancelled for server {}", server_id);
+                        return;
+                    }
+                }
+
+                match self.open_connection(&server, &variables).await {
+                    Ok(connection_id) => {
+                        // Cancelled by the user while the attempt was in flight
+                        if cancel_token.is_cancelled() {
+                            self.close_connection(server_id, &connection_id).await;
+                            return;
+                        }
+
+                        info!(target: "mcp_client", "Reconnected to server {} (attempt {})", server_id, attempt);
+                        self.restore_tools(server_id, tools).await;
+                        self.publish_reconnecting(&server, attempt, "connected", None, None).await;
+                        self.finish_reconnect(server_id, &cancel_token).await;
+                        return;
+                    }
+                    Err(e) => {
+                        warn!(target: "mcp_client", "Reconnect attempt {}/{} for server {} failed: {}",
+                            attempt, policy.max_attempts, server_id, e);
+                        let error = e.to_string();
+                        self.publish_reconnecting(&server, attempt, "failed", None, Some(&error))
+                            .await;
+                        last_error = Some(error);
+                    }
+                }
+            }
+
+            if cancel_token.is_cancelled() {
+                return;
+            }
+            error!(target: "mcp_client", "Giving up reconnecting to server {} after {} attempts", server_id, policy.max_attempts);
+            self.publish_reconnecting(
+                &server,
+                policy.max_attempts,
+                "gave_up",
+                None,
+                last_error.as_deref(),
+            )
+            .await;
+            self.finish_reconnect(server_id, &cancel_token).await;
+        })
+    }
+
+    /// Refill the tools cache after a reconnect, keeping the pre-disconnect list if tools/list fails
+    async fn restore_tools(&self, server_id: &str, tools: Option<Vec<McpToolInfo>>) {
+        match self.list_tools(server_id).await {
+            Ok(result) => {
+                info!(target: "mcp_client", "Restored {} tools for server {}", result.tools.len(), server_id);
+            }
+            Err(e) => {
+                warn!(target: "mcp_client", "Failed to reload tools after reconnect for {}: {}", server_id, e);
+                if let Some(tools) = tools {
+                    self.tools_cache.write().await.insert(server_id.to_string(), tools);
+                }
+            }
+        }
+    }
+
+    /// Drop the reconnect entry unless it was already cancelled or replaced
+    async fn finish_reconnect(&self, server_id: &str, cancel_token: &CancellationToken) {
+        if !cancel_token.is_cancelled() {
+            self.reconnect_tasks.write().await.remove(server_id);
+        }
+    }
+
+    async fn publish_reconnecting(
+        &self,
+        server: &McpServer,
+        attempt: u32,
+        status: &str,
+        delay_ms: Option<u64>,
+        error: Option<&str>,
+    ) {
+        let event_data = serde_json::json!({
+            "server_id": server.id,
+            "attempt": attempt,
+            "max_attempts": server.reconnect_policy.max_attempts,
+            "status": status,
+            "delay_ms": delay_ms,
+            "error": error,
+        });
+        self.event_publisher.publish("mcp:reconnecting", event_data).await;
+    }
+
+    /// Run heartbeat task to monitor connection health
+    async fn run_heartbeat(
+        &self,
+        server_id: &str,
+        connection_id: &str,
+        settings: HeartbeatSettings,
+        cancel_token: CancellationToken,
+    ) {
+        let mut ticker = interval(settings.interval);
+        // The first tick completes immediately; the connection was just verified by initialize
+        ticker.tick().await;
         let mut consecutive_failures = 0;
 
-        info!(target: "mcp_client", "Starting heartbeat for server {} with interval {}s", server_id, heartbeat_interval);
+        info!(target: "mcp_client", "Starting {} heartbeat for server {} every {}s (max failures {})",
+            settings.strategy, server_id, settings.interval.as_secs(), settings.max_failures);
 
         loop {
             tokio::select! {
                 _ = ticker.tick() => {
                     debug!(target: "mcp_client", "Heartbeat tick for server {}", server_id);
 
-                    // Try to ping the server
-                    let ping_result = self.ping_server(server_id).await;
+                    let start = std::time::Instant::now();
+                    let ping_result = self.ping_server(server_id, settings.strategy, settings.interval).await;
+                    let rtt_ms = start.elapsed().as_millis() as i64;
+
+                    if cancel_token.is_cancelled() {
+                        break;
+                    }
+                    self.record_heartbeat(server_id, settings.strategy, rtt_ms, &ping_result).await;
 
                     match ping_result {
                         Ok(_) => {
-                            debug!(target: "mcp_client", "Heartbeat OK for server {}", server_id);
+                            debug!(target: "mcp_client", "Heartbeat OK for server {} ({}ms)", server_id, rtt_ms);
                             consecutive_failures = 0;
                         }
                         Err(e) => {
@@ -282,11 +875,11 @@ impl McpClientManager {
                                 AppError::Io(msg) if msg.contains("Transport closed") || msg.contains("Connection reset")
                             );
                             warn!(target: "mcp_client", "Heartbeat failed for server {} ({}/{}): {}",
-                                server_id, consecutive_failures, MAX_FAILURES, e);
+                                server_id, consecutive_failures, settings.max_failures, e);
 
-                            if consecutive_failures >= MAX_FAILURES || is_transport_closed {
+                            if consecutive_failures >= settings.max_failures || is_transport_closed {
                                 error!(target: "mcp_client", "Heartbeat marked server {} as disconnected", server_id);
-                                self.handle_transport_disconnect(server_id, "heartbeat_failed".to_string()).await;
+                                self.handle_transport_disconnect(server_id, connection_id, "heartbeat_failed".to_string()).await;
                                 break;
                             }
                         }
@@ -301,41 +894,115 @@ impl McpClientManager {
         }
     }
 
-    /// Ping the server to check connection health
-    /// Uses list_tools as a health check since MCP protocol doesn't have a dedicated ping
-    async fn ping_server(&self, server_id: &str) -> Result<(), AppError> {
-        let connections = self.connections.read().await;
-        let conn = connections
-            .get(server_id)
-            .ok_or_else(|| AppError::Domain("Not connected to server".to_string()))?;
+    /// Check connection health with the given strategy; the request times out after `timeout`
+    async fn ping_server(
+        &self,
+        server_id: &str,
+        strategy: HeartbeatStrategy,
+        timeout: Duration,
+    ) -> Result<(), AppError> {
+        let peer = self.peer(server_id).await?;
 
-        // Use list_tools as a health check (lightweight operation)
-        // If the connection is dead, this will fail
-        conn.client.list_tools(Default::default()).await.map_err(|e| {
-            error!(target: "mcp_client", "Health check failed: {}", e);
-            AppError::Io(format!("Health check failed: {}", e))
-        })?;
+        let result = match strategy {
+            HeartbeatStrategy::ListTools => {
+                match tokio::time::timeout(timeout, peer.list_tools(Default::default())).await {
+                    Ok(result) => result.map(|_| ()),
+                    Err(_) => Err(ServiceError::Timeout { timeout }),
+                }
+            }
+            _ => {
+                let request = ClientRequest::PingRequest(PingRequest {
+                    method: Default::default(),
+                    extensions: Default::default(),
+                });
+                let options = PeerRequestOptions { timeout: Some(timeout), meta: None };
+                match peer.send_request_with_option(request, options).await {
+                    Ok(handle) => handle.await_response().await.map(|_| ()),
+                    Err(e) => Err(e),
+                }
+            }
+        };
 
-        Ok(())
+        result.map_err(|e| AppError::Io(format!("Health check failed: {}", e)))
+    }
+
+    /// Persist one heartbeat and publish it for live latency charts
+    async fn record_heartbeat(
+        &self,
+        server_id: &str,
+        strategy: HeartbeatStrategy,
+        rtt_ms: i64,
+        result: &Result<(), AppError>,
+    ) {
+        let error_message = result.as_ref().err().map(|e| e.to_string());
+
+        let event_data = serde_json::json!({
+            "server_id": server_id,
+            "strategy": strategy,
+            "success": result.is_ok(),
+            "rtt_ms": rtt_ms,
+            "error": error_message,
+        });
+        self.event_publisher.publish("mcp:heartbeat", event_data).await;
+
+        let repo = self.heartbeat_repo.read().await.clone();
+        if let Some(repo) = repo {
+            let heartbeat = McpHeartbeat {
+                id: uuid::Uuid::new_v4().to_string(),
+                server_id: server_id.to_string(),
+                strategy,
+                success: result.is_ok(),
+                rtt_ms,
+                error_message,
+                created_at: now_timestamp(),
+            };
+            if let Err(e) = repo.create(heartbeat).await {
+                warn!(target: "mcp_client", "Failed to save heartbeat for {}: {}", server_id, e);
+            }
+        }
+    }
+
+    /// Close one particular connection of a server, leaving any newer connection alone
+    async fn close_connection(&self, server_id: &str, connection_id: &str) {
+        let connection = {
+            let mut connections = self.connections.write().await;
+            match connections.get(server_id) {
+                Some(conn) if conn.connection_id == connection_id => connections.remove(server_id),
+                _ => None,
+            }
+        };
+        let Some(conn) = connection else {
+            return;
+        };
+
+        info!(target: "mcp_client", "Closing stale connection {} of server {}", connection_id, server_id);
+        close(server_id, conn).await;
+        self.clear_runtime_cache(server_id).await;
     }
 
     /// Disconnect from an MCP server
     pub async fn disconnect(&self, server_id: &str) {
         info!(target: "mcp_client", "Disconnecting from MCP server {}", server_id);
 
-        let mut connections = self.connections.write().await;
-        if let Some(conn) = connections.remove(server_id) {
-            // Cancel heartbeat task
-            conn.heartbeat_cancel.cancel();
+        self.cancel_reconnect(server_id).await;
 
-            if let Err(e) = conn.client.cancel().await {
-                error!(target: "mcp_client", "Error disconnecting: {}", e);
-            }
+        // Closed after the lock is released: other servers aren't held up by the session DELETE
+        let connection = self.connections.write().await.remove(server_id);
+        if let Some(conn) = connection {
+            close(server_id, conn).await;
         }
 
-        // Clear tools cache for this server
-        let mut tools_cache = self.tools_cache.write().await;
-        tools_cache.remove(server_id);
+        self.clear_runtime_cache(server_id).await;
+    }
+
+    /// Drop everything cached in memory for a server (tools, resources, subscriptions, prompts, roots)
+    async fn clear_runtime_cache(&self, server_id: &str) {
+        self.prompts_cache.write().await.remove(server_id);
+        self.roots.write().await.remove(server_id);
+        self.tools_cache.write().await.remove(server_id);
+        self.resources_cache.write().await.remove(server_id);
+        self.resource_templates_cache.write().await.remove(server_id);
+        self.resource_subscriptions.write().await.remove(server_id);
     }
 
     /// Check if connected to a server
@@ -351,74 +1018,64 @@ impl McpClientManager {
     ) -> Result<McpToolsListResultInternal, AppError> {
         info!(target: "mcp_client", "Listing tools for server {}", server_id);
 
-        let connections = self.connections.read().await;
-        let conn = connections
-            .get(server_id)
-            .ok_or_else(|| AppError::Domain("Not connected to server".to_string()))?;
+        let peer = self.peer(server_id).await?;
+        let result = fetch_tools(&peer).await?;
 
-        // Call tools/list
-        let tools_result = conn.client.list_tools(Default::default()).await.map_err(|e| {
-            error!(target: "mcp_client", "Failed to list tools: {}", e);
-            AppError::Io(format!("Failed to list tools: {}", e))
-        })?;
+        info!(target: "mcp_client", "Found {} tools", result.tools.len());
 
-        // Serialize the raw response for debugging
-        let raw_response = serde_json::to_string_pretty(&tools_result)
-            .unwrap_or_else(|_| format!("{:?}", tools_result));
+        // Cache tools in memory
+        {
+            let mut tools_cache = self.tools_cache.write().await;
+            tools_cache.insert(server_id.to_string(), result.tools.clone());
+        }
 
-        debug!(target: "mcp_client", "Raw tools/list response:\n{}", raw_response);
+        Ok(result)
+    }
 
-        // Convert to our tool format
-        let tools: Vec<McpToolInfo> = tools_result
-            .tools
-            .into_iter()
-            .map(|t| {
-                // input_schema is Arc<serde_json::Map<String, Value>>, serialize it directly
-                let input_schema =
-                    serde_json::to_string_pretty(&*t.input_schema).ok().filter(|s| s != "{}");
-
-                // output_schema is also Arc<serde_json::Map<String, Value>>, serialize it
-                let output_schema = t.output_schema.as_ref().and_then(|schema| {
-                    serde_json::to_string_pretty(&**schema).ok().filter(|s| s != "{}")
-                });
+    /// Re-list tools after a `notifications/tools/list_changed` and publish the name diff
+    pub(crate) async fn handle_tools_list_changed(
+        &self,
+        server_id: &str,
+        peer: &Peer<RoleClient>,
+    ) -> Result<(), AppError> {
+        let result = fetch_tools(peer).await?;
 
-                // Convert tool to Value to capture extra fields like annotations and _meta
-                let mut tool_value = serde_json::to_value(&t).unwrap_or(serde_json::Value::Null);
+        let previous = {
+            let mut tools_cache = self.tools_cache.write().await;
+            tools_cache.insert(server_id.to_string(), result.tools.clone())
+        };
 
-                // Remove known fields
-                if let Some(obj) = tool_value.as_object_mut() {
-                    obj.remove("name");
-                    obj.remove("description");
-                    obj.remove("inputSchema");
-                    obj.remove("outputSchema");
-                }
+        let before: Vec<String> =
+            previous.unwrap_or_default().into_iter().map(|t| t.name).collect();
+        let after: Vec<String> = result.tools.iter().map(|t| t.name.clone()).collect();
 
-                let extra = if tool_value.is_object() && !tool_value.as_object().unwrap().is_empty()
-                {
-                    serde_json::to_string(&tool_value).ok()
-                } else {
-                    None
-                };
+        let before_set: HashSet<&String> = before.iter().collect();
+        let after_set: HashSet<&String> = after.iter().collect();
+        let added: Vec<&String> = after.iter().filter(|n| !before_set.contains(n)).collect();
+        let removed: Vec<&String> = before.iter().filter(|n| !after_set.contains(n)).collect();
 
-                McpToolInfo {
-                    name: t.name.to_string(),
-                    description: t.description.as_ref().map(|d| d.to_string()),
-                    input_schema,
-                    output_schema,
-                    extra,
-                }
-            })
-            .collect();
+        info!(target: "mcp_client", "Tools changed for server {}: +{} -{}", server_id, added.len(), removed.len());
 
-        info!(target: "mcp_client", "Found {} tools", tools.len());
+        let event_data = serde_json::json!({
+            "server_id": server_id,
+            "tools_count": after.len(),
+            "before": before,
+            "after": after,
+            "added": added,
+            "removed": removed,
+        });
+        self.event_publisher.publish("mcp:tools_changed", event_data).await;
 
-        // Cache tools in memory
-        {
-            let mut tools_cache = self.tools_cache.write().await;
-            tools_cache.insert(server_id.to_string(), tools.clone());
-        }
+        Ok(())
+    }
 
-        Ok(McpToolsListResultInternal { tools, raw_response })
+    /// Get a handle to the peer of a connected server without holding the connections lock
+    async fn peer(&self, server_id: &str) -> Result<Peer<RoleClient>, AppError> {
+        let connections = self.connections.read().await;
+        connections
+            .get(server_id)
+            .map(|conn| conn.client.peer().clone())
+            .ok_or_else(|| AppError::Domain("Not connected to server".to_string()))
     }
 
     /// Get cached tools for a server (from memory, not database)
@@ -427,41 +1084,29 @@ impl McpClientManager {
         tools_cache.get(server_id).cloned()
     }
 
-    /// Call a tool on an MCP server (returns raw JSON response)
+    /// Call a tool on an MCP server (returns raw JSON response).
+    ///
+    /// The call is registered under `call_id` until it completes so it can be cancelled with
+    /// `cancel_tool_call`; progress notifications for its progress token are published as
+    /// `mcp:tool_call_progress`. Cancelling or timing out sends `notifications/cancelled`.
     pub async fn call_tool(
         &self,
         server_id: &str,
+        call_id: &str,
         tool_name: &str,
         params: Option<serde_json::Value>,
+        timeout: Duration,
     ) -> Result<McpToolCallResult, AppError> {
-        info!(target: "mcp_client", "Calling tool {} on server {}", tool_name, server_id);
-
-        info!(target: "mcp_client", "Acquiring read lock on connections...");
-        let connections = self.connections.read().await;
-        info!(target: "mcp_client", "Read lock acquired, getting connection for server {}", server_id);
-
-        let conn = connections.get(server_id).ok_or_else(|| {
-            error!(target: "mcp_client", "Server {} not found in connections map", server_id);
-            AppError::Domain("Not connected to server".to_string())
-        })?;
+        info!(target: "mcp_client", "Calling tool {} on server {} (call {})", tool_name, server_id, call_id);
 
-        info!(target: "mcp_client", "Connection found, preparing tool call");
+        let peer = self.peer(server_id).await?;
 
         // Prepare arguments
         let arguments = params.and_then(|p| p.as_object().cloned());
 
-        debug!(target: "mcp_client", "Tool call arguments: {:?}", arguments);
-
-        // Log as JSON to see exact structure
-        if let Some(ref args) = arguments {
-            match serde_json::to_string_pretty(args) {
-                Ok(json) => info!(target: "mcp_client", "Arguments as JSON:\n{}", json),
-                Err(e) => warn!(target: "mcp_client", "Failed to serialize arguments: {}", e),
-            }
-        }
-
-        // Clone tool_name to own it
-        let tool_name_owned = tool_name.to_string();
+        // Names only: the values are resolved and may hold secrets
+        debug!(target: "mcp_client", "Tool call arguments: {:?}",
+            arguments.as_ref().map(|args| args.keys().collect::<Vec<_>>()));
 
         // TODO: rmcp 0.11 does not support _meta in CallToolRequestParam.
         // We should upgrade to a newer version or wait for support to inject clientId/clientName.
@@ -475,18 +1120,84 @@ impl McpClientManager {
         info!(target: "mcp_client", "Sending tool call request to server...");
         let start = std::time::Instant::now();
 
-        // Add timeout to debug
-        let result = tokio::time::timeout(
-            std::time::Duration::from_secs(30),
-            conn.client.call_tool(CallToolRequestParam { name: tool_name_owned.into(), arguments }),
-        )
-        .await;
-
-        match result {
-            Ok(Ok(tool_result)) => {
-                let duration_ms = start.elapsed().as_millis() as i64;
-                info!(target: "mcp_client", "Tool call completed in {}ms", duration_ms);
-                info!(target: "mcp_client", "Tool call succeeded, processing response...");
+        let cancel = CancellationToken::new();
+        // Our own progress token, so the call is registered before sending and progress that
+        // arrives early finds it (no lock is held while the request is on the wire)
+        let progress_token =
+            ProgressToken(NumberOrString::String(uuid::Uuid::new_v4().to_string().into()));
+        {
+            let mut in_flight_calls = self.in_flight_calls.write().await;
+            if in_flight_calls.contains_key(call_id) {
+                return Err(AppError::Domain(format!("Tool call {} is already running", call_id)));
+            }
+            in_flight_calls.insert(
+                call_id.to_string(),
+                InFlightCall {
+                    server_id: server_id.to_string(),
+                    progress_token: progress_token.0.to_string(),
+                    cancel: cancel.clone(),
+                },
+            );
+        }
+
+        let request = ClientRequest::CallToolRequest(Request::new(CallToolRequestParam {
+            name: tool_name.to_string().into(),
+            arguments,
+        }));
+        let mut meta = Meta::new();
+        meta.set_progress_token(progress_token);
+        let options = PeerRequestOptions { timeout: None, meta: Some(meta) };
+        let handle = match peer.send_cancellable_request(request, options).await {
+            Ok(handle) => handle,
+            Err(e) => {
+                self.in_flight_calls.write().await.remove(call_id);
+                error!(target: "mcp_client", "Failed to send tools/call: {}", e);
+                return Err(AppError::Io(format!("Failed to send tool call: {}", e)));
+            }
+        };
+
+        let outcome = tokio::select! {
+            response = handle.rx => Ok(response.unwrap_or(Err(ServiceError::TransportClosed))),
+            _ = cancel.cancelled() => Err("cancelled"),
+            _ = tokio::time::sleep(timeout) => Err("timeout"),
+        };
+
+        self.in_flight_calls.write().await.remove(call_id);
+        let duration_ms = start.elapsed().as_millis() as i64;
+
+        let response = match outcome {
+            Ok(response) => response,
+            Err(status) => {
+                let reason = if status == "timeout" {
+                    format!("Request timed out after {}ms", timeout.as_millis())
+                } else {
+                    "Cancelled by user".to_string()
+                };
+                warn!(target: "mcp_client", "Tool call {} {}: {}", call_id, status, reason);
+
+                let notification = CancelledNotificationParam {
+                    request_id: handle.id,
+                    reason: Some(reason.clone()),
+                };
+                if let Err(e) = peer.notify_cancelled(notification).await {
+                    warn!(target: "mcp_client", "Failed to send notifications/cancelled: {}", e);
+                }
+
+                return Ok(McpToolCallResult {
+                    call_id: call_id.to_string(),
+                    status: status.to_string(),
+                    success: false,
+                    raw_response: serde_json::json!({ "error": reason }).to_string(),
+                    result: None,
+                    error: Some(reason),
+                    duration_ms,
+                });
+            }
+        };
+
+        match response {
+            Ok(ServerResult::CallToolResult(tool_result)) => {
+                info!(target: "mcp_client", "Tool call completed in {}ms", duration_ms);
 
                 // Serialize the raw response for debugging
                 let raw_response = serde_json::to_string_pretty(&tool_result)
@@ -498,9 +1209,9 @@ impl McpClientManager {
                 let result_value =
                     serde_json::to_value(&tool_result.content).unwrap_or(serde_json::Value::Null);
 
-                info!(target: "mcp_client", "Tool call result prepared, returning to frontend");
-
                 Ok(McpToolCallResult {
+                    call_id: call_id.to_string(),
+                    status: "success".to_string(),
                     success: !tool_result.is_error.unwrap_or(false),
                     raw_response,
                     result: Some(result_value),
@@ -508,29 +1219,486 @@ impl McpClientManager {
                     duration_ms,
                 })
             }
-            Ok(Err(e)) => {
-                let duration_ms = start.elapsed().as_millis() as i64;
+            Ok(_) => Err(AppError::Io("Unexpected response to tools/call".to_string())),
+            Err(e) => {
                 error!(target: "mcp_client", "Tool call failed: {}", e);
                 Ok(McpToolCallResult {
+                    call_id: call_id.to_string(),
+                    status: "error".to_string(),
                     success: false,
-                    raw_response: format!("{{\"error\": \"{}\"}}", e),
+                    raw_response: serde_json::json!({ "error": e.to_string() }).to_string(),
                     result: None,
                     error: Some(e.to_string()),
                     duration_ms,
                 })
             }
-            Err(_timeout) => {
-                let duration_ms = start.elapsed().as_millis() as i64;
-                error!(target: "mcp_client", "Tool call timed out after {}ms", duration_ms);
-                Ok(McpToolCallResult {
+        }
+    }
+
+    /// Cancel an in-flight tool call; `call_tool` sends `notifications/cancelled` and returns
+    pub async fn cancel_tool_call(&self, call_id: &str) -> Result<(), AppError> {
+        let in_flight_calls = self.in_flight_calls.read().await;
+        let call = in_flight_calls
+            .get(call_id)
+            .ok_or_else(|| AppError::NotFound(format!("Tool call {} is not in flight", call_id)))?;
+
+        info!(target: "mcp_client", "Cancelling tool call {} on server {}", call_id, call.server_id);
+        call.cancel.cancel();
+        Ok(())
+    }
+
+    /// Forward `notifications/progress` for an in-flight tool call as `mcp:tool_call_progress`
+    pub(crate) async fn handle_progress(&self, server_id: &str, params: ProgressNotificationParam) {
+        let progress_token = params.progress_token.0.to_string();
+        let call_id = {
+            let in_flight_calls = self.in_flight_calls.read().await;
+            in_flight_calls
+                .iter()
+                .find(|(_, call)| {
+                    call.server_id == server_id && call.progress_token == progress_token
+                })
+                .map(|(call_id, _)| call_id.clone())
+        };
+
+        let Some(call_id) = call_id else {
+            debug!(target: "mcp_client", "Ignoring progress for unknown token {} on {}", progress_token, server_id);
+            return;
+        };
+
+        let event_data = serde_json::json!({
+            "call_id": call_id,
+            "server_id": server_id,
+            "progress_token": progress_token,
+            "progress": params.progress,
+            "total": params.total,
+            "message": params.message,
+        });
+        self.event_publisher.publish("mcp:tool_call_progress", event_data).await;
+    }
+
+    /// List resources from an MCP server (returns raw JSON response)
+    pub async fn list_resources(
+        &self,
+        server_id: &str,
+    ) -> Result<McpResourcesListResultInternal, AppError> {
+        info!(target: "mcp_client", "Listing resources for server {}", server_id);
+
+        let peer = self.peer(server_id).await?;
+        let result = fetch_resources(&peer).await?;
+
+        info!(target: "mcp_client", "Found {} resources", result.resources.len());
+
+        {
+            let mut resources_cache = self.resources_cache.write().await;
+            resources_cache.insert(server_id.to_string(), result.resources.clone());
+        }
+
+        Ok(result)
+    }
+
+    /// List resource templates from an MCP server (returns raw JSON response)
+    pub async fn list_resource_templates(
+        &self,
+        server_id: &str,
+    ) -> Result<McpResourceTemplatesListResultInternal, AppError> {
+        info!(target: "mcp_client", "Listing resource templates for server {}", server_id);
+
+        let peer = self.peer(server_id).await?;
+        let (templates, raw_response, paging) = fetch_all_pages("resource templates", |params| {
+            let peer = peer.clone();
+            async move {
+                let result = peer.list_resource_templates(params).await?;
+                let raw = serde_json::to_string_pretty(&result)
+                    .unwrap_or_else(|_| format!("{:?}", result));
+                Ok((result.resource_templates, result.next_cursor, raw))
+            }
+        })
+        .await?;
+
+        let resource_templates: Vec<McpResourceTemplateInfo> = templates
+            .into_iter()
+            .map(|t| McpResourceTemplateInfo {
+                extra: extra_fields(
+                    &t,
+                    &["uriTemplate", "name", "title", "description", "mimeType"],
+                ),
+                uri_template: t.raw.uri_template,
+                name: t.raw.name,
+                title: t.raw.title,
+                description: t.raw.description,
+                mime_type: t.raw.mime_type,
+            })
+            .collect();
+
+        {
+            let mut templates_cache = self.resource_templates_cache.write().await;
+            templates_cache.insert(server_id.to_string(), resource_templates.clone());
+        }
+
+        Ok(McpResourceTemplatesListResultInternal { resource_templates, raw_response, paging })
+    }
+
+    /// Get cached resources for a server (from memory, not database)
+    pub async fn get_cached_resources(&self, server_id: &str) -> Option<Vec<McpResourceInfo>> {
+        let resources_cache = self.resources_cache.read().await;
+        resources_cache.get(server_id).cloned()
+    }
+
+    /// Get cached resource templates for a server (from memory, not database)
+    pub async fn get_cached_resource_templates(
+        &self,
+        server_id: &str,
+    ) -> Option<Vec<McpResourceTemplateInfo>> {
+        let templates_cache = self.resource_templates_cache.read().await;
+        templates_cache.get(server_id).cloned()
+    }
+
+    /// Read a resource (text and blob contents are returned as raw JSON)
+    pub async fn read_resource(
+        &self,
+        server_id: &str,
+        uri: &str,
+    ) -> Result<McpResourceReadResult, AppError> {
+        info!(target: "mcp_client", "Reading resource {} on server {}", uri, server_id);
+
+        let peer = self.peer(server_id).await?;
+        let start = std::time::Instant::now();
+        let result = peer.read_resource(ReadResourceRequestParam { uri: uri.to_string() }).await;
+        let duration_ms = start.elapsed().as_millis() as i64;
+
+        match result {
+            Ok(read_result) => {
+                let raw_response = serde_json::to_string_pretty(&read_result)
+                    .unwrap_or_else(|_| format!("{:?}", read_result));
+
+                debug!(target: "mcp_client", "Raw resources/read response:\n{}", raw_response);
+
+                let contents =
+                    serde_json::to_value(&read_result.contents).unwrap_or(serde_json::Value::Null);
+
+                Ok(McpResourceReadResult {
+                    success: true,
+                    raw_response,
+                    contents: Some(contents),
+                    error: None,
+                    duration_ms,
+                })
+            }
+            Err(e) => {
+                error!(target: "mcp_client", "Resource read failed: {}", e);
+                Ok(McpResourceReadResult {
                     success: false,
-                    raw_response: r#"{"error": "Request timed out"}"#.to_string(),
-                    result: None,
-                    error: Some("Request timed out after 30 seconds".to_string()),
+                    raw_response: serde_json::json!({ "error": e.to_string() }).to_string(),
+                    contents: None,
+                    error: Some(e.to_string()),
+                    duration_ms,
+                })
+            }
+        }
+    }
+
+    /// Subscribe to `notifications/resources/updated` for a resource
+    pub async fn subscribe_resource(&self, server_id: &str, uri: &str) -> Result<(), AppError> {
+        info!(target: "mcp_client", "Subscribing to resource {} on server {}", uri, server_id);
+
+        let peer = self.peer(server_id).await?;
+        peer.subscribe(SubscribeRequestParam { uri: uri.to_string() }).await.map_err(|e| {
+            error!(target: "mcp_client", "Failed to subscribe to resource: {}", e);
+            AppError::Io(format!("Failed to subscribe to resource: {}", e))
+        })?;
+
+        let mut subscriptions = self.resource_subscriptions.write().await;
+        subscriptions.entry(server_id.to_string()).or_default().insert(uri.to_string());
+
+        Ok(())
+    }
+
+    /// Unsubscribe from updates for a resource
+    pub async fn unsubscribe_resource(&self, server_id: &str, uri: &str) -> Result<(), AppError> {
+        info!(target: "mcp_client", "Unsubscribing from resource {} on server {}", uri, server_id);
+
+        let peer = self.peer(server_id).await?;
+        peer.unsubscribe(UnsubscribeRequestParam { uri: uri.to_string() }).await.map_err(|e| {
+            error!(target: "mcp_client", "Failed to unsubscribe from resource: {}", e);
+            AppError::Io(format!("Failed to unsubscribe from resource: {}", e))
+        })?;
+
+        let mut subscriptions = self.resource_subscriptions.write().await;
+        if let Some(uris) = subscriptions.get_mut(server_id) {
+            uris.remove(uri);
+        }
+
+        Ok(())
+    }
+
+    /// Resource URIs currently subscribed to on a server
+    pub async fn get_resource_subscriptions(&self, server_id: &str) -> Vec<String> {
+        let subscriptions = self.resource_subscriptions.read().await;
+        let mut uris: Vec<String> =
+            subscriptions.get(server_id).map(|u| u.iter().cloned().collect()).unwrap_or_default();
+        uris.sort();
+        uris
+    }
+
+    /// Forward `notifications/resources/updated` for subscribed resources
+    pub(crate) async fn handle_resource_updated(&self, server_id: &str, uri: &str) {
+        let subscribed = {
+            let subscriptions = self.resource_subscriptions.read().await;
+            subscriptions.get(server_id).is_some_and(|uris| uris.contains(uri))
+        };
+
+        if !subscribed {
+            debug!(target: "mcp_client", "Ignoring update for unsubscribed resource {} on {}", uri, server_id);
+            return;
+        }
+
+        let event_data = serde_json::json!({
+            "server_id": server_id,
+            "uri": uri,
+        });
+        self.event_publisher.publish("mcp:resource_updated", event_data).await;
+    }
+
+    /// Re-list resources after a `notifications/resources/list_changed`
+    pub(crate) async fn handle_resources_list_changed(
+        &self,
+        server_id: &str,
+        peer: &Peer<RoleClient>,
+    ) -> Result<(), AppError> {
+        let result = fetch_resources(peer).await?;
+        let resources_count = result.resources.len();
+
+        {
+            let mut resources_cache = self.resources_cache.write().await;
+            resources_cache.insert(server_id.to_string(), result.resources);
+        }
+
+        let event_data = serde_json::json!({
+            "server_id": server_id,
+            "resources_count": resources_count,
+        });
+        self.event_publisher.publish("mcp:resources_changed", event_data).await;
+
+        Ok(())
+    }
+
+    /// List prompts from an MCP server (returns raw JSON response)
+    pub async fn list_prompts(
+        &self,
+        server_id: &str,
+    ) -> Result<McpPromptsListResultInternal, AppError> {
+        info!(target: "mcp_client", "Listing prompts for server {}", server_id);
+
+        let peer = self.peer(server_id).await?;
+        let result = fetch_prompts(&peer).await?;
+
+        info!(target: "mcp_client", "Found {} prompts", result.prompts.len());
+
+        {
+            let mut prompts_cache = self.prompts_cache.write().await;
+            prompts_cache.insert(server_id.to_string(), result.prompts.clone());
+        }
+
+        Ok(result)
+    }
+
+    /// Get cached prompts for a server (from memory, not database)
+    pub async fn get_cached_prompts(&self, server_id: &str) -> Option<Vec<McpPromptInfo>> {
+        let prompts_cache = self.prompts_cache.read().await;
+        prompts_cache.get(server_id).cloned()
+    }
+
+    /// Render a prompt via prompts/get (messages are returned as raw JSON)
+    pub async fn get_prompt(
+        &self,
+        server_id: &str,
+        prompt_name: &str,
+        arguments: Option<serde_json::Value>,
+    ) -> Result<McpPromptGetResult, AppError> {
+        info!(target: "mcp_client", "Getting prompt {} on server {}", prompt_name, server_id);
+
+        let peer = self.peer(server_id).await?;
+
+        // Prompt arguments are string-valued in the spec; non-string values are passed through
+        // untouched so servers with lax validation can still be exercised
+        let arguments = match arguments {
+            None | Some(serde_json::Value::Null) => None,
+            Some(serde_json::Value::Object(map)) => Some(map),
+            Some(other) => {
+                return Err(AppError::Domain(format!(
+                    "Prompt arguments must be a JSON object, got: {}",
+                    other
+                )))
+            }
+        };
+
+        debug!(target: "mcp_client", "Prompt arguments: {:?}", arguments);
+
+        let start = std::time::Instant::now();
+        let result = peer
+            .get_prompt(GetPromptRequestParam { name: prompt_name.to_string(), arguments })
+            .await;
+        let duration_ms = start.elapsed().as_millis() as i64;
+
+        match result {
+            Ok(prompt_result) => {
+                info!(target: "mcp_client", "prompts/get completed in {}ms", duration_ms);
+
+                let raw_response = serde_json::to_string_pretty(&prompt_result)
+                    .unwrap_or_else(|_| format!("{:?}", prompt_result));
+
+                debug!(target: "mcp_client", "Raw prompts/get response:\n{}", raw_response);
+
+                let messages = serde_json::to_value(&prompt_result.messages)
+                    .unwrap_or(serde_json::Value::Null);
+
+                Ok(McpPromptGetResult {
+                    success: true,
+                    raw_response,
+                    description: prompt_result.description,
+                    messages: Some(messages),
+                    error: None,
                     duration_ms,
                 })
             }
+            Err(e) => {
+                error!(target: "mcp_client", "prompts/get failed: {}", e);
+                Ok(McpPromptGetResult {
+                    success: false,
+                    raw_response: serde_json::json!({ "error": e.to_string() }).to_string(),
+                    description: None,
+                    messages: None,
+                    error: Some(e.to_string()),
+                    duration_ms,
+                })
+            }
+        }
+    }
+
+    /// Re-list prompts after a `notifications/prompts/list_changed`
+    pub(crate) async fn handle_prompts_list_changed(
+        &self,
+        server_id: &str,
+        peer: &Peer<RoleClient>,
+    ) -> Result<(), AppError> {
+        let result = fetch_prompts(peer).await?;
+        let prompts_count = result.prompts.len();
+
+        {
+            let mut prompts_cache = self.prompts_cache.write().await;
+            prompts_cache.insert(server_id.to_string(), result.prompts);
+        }
+
+        let event_data = serde_json::json!({
+            "server_id": server_id,
+            "prompts_count": prompts_count,
+        });
+        self.event_publisher.publish("mcp:prompts_changed", event_data).await;
+
+        Ok(())
+    }
+
+    /// Route a `sampling/createMessage` request to the sampling responder
+    pub(crate) async fn handle_create_message(
+        &self,
+        server_id: &str,
+        params: CreateMessageRequestParam,
+        ct: CancellationToken,
+    ) -> Result<CreateMessageResult, McpError> {
+        let responder = self.sampling_responder.read().await.clone();
+
+        match responder {
+            Some(responder) => responder.handle_create_message(server_id, params, ct).await,
+            None => Err(McpError::internal_error("Sampling responder is not ready", None)),
+        }
+    }
+
+    /// Route an `elicitation/create` request to the elicitation responder
+    pub(crate) async fn handle_create_elicitation(
+        &self,
+        server_id: &str,
+        params: CreateElicitationRequestParam,
+        ct: CancellationToken,
+    ) -> CreateElicitationResult {
+        let responder = self.elicitation_responder.read().await.clone();
+
+        match responder {
+            Some(responder) => responder.handle_create_elicitation(server_id, params, ct).await,
+            None => {
+                warn!(target: "mcp_client", "Elicitation responder is not ready, declining request");
+                CreateElicitationResult { action: ElicitationAction::Decline, content: None }
+            }
+        }
+    }
+
+    /// Answer roots/list for a server from its configured roots
+    pub(crate) async fn handle_list_roots(&self, server_id: &str) -> ListRootsResult {
+        let roots = self.roots.read().await;
+        let roots = roots
+            .get(server_id)
+            .map(|roots| {
+                roots.iter().map(|r| Root { uri: r.uri.clone(), name: r.name.clone() }).collect()
+            })
+            .unwrap_or_default();
+
+        ListRootsResult { roots }
+    }
+
+    /// Replace the roots of a connected server and send `notifications/roots/list_changed`.
+    /// Disconnected servers pick up their stored roots on the next connect.
+    pub async fn update_roots(&self, server_id: &str, roots: Vec<McpRoot>) {
+        let Ok(peer) = self.peer(server_id).await else {
+            return;
+        };
+
+        {
+            let mut all_roots = self.roots.write().await;
+            if all_roots.get(server_id) == Some(&roots) {
+                return;
+            }
+            all_roots.insert(server_id.to_string(), roots);
+        }
+
+        info!(target: "mcp_client", "Roots changed for server {}, notifying", server_id);
+        if let Err(e) = peer.notify_roots_list_changed().await {
+            warn!(target: "mcp_client", "Failed to send roots/list_changed to {}: {}", server_id, e);
+        }
+    }
+
+    /// Send `logging/setLevel` to a connected server
+    pub async fn set_log_level(&self, server_id: &str, level: McpLogLevel) -> Result<(), AppError> {
+        let peer = self.peer(server_id).await?;
+
+        peer.set_level(SetLevelRequestParam { level: to_logging_level(level) }).await.map_err(|e| {
+            error!(target: "mcp_client", "logging/setLevel failed for {}: {}", server_id, e);
+            AppError::Io(format!("Failed to set log level: {}", e))
+        })
+    }
+
+    /// Persist a `notifications/message` log record and publish it as `mcp:server_log`
+    pub(crate) async fn handle_logging_message(
+        &self,
+        server_id: &str,
+        params: LoggingMessageNotificationParam,
+    ) {
+        let log = McpServerLog {
+            id: uuid::Uuid::new_v4().to_string(),
+            server_id: server_id.to_string(),
+            level: from_logging_level(params.level),
+            logger: params.logger,
+            data: params.data.to_string(),
+            created_at: now_timestamp(),
+        };
+
+        let log_repo = self.log_repo.read().await.clone();
+        if let Some(repo) = log_repo {
+            if let Err(e) = repo.create(log.clone()).await {
+                warn!(target: "mcp_client", "Failed to save log record from {}: {}", server_id, e);
+            }
         }
+
+        let event_data = serde_json::to_value(&log).unwrap_or(serde_json::Value::Null);
+        self.event_publisher.publish("mcp:server_log", event_data).await;
     }
 
     /// Get server info for a connected server
@@ -547,44 +1715,378 @@ impl McpClientManager {
         Ok(raw_response)
     }
 
+    /// Live details of a connected server (initialize result and streamable HTTP session)
+    pub async fn connection_info(&self, server_id: &str) -> Result<McpConnectionInfo, AppError> {
+        let connections = self.connections.read().await;
+        let conn = connections
+            .get(server_id)
+            .ok_or_else(|| AppError::Domain("Not connected to server".to_string()))?;
+
+        Ok(McpConnectionInfo {
+            server_id: server_id.to_string(),
+            server_type: conn.server.server_type.clone(),
+            server_info: serde_json::to_value(conn.client.peer_info())
+                .unwrap_or(serde_json::Value::Null),
+            session: conn.session.as_ref().map(SessionHttpClient::session),
+            connection_id: conn.connection_id.clone(),
+        })
+    }
+
+    /// Send a hand-written JSON-RPC message over the connection and wait for its reply
+    pub async fn send_raw_rpc(
+        &self,
+        server_id: &str,
+        message: &str,
+        timeout: Duration,
+    ) -> Result<RawRpcReply, AppError> {
+        let (raw_rpc, session, url) = {
+            let connections = self.connections.read().await;
+            let conn = connections
+                .get(server_id)
+                .ok_or_else(|| AppError::Domain("Not connected to server".to_string()))?;
+            (conn.raw_rpc.clone(), conn.session.clone(), conn.server.url.clone())
+        };
+
+        match (raw_rpc, session) {
+            (Some(raw_rpc), _) => raw_rpc.send(message, timeout).await,
+            (None, Some(session)) => session.send_raw(&url, message, timeout).await,
+            (None, None) => {
+                Err(AppError::Domain("Connection does not support raw messages".to_string()))
+            }
+        }
+    }
+
+    /// End the streamable HTTP session with `DELETE`, then close the connection
+    pub async fn terminate_session(
+        &self,
+        server_id: &str,
+    ) -> Result<McpSessionTermination, AppError> {
+        let (session, url) = {
+            let connections = self.connections.read().await;
+            let conn = connections
+                .get(server_id)
+                .ok_or_else(|| AppError::Domain("Not connected to server".to_string()))?;
+            let session = conn.session.clone().ok_or_else(|| {
+                AppError::Domain("Only streamable HTTP connections have a session".to_string())
+            })?;
+            (session, conn.server.url.clone())
+        };
+
+        let (session_id, status) = session.terminate(&url).await?;
+        info!(target: "mcp_client", "Session {} of server {} terminated (HTTP {})", session_id, server_id, status);
+
+        self.disconnect(server_id).await;
+
+        Ok(McpSessionTermination { server_id: server_id.to_string(), session_id, status })
+    }
+
     /// Disconnect all servers
     pub async fn disconnect_all(&self) {
         info!(target: "mcp_client", "Disconnecting all MCP servers");
-        let mut connections = self.connections.write().await;
-        for (id, conn) in connections.drain() {
+        for (_, cancel_token) in self.reconnect_tasks.write().await.drain() {
+            cancel_token.cancel();
+        }
+
+        let connections: Vec<_> = self.connections.write().await.drain().collect();
+        for (id, conn) in connections {
             debug!(target: "mcp_client", "Disconnecting server {}", id);
-            conn.heartbeat_cancel.cancel();
-            let _ = conn.client.cancel().await;
+            close(&id, conn).await;
         }
     }
 
-    async fn handle_transport_disconnect(&self, server_id: &str, reason: String) {
+    /// Handle the loss of one connection; a late report about a connection that was already
+    /// replaced (manual or automatic reconnect) leaves the newer connection alone
+    async fn handle_transport_disconnect(
+        &self,
+        server_id: &str,
+        connection_id: &str,
+        reason: String,
+    ) {
         info!(target: "mcp_client", "Handling transport disconnect for server {} (reason: {})", server_id, reason);
 
         let connection = {
             let mut connections = self.connections.write().await;
-            connections.remove(server_id)
+            match connections.get(server_id) {
+                Some(conn) if conn.connection_id != connection_id => {
+                    debug!(target: "mcp_client", "Ignoring disconnect of replaced connection {} of server {}", connection_id, server_id);
+                    return;
+                }
+                _ => connections.remove(server_id),
+            }
         };
 
+        // Server config (with its live roots), environment and tools, kept for an automatic reconnect
+        let mut lost = None;
         if let Some(conn) = connection {
             conn.heartbeat_cancel.cancel();
+            let mut server = conn.server.clone();
             if let Err(e) = conn.client.cancel().await {
                 error!(target: "mcp_client", "Failed to cancel client after disconnect: {}", e);
             }
 
-            let mut tools_cache = self.tools_cache.write().await;
-            tools_cache.remove(server_id);
+            if let Some(roots) = self.roots.read().await.get(server_id) {
+                server.roots = roots.clone();
+            }
+            let tools = self.tools_cache.read().await.get(server_id).cloned();
+            self.clear_runtime_cache(server_id).await;
+            lost = Some((server, conn.variables, tools));
         } else {
             warn!(target: "mcp_client", "Disconnect callback triggered but no connection found for {}", server_id);
         }
 
+        let reconnect = lost.as_ref().is_some_and(|(server, _, _)| server.reconnect_policy.enabled);
         let event_data = serde_json::json!({
             "server_id": server_id,
             "status": "disconnected",
             "error": format!("Transport closed: {}", reason),
             "reason": reason,
+            "reconnect": reconnect,
         });
         self.event_publisher.publish("mcp:connection_lost", event_data).await;
+
+        if let Some((server, variables, tools)) = lost.filter(|_| reconnect) {
+            self.schedule_reconnect(server, variables, tools).await;
+        }
+    }
+}
+
+/// Close a connection taken out of the map: stop its heartbeat, end the session before the
+/// transport closes (stateless servers have none) and stop the client
+async fn close(server_id: &str, conn: McpConnection) {
+    conn.heartbeat_cancel.cancel();
+    if let Some(session) = &conn.session {
+        if let Err(e) = session.terminate(&conn.server.url).await {
+            debug!(target: "mcp_client", "Session of server {} not terminated: {}", server_id, e);
+        }
+    }
+    if let Err(e) = conn.client.cancel().await {
+        error!(target: "mcp_client", "Error disconnecting: {}", e);
+    }
+}
+
+/// Random extra delay of up to a fifth of the backoff delay, so reconnects don't align
+fn jitter_ms(delay_ms: u64) -> u64 {
+    fastrand::u64(0..=delay_ms / 5)
+}
+
+/// Call `tools/list` on a peer and convert the result (raw JSON kept for debugging)
+async fn fetch_tools(peer: &Peer<RoleClient>) -> Result<McpToolsListResultInternal, AppError> {
+    // Call tools/list, following cursors
+    let (tools, raw_response, paging) = fetch_all_pages("tools", |params| {
+        let peer = peer.clone();
+        async move {
+            let result = peer.list_tools(params).await?;
+            let raw =
+                serde_json::to_string_pretty(&result).unwrap_or_else(|_| format!("{:?}", result));
+            Ok((result.tools, result.next_cursor, raw))
+        }
+    })
+    .await?;
+
+    // Convert to our tool format
+    let tools: Vec<McpToolInfo> = tools
+        .into_iter()
+        .map(|t| {
+            // input_schema is Arc<serde_json::Map<String, Value>>, serialize it directly
+            let input_schema =
+                serde_json::to_string_pretty(&*t.input_schema).ok().filter(|s| s != "{}");
+
+            // output_schema is also Arc<serde_json::Map<String, Value>>, serialize it
+            let output_schema = t.output_schema.as_ref().and_then(|schema| {
+                serde_json::to_string_pretty(&**schema).ok().filter(|s| s != "{}")
+            });
+
+            // Capture extra fields like annotations and _meta
+            let extra = extra_fields(&t, &["name", "description", "inputSchema", "outputSchema"]);
+
+            McpToolInfo {
+                name: t.name.to_string(),
+                description: t.description.as_ref().map(|d| d.to_string()),
+                input_schema,
+                output_schema,
+                extra,
+            }
+        })
+        .collect();
+
+    Ok(McpToolsListResultInternal { tools, raw_response, paging })
+}
+
+/// Follow `next_cursor` until the server stops paging (or `MAX_LIST_PAGES` is reached).
+///
+/// `fetch_page` returns one page as (items, next cursor, raw JSON). The returned raw response is
+/// the single page as-is, or a JSON array of every page when the server paged.
+async fn fetch_all_pages<T, F, Fut>(
+    what: &str,
+    mut fetch_page: F,
+) -> Result<(Vec<T>, String, McpListPaging), AppError>
+where
+    F: FnMut(Option<PaginatedRequestParam>) -> Fut,
+    Fut: std::future::Future<Output = Result<(Vec<T>, Option<String>, String), ServiceError>>,
+{
+    let start = std::time::Instant::now();
+    let mut items = Vec::new();
+    let mut paging = McpListPaging::default();
+    let mut seen_cursors = HashSet::new();
+    let mut cursor: Option<String> = None;
+
+    loop {
+        let params = cursor.take().map(|c| PaginatedRequestParam { cursor: Some(c) });
+        let (page_items, next_cursor, raw) = fetch_page(params).await.map_err(|e| {
+            error!(target: "mcp_client", "Failed to list {}: {}", what, e);
+            AppError::Io(format!("Failed to list {}: {}", what, e))
+        })?;
+
+        debug!(target: "mcp_client", "Raw {} list response (page {}):\n{}", what, paging.raw_pages.len() + 1, raw);
+
+        items.extend(page_items);
+        paging.raw_pages.push(raw);
+
+        match next_cursor {
+            None => break,
+            Some(_) if paging.raw_pages.len() >= MAX_LIST_PAGES => {
+                warn!(target: "mcp_client", "Stopped listing {} after {} pages", what, MAX_LIST_PAGES);
+                paging.truncated = true;
+                break;
+            }
+            Some(next) if !seen_cursors.insert(next.clone()) => {
+                warn!(target: "mcp_client", "Server repeated cursor {} while listing {}", next, what);
+                paging.truncated = true;
+                break;
+            }
+            Some(next) => cursor = Some(next),
+        }
+    }
+
+    paging.page_count = paging.raw_pages.len();
+    paging.duration_ms = start.elapsed().as_millis() as i64;
+    info!(target: "mcp_client", "Listed {} {} in {} page(s), {}ms", items.len(), what, paging.page_count, paging.duration_ms);
+
+    let raw_response = if paging.raw_pages.len() == 1 {
+        paging.raw_pages[0].clone()
+    } else {
+        let pages: Vec<serde_json::Value> = paging
+            .raw_pages
+            .iter()
+            .map(|p| {
+                serde_json::from_str(p).unwrap_or_else(|_| serde_json::Value::String(p.clone()))
+            })
+            .collect();
+        serde_json::to_string_pretty(&pages).unwrap_or_default()
+    };
+
+    Ok((items, raw_response, paging))
+}
+
+/// Call `resources/list` on a peer and convert the result (raw JSON kept for debugging)
+async fn fetch_resources(
+    peer: &Peer<RoleClient>,
+) -> Result<McpResourcesListResultInternal, AppError> {
+    let (resources, raw_response, paging) = fetch_all_pages("resources", |params| {
+        let peer = peer.clone();
+        async move {
+            let result = peer.list_resources(params).await?;
+            let raw =
+                serde_json::to_string_pretty(&result).unwrap_or_else(|_| format!("{:?}", result));
+            Ok((result.resources, result.next_cursor, raw))
+        }
+    })
+    .await?;
+
+    let resources = resources
+        .into_iter()
+        .map(|r| McpResourceInfo {
+            extra: extra_fields(&r, &["uri", "name", "title", "description", "mimeType", "size"]),
+            uri: r.raw.uri,
+            name: r.raw.name,
+            title: r.raw.title,
+            description: r.raw.description,
+            mime_type: r.raw.mime_type,
+            size: r.raw.size,
+        })
+        .collect();
+
+    Ok(McpResourcesListResultInternal { resources, raw_response, paging })
+}
+
+/// Call `prompts/list` on a peer and convert the result (raw JSON kept for debugging)
+async fn fetch_prompts(peer: &Peer<RoleClient>) -> Result<McpPromptsListResultInternal, AppError> {
+    let (prompts, raw_response, paging) = fetch_all_pages("prompts", |params| {
+        let peer = peer.clone();
+        async move {
+            let result = peer.list_prompts(params).await?;
+            let raw =
+                serde_json::to_string_pretty(&result).unwrap_or_else(|_| format!("{:?}", result));
+            Ok((result.prompts, result.next_cursor, raw))
+        }
+    })
+    .await?;
+
+    let prompts = prompts
+        .into_iter()
+        .map(|p| McpPromptInfo {
+            extra: extra_fields(&p, &["name", "title", "description", "arguments"]),
+            name: p.name,
+            title: p.title,
+            description: p.description,
+            arguments: p
+                .arguments
+                .unwrap_or_default()
+                .into_iter()
+                .map(|a| McpPromptArgument {
+                    name: a.name,
+                    title: a.title,
+                    description: a.description,
+                    required: a.required.unwrap_or(false),
+                })
+                .collect(),
+        })
+        .collect();
+
+    Ok(McpPromptsListResultInternal { prompts, raw_response, paging })
+}
+
+/// Map a domain log level to the rmcp one
+fn to_logging_level(level: McpLogLevel) -> LoggingLevel {
+    match level {
+        McpLogLevel::Debug => LoggingLevel::Debug,
+        McpLogLevel::Info => LoggingLevel::Info,
+        McpLogLevel::Notice => LoggingLevel::Notice,
+        McpLogLevel::Warning => LoggingLevel::Warning,
+        McpLogLevel::Error => LoggingLevel::Error,
+        McpLogLevel::Critical => LoggingLevel::Critical,
+        McpLogLevel::Alert => LoggingLevel::Alert,
+        McpLogLevel::Emergency => LoggingLevel::Emergency,
+    }
+}
+
+/// Map an rmcp log level to the domain one
+fn from_logging_level(level: LoggingLevel) -> McpLogLevel {
+    match level {
+        LoggingLevel::Debug => McpLogLevel::Debug,
+        LoggingLevel::Info => McpLogLevel::Info,
+        LoggingLevel::Notice => McpLogLevel::Notice,
+        LoggingLevel::Warning => McpLogLevel::Warning,
+        LoggingLevel::Error => McpLogLevel::Error,
+        LoggingLevel::Critical => McpLogLevel::Critical,
+        LoggingLevel::Alert => McpLogLevel::Alert,
+        LoggingLevel::Emergency => McpLogLevel::Emergency,
+    }
+}
+
+/// Serialize an item and keep only the fields not in `known` (annotations, _meta, icons, ...)
+fn extra_fields<T: Serialize>(item: &T, known: &[&str]) -> Option<String> {
+    let mut value = serde_json::to_value(item).unwrap_or(serde_json::Value::Null);
+    let obj = value.as_object_mut()?;
+
+    for key in known {
+        obj.remove(*key);
+    }
+
+    if obj.is_empty() {
+        None
+    } else {
+        serde_json::to_string(obj).ok()
     }
 }
 
@@ -593,6 +2095,19 @@ impl Clone for McpClientManager {
         Self {
             connections: self.connections.clone(),
             tools_cache: self.tools_cache.clone(),
+            resources_cache: self.resources_cache.clone(),
+            resource_templates_cache: self.resource_templates_cache.clone(),
+            resource_subscriptions: self.resource_subscriptions.clone(),
+            prompts_cache: self.prompts_cache.clone(),
+            roots: self.roots.clone(),
+            sampling_responder: self.sampling_responder.clone(),
+            elicitation_responder: self.elicitation_responder.clone(),
+            in_flight_calls: self.in_flight_calls.clone(),
+            log_repo: self.log_repo.clone(),
+            heartbeat_repo: self.heartbeat_repo.clone(),
+            traffic_repo: self.traffic_repo.clone(),
+            oauth_manager: self.oauth_manager.clone(),
+            reconnect_tasks: self.reconnect_tasks.clone(),
             event_publisher: self.event_publisher.clone(),
             config_repo: self.config_repo.clone(),
         }
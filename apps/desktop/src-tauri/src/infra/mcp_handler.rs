@@ -0,0 +1,112 @@
+//! MCP Client Handler - receives server-initiated notifications for one connection.
+//!
+//! Every connection made by `McpClientManager` is served with its own
+//! `McpClientHandler`, so notifications can be routed back to the right server id.
+
+use rmcp::model::{
+    ClientInfo, CreateElicitationRequestParam, CreateElicitationResult, CreateMessageRequestParam,
+    CreateMessageResult, ErrorData as McpError, ListRootsResult, LoggingMessageNotificationParam,
+    ProgressNotificationParam, ResourceUpdatedNotificationParam,
+};
+use rmcp::service::{NotificationContext, RequestContext};
+use rmcp::{ClientHandler, RoleClient};
+use tracing::{debug, info, warn};
+
+use crate::infra::mcp_client::McpClientManager;
+
+/// Per-connection rmcp `ClientHandler`
+pub struct McpClientHandler {
+    server_id: String,
+    client_info: ClientInfo,
+    manager: McpClientManager,
+}
+
+impl McpClientHandler {
+    pub fn new(server_id: String, client_info: ClientInfo, manager: McpClientManager) -> Self {
+        Self { server_id, client_info, manager }
+    }
+}
+
+impl ClientHandler for McpClientHandler {
+    async fn create_message(
+        &self,
+        params: CreateMessageRequestParam,
+        context: RequestContext<RoleClient>,
+    ) -> Result<CreateMessageResult, McpError> {
+        self.manager.handle_create_message(&self.server_id, params, context.ct).await
+    }
+
+    async fn list_roots(
+        &self,
+        _context: RequestContext<RoleClient>,
+    ) -> Result<ListRootsResult, McpError> {
+        debug!(target: "mcp_client", "Server {} requested roots/list", self.server_id);
+        Ok(self.manager.handle_list_roots(&self.server_id).await)
+    }
+
+    async fn on_tool_list_changed(&self, context: NotificationContext<RoleClient>) {
+        info!(target: "mcp_client", "Tools list changed for server {}", self.server_id);
+
+        if let Err(e) = self.manager.handle_tools_list_changed(&self.server_id, &context.peer).await
+        {
+            warn!(target: "mcp_client", "Failed to refresh tools after list_changed for {}: {}", self.server_id, e);
+        }
+    }
+
+    async fn create_elicitation(
+        &self,
+        request: CreateElicitationRequestParam,
+        context: RequestContext<RoleClient>,
+    ) -> Result<CreateElicitationResult, McpError> {
+        Ok(self.manager.handle_create_elicitation(&self.server_id, request, context.ct).await)
+    }
+
+    async fn on_progress(
+        &self,
+        params: ProgressNotificationParam,
+        _context: NotificationContext<RoleClient>,
+    ) {
+        self.manager.handle_progress(&self.server_id, params).await;
+    }
+
+    async fn on_logging_message(
+        &self,
+        params: LoggingMessageNotificationParam,
+        _context: NotificationContext<RoleClient>,
+    ) {
+        self.manager.handle_logging_message(&self.server_id, params).await;
+    }
+
+    async fn on_resource_updated(
+        &self,
+        params: ResourceUpdatedNotificationParam,
+        _context: NotificationContext<RoleClient>,
+    ) {
+        debug!(target: "mcp_client", "Resource {} updated on server {}", params.uri, self.server_id);
+        self.manager.handle_resource_updated(&self.server_id, &params.uri).await;
+    }
+
+    async fn on_resource_list_changed(&self, context: NotificationContext<RoleClient>) {
+        info!(target: "mcp_client", "Resources list changed for server {}", self.server_id);
+
+        if let Err(e) =
+            self.manager.handle_resources_list_changed(&self.server_id, &context.peer).await
+        {
+            warn!(target: "mcp_client", "Failed to refresh resources after list_changed for {}: {}", self.server_id, e);
+        }
+    }
+
+    async fn on_prompt_list_changed(&self, context: NotificationContext<RoleClient>) {
+        info!(target: "mcp_client", "Prompts list changed for server {}", self.server_id);
+
+        if let Err(e) =
+            self.manager.handle_prompts_list_changed(&self.server_id, &context.peer).await
+        {
+            warn!(target: "mcp_client", "Failed to refresh prompts after list_changed for {}: {}", self.server_id, e);
+        }
+    }
+
+    fn get_info(&self) -> ClientInfo {
+        self.client_info.clone()
+    }
+}
@@ -1,9 +1,35 @@
+pub mod assertion;
+pub mod clock;
 pub mod db;
+pub mod elicitation_responder;
+pub mod environment;
 pub mod event_publisher;
 pub mod http;
 pub mod http_server;
+pub mod json_diff;
+pub mod json_path;
+pub mod json_schema;
+pub mod junit;
+#[cfg(feature = "desktop")]
 pub mod logging;
 pub mod mcp_client;
+pub mod mcp_handler;
+pub mod oauth;
+pub mod raw_rpc;
+pub mod repo_collection;
 pub mod repo_config;
+pub mod repo_elicitation;
+pub mod repo_environment;
 pub mod repo_mcp;
+pub mod repo_oauth;
+pub mod repo_sampling;
+pub mod repo_server_log;
+pub mod repo_test_run;
+pub mod repo_traffic;
+pub mod sampling_responder;
 pub mod sse_transport;
+pub mod stdio_transport;
+pub mod streamable_http;
+pub mod traffic;
+pub mod traffic_export;
+pub mod websocket_transport;
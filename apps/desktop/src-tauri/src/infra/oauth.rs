@@ -0,0 +1,593 @@
+//! OAuth Manager - runs the MCP authorization flow for remote servers.
+//!
+//! Discovery follows the MCP authorization spec: the `WWW-Authenticate` header of a 401
+//! (or the well-known URI) points at the protected-resource metadata (RFC 9728), which
+//! names the authorization server whose metadata (RFC 8414 / OpenID discovery) gives the
+//! endpoints. Studio registers itself through dynamic client registration (RFC 7591)
+//! unless a client id is configured, then runs the authorization code flow with PKCE.
+//! The browser comes back through `/oauth/callback` on the embedded HTTP server.
+
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+    Engine,
+};
+use reqwest::header::ACCEPT;
+use reqwest::{StatusCode, Url};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use sha2::{Digest, Sha256};
+
+use crate::domain::mcp::{McpServer, McpServerAuth, McpServerType};
+use crate::domain::oauth::{
+    IMcpOAuthRepository, McpOAuthCredentials, McpOAuthStart, McpOAuthStatus,
+};
+use crate::error::AppError;
+use crate::infra::clock::unix_now;
+use crate::infra::event_publisher::EventPublisher;
+use crate::infra::http_server::OAuthCallbackParams;
+use crate::infra::mcp_client::request_headers;
+
+/// How long a started flow waits for the browser to come back
+const PENDING_FLOW_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Access tokens this close to expiry are refreshed before use
+const TOKEN_EXPIRY_MARGIN_SECS: i64 = 60;
+
+/// Timeout for discovery, registration and token requests
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Client name sent with dynamic client registration
+const CLIENT_NAME: &str = "MCP Studio";
+
+struct PendingFlow {
+    /// Client and endpoints of the flow (tokens are filled in after the code exchange)
+    credentials: McpOAuthCredentials,
+    code_verifier: String,
+    started: Instant,
+}
+
+/// Protected resource metadata (RFC 9728), the fields Studio uses
+#[derive(Debug, Deserialize)]
+struct ProtectedResourceMetadata {
+    resource: Option<String>,
+    #[serde(default)]
+    authorization_servers: Vec<String>,
+    #[serde(default)]
+    scopes_supported: Vec<String>,
+}
+
+/// Authorization server metadata (RFC 8414), the fields Studio uses
+#[derive(Debug, Deserialize)]
+struct AuthorizationServerMetadata {
+    authorization_endpoint: String,
+    token_endpoint: String,
+    registration_endpoint: Option<String>,
+    #[serde(default)]
+    code_challenge_methods_supported: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ClientRegistrationResponse {
+    client_id: String,
+    client_secret: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    token_type: Option<String>,
+    expires_in: Option<i64>,
+    refresh_token: Option<String>,
+    scope: Option<String>,
+}
+
+/// Parameters of the `WWW-Authenticate: Bearer` challenge of a 401 response
+#[derive(Debug, Default)]
+struct AuthChallenge {
+    resource_metadata: Option<String>,
+    scope: Option<String>,
+}
+
+pub struct McpOAuthManager {
+    repo: Arc<dyn IMcpOAuthRepository>,
+    event_publisher: Arc<dyn EventPublisher>,
+    client: reqwest::Client,
+    /// Flows waiting for the redirect, keyed by `state`
+    pending: Mutex<HashMap<String, PendingFlow>>,
+}
+
+impl McpOAuthManager {
+    pub fn new(
+        repo: Arc<dyn IMcpOAuthRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Result<Self, AppError> {
+        let client =
+            reqwest::Client::builder().no_proxy().timeout(REQUEST_TIMEOUT).build().map_err(
+                |e| AppError::Unknown(format!("Failed to build OAuth http client: {}", e)),
+            )?;
+
+        Ok(Self { repo, event_publisher, client, pending: Mutex::new(HashMap::new()) })
+    }
+
+    /// Discover the authorization server, register if needed and build the authorization URL
+    pub async fn start(
+        &self,
+        server: &McpServer,
+        redirect_uri: &str,
+    ) -> Result<McpOAuthStart, AppError> {
+        if server.server_type == McpServerType::Stdio {
+            return Err(AppError::Domain("OAuth applies to remote servers only".to_string()));
+        }
+
+        let (configured_client, configured_scope) = match &server.auth {
+            McpServerAuth::OAuth { client_id, client_secret, scope } => (
+                client_id
+                    .clone()
+                    .filter(|id| !id.trim().is_empty())
+                    .map(|id| (id, client_secret.clone().filter(|s| !s.is_empty()))),
+                scope.clone().filter(|s| !s.trim().is_empty()),
+            ),
+            _ => (None, None),
+        };
+
+        info!(target: "oauth", "Starting OAuth flow for server {}", server.id);
+
+        let challenge = self.probe(server).await;
+        let resource_metadata = self
+            .fetch_protected_resource_metadata(&server.url, challenge.resource_metadata)
+            .await?;
+
+        let resource = resource_metadata
+            .as_ref()
+            .and_then(|m| m.resource.clone())
+            .unwrap_or_else(|| server.url.clone());
+        let issuer = match resource_metadata.as_ref().and_then(|m| m.authorization_servers.first())
+        {
+            Some(issuer) => issuer.trim_end_matches('/').to_string(),
+            // Servers without resource metadata act as their own authorization server
+            None => split_url(&server.url)?.0,
+        };
+        info!(target: "oauth", "Authorization server for {}: {}", server.id, issuer);
+
+        let metadata = self.fetch_authorization_server_metadata(&issuer).await?;
+        if !metadata.code_challenge_methods_supported.is_empty()
+            && !metadata.code_challenge_methods_supported.iter().any(|m| m == "S256")
+        {
+            return Err(AppError::Domain(format!(
+                "Authorization server {} does not support PKCE with S256",
+                issuer
+            )));
+        }
+
+        let scope = configured_scope.or(challenge.scope).or_else(|| {
+            resource_metadata
+                .as_ref()
+                .map(|m| m.scopes_supported.join(" "))
+                .filter(|s| !s.is_empty())
+        });
+
+        // Reuse an earlier registration with the same authorization server and redirect
+        let existing = self
+            .repo
+            .find(&server.id)
+            .await?
+            .filter(|c| c.authorization_server == issuer && c.redirect_uri == redirect_uri);
+        let (client_id, client_secret) = match (configured_client, existing) {
+            (Some(client), _) => client,
+            (None, Some(existing)) => (existing.client_id, existing.client_secret),
+            (None, None) => self.register_client(&metadata, redirect_uri).await?,
+        };
+
+        let code_verifier = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+        let code_challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()));
+        let state = Uuid::new_v4().simple().to_string();
+
+        let mut authorization_url = Url::parse(&metadata.authorization_endpoint).map_err(|e| {
+            AppError::Domain(format!(
+                "Invalid authorization endpoint {}: {}",
+                metadata.authorization_endpoint, e
+            ))
+        })?;
+        {
+            let mut query = authorization_url.query_pairs_mut();
+            query
+                .append_pair("response_type", "code")
+                .append_pair("client_id", &client_id)
+                .append_pair("redirect_uri", redirect_uri)
+                .append_pair("code_challenge", &code_challenge)
+                .append_pair("code_challenge_method", "S256")
+                .append_pair("state", &state)
+                .append_pair("resource", &resource);
+            if let Some(scope) = &scope {
+                query.append_pair("scope", scope);
+            }
+        }
+
+        let credentials = McpOAuthCredentials {
+            server_id: server.id.clone(),
+            authorization_server: issuer,
+            token_endpoint: metadata.token_endpoint,
+            resource,
+            client_id,
+            client_secret,
+            redirect_uri: redirect_uri.to_string(),
+            access_token: None,
+            refresh_token: None,
+            token_type: None,
+            scope,
+            expires_at: None,
+            created_at: String::new(),
+            updated_at: String::new(),
+        };
+
+        let mut pending = self.pending.lock().await;
+        pending.retain(|_, flow| flow.started.elapsed() < PENDING_FLOW_TIMEOUT);
+        pending.insert(
+            state.clone(),
+            PendingFlow { credentials, code_verifier, started: Instant::now() },
+        );
+
+        Ok(McpOAuthStart {
+            server_id: server.id.clone(),
+            state,
+            authorization_url: authorization_url.to_string(),
+            redirect_uri: redirect_uri.to_string(),
+        })
+    }
+
+    /// Finish a flow from the loopback redirect and publish `mcp:oauth_completed`
+    pub async fn handle_callback(&self, params: OAuthCallbackParams) {
+        let Some(state) = params.state.as_deref() else {
+            warn!(target: "oauth", "OAuth callback without state ignored");
+            return;
+        };
+        let Some(flow) = self.pending.lock().await.remove(state) else {
+            warn!(target: "oauth", "OAuth callback for unknown or expired state {}", state);
+            return;
+        };
+        let server_id = flow.credentials.server_id.clone();
+
+        let result = match (params.error, params.code) {
+            (Some(error), _) => Err(AppError::Domain(format!(
+                "Authorization denied: {}",
+                params.error_description.unwrap_or(error)
+            ))),
+            (None, Some(code)) => self.exchange_code(flow, &code).await,
+            (None, None) => Err(AppError::Domain("Authorization response has no code".to_string())),
+        };
+
+        match &result {
+            Ok(_) => info!(target: "oauth", "OAuth flow completed for server {}", server_id),
+            Err(e) => warn!(target: "oauth", "OAuth flow failed for server {}: {}", server_id, e),
+        }
+
+        let event_data = serde_json::json!({
+            "server_id": server_id,
+            "success": result.is_ok(),
+            "error": result.err().map(|e| e.to_string()),
+        });
+        self.event_publisher.publish("mcp:oauth_completed", event_data).await;
+    }
+
+    /// Current access token of a server, refreshed first when it is about to expire
+    pub async fn access_token(&self, server_id: &str) -> Result<Option<String>, AppError> {
+        let Some(credentials) = self.repo.find(server_id).await? else {
+            return Ok(None);
+        };
+
+        let expired =
+            credentials.expires_at.is_some_and(|at| at <= unix_now() + TOKEN_EXPIRY_MARGIN_SECS);
+        if credentials.access_token.is_some() && !expired {
+            return Ok(credentials.access_token);
+        }
+        if credentials.refresh_token.is_none() {
+            return Ok(None);
+        }
+
+        debug!(target: "oauth", "Access token of server {} expired, refreshing", server_id);
+        Ok(self.refresh_credentials(credentials).await?.access_token)
+    }
+
+    /// Exchange the stored refresh token for a new access token
+    pub async fn refresh(&self, server_id: &str) -> Result<McpOAuthStatus, AppError> {
+        let credentials = self.repo.find(server_id).await?.ok_or_else(|| {
+            AppError::NotFound(format!("Server {} has no OAuth credentials", server_id))
+        })?;
+
+        let credentials = self.refresh_credentials(credentials).await?;
+        Ok(McpOAuthStatus::from_credentials(server_id, Some(&credentials)))
+    }
+
+    pub async fn status(&self, server_id: &str) -> Result<McpOAuthStatus, AppError> {
+        let credentials = self.repo.find(server_id).await?;
+        Ok(McpOAuthStatus::from_credentials(server_id, credentials.as_ref()))
+    }
+
+    /// Forget tokens and client registration (and abandon flows in progress)
+    pub async fn clear(&self, server_id: &str) -> Result<(), AppError> {
+        self.pending.lock().await.retain(|_, flow| flow.credentials.server_id != server_id);
+        self.repo.delete(server_id).await
+    }
+
+    async fn exchange_code(&self, flow: PendingFlow, code: &str) -> Result<(), AppError> {
+        let form = vec![
+            ("grant_type", "authorization_code".to_string()),
+            ("code", code.to_string()),
+            ("redirect_uri", flow.credentials.redirect_uri.clone()),
+            ("code_verifier", flow.code_verifier),
+            ("resource", flow.credentials.resource.clone()),
+        ];
+
+        let token = self.token_request(&flow.credentials, form).await?;
+        self.repo.save(apply_token(flow.credentials, token)).await?;
+        Ok(())
+    }
+
+    async fn refresh_credentials(
+        &self,
+        credentials: McpOAuthCredentials,
+    ) -> Result<McpOAuthCredentials, AppError> {
+        let refresh_token = credentials.refresh_token.clone().ok_or_else(|| {
+            AppError::Domain("No refresh token stored; authorize the server again".to_string())
+        })?;
+
+        let form = vec![
+            ("grant_type", "refresh_token".to_string()),
+            ("refresh_token", refresh_token),
+            ("resource", credentials.resource.clone()),
+        ];
+
+        let token = self.token_request(&credentials, form).await?;
+        info!(target: "oauth", "Refreshed access token for server {}", credentials.server_id);
+        self.repo.save(apply_token(credentials, token)).await
+    }
+
+    /// POST to the token endpoint; confidential clients authenticate with HTTP Basic
+    async fn token_request(
+        &self,
+        credentials: &McpOAuthCredentials,
+        mut form: Vec<(&str, String)>,
+    ) -> Result<TokenResponse, AppError> {
+        let mut request =
+            self.client.post(&credentials.token_endpoint).header(ACCEPT, "application/json");
+        match &credentials.client_secret {
+            Some(secret) => request = request.basic_auth(&credentials.client_id, Some(secret)),
+            None => form.push(("client_id", credentials.client_id.clone())),
+        }
+
+        let response = request
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| AppError::Io(format!("Token request failed: {}", e)))?;
+
+        read_json(response, "Token request").await
+    }
+
+    async fn register_client(
+        &self,
+        metadata: &AuthorizationServerMetadata,
+        redirect_uri: &str,
+    ) -> Result<(String, Option<String>), AppError> {
+        let endpoint = metadata.registration_endpoint.as_deref().ok_or_else(|| {
+            AppError::Domain(
+                "Authorization server does not support dynamic client registration; configure a client id"
+                    .to_string(),
+            )
+        })?;
+
+        info!(target: "oauth", "Registering client at {}", endpoint);
+        let body = serde_json::json!({
+            "client_name": CLIENT_NAME,
+            "redirect_uris": [redirect_uri],
+            "grant_types": ["authorization_code", "refresh_token"],
+            "response_types": ["code"],
+            "token_endpoint_auth_method": "none",
+        });
+
+        let response = self
+            .client
+            .post(endpoint)
+            .header(ACCEPT, "application/json")
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| AppError::Io(format!("Client registration failed: {}", e)))?;
+
+        let registration: ClientRegistrationResponse =
+            read_json(response, "Client registration").await?;
+        Ok((registration.client_id, registration.client_secret))
+    }
+
+    /// Unauthenticated request to the MCP endpoint to read the 401 challenge, if any
+    async fn probe(&self, server: &McpServer) -> AuthChallenge {
+        let headers = request_headers(&server.headers, &McpServerAuth::None).unwrap_or_default();
+        let request = match server.server_type {
+            McpServerType::Sse => self.client.get(&server.url).header(ACCEPT, "text/event-stream"),
+            // The handshake is rejected before the upgrade when a token is required
+            McpServerType::WebSocket => self
+                .client
+                .get(http_url(&server.url))
+                .header("Connection", "Upgrade")
+                .header("Upgrade", "websocket")
+                .header("Sec-WebSocket-Version", "13")
+                .header("Sec-WebSocket-Key", STANDARD.encode(Uuid::new_v4().as_bytes())),
+            _ => self
+                .client
+                .post(&server.url)
+                .header(ACCEPT, "application/json, text/event-stream")
+                .json(&serde_json::json!({ "jsonrpc": "2.0", "id": 0, "method": "ping" })),
+        };
+
+        let response = match request.headers(headers).send().await {
+            Ok(response) => response,
+            Err(e) => {
+                warn!(target: "oauth", "Probe of {} failed: {}", server.url, e);
+                return AuthChallenge::default();
+            }
+        };
+
+        if response.status() != StatusCode::UNAUTHORIZED {
+            debug!(target: "oauth", "Probe of {} returned {}", server.url, response.status());
+            return AuthChallenge::default();
+        }
+
+        let header = response
+            .headers()
+            .get_all(reqwest::header::WWW_AUTHENTICATE)
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .find(|v| v.trim_start().to_ascii_lowercase().starts_with("bearer"))
+            .unwrap_or_default();
+        debug!(target: "oauth", "WWW-Authenticate from {}: {}", server.url, header);
+
+        AuthChallenge {
+            resource_metadata: challenge_param(header, "resource_metadata"),
+            scope: challenge_param(header, "scope"),
+        }
+    }
+
+    async fn fetch_protected_resource_metadata(
+        &self,
+        server_url: &str,
+        hinted_url: Option<String>,
+    ) -> Result<Option<ProtectedResourceMetadata>, AppError> {
+        let (origin, path) = split_url(server_url)?;
+        let mut candidates: Vec<String> = hinted_url.into_iter().collect();
+        if !path.is_empty() {
+            candidates.push(format!("{}/.well-known/oauth-protected-resource{}", origin, path));
+        }
+        candidates.push(format!("{}/.well-known/oauth-protected-resource", origin));
+
+        Ok(self.fetch_first(&candidates).await)
+    }
+
+    async fn fetch_authorization_server_metadata(
+        &self,
+        issuer: &str,
+    ) -> Result<AuthorizationServerMetadata, AppError> {
+        let (origin, path) = split_url(issuer)?;
+        let candidates = if path.is_empty() {
+            vec![
+                format!("{}/.well-known/oauth-authorization-server", origin),
+                format!("{}/.well-known/openid-configuration", origin),
+            ]
+        } else {
+            vec![
+                format!("{}/.well-known/oauth-authorization-server{}", origin, path),
+                format!("{}/.well-known/openid-configuration{}", origin, path),
+                format!("{}{}/.well-known/openid-configuration", origin, path),
+            ]
+        };
+
+        if let Some(metadata) = self.fetch_first(&candidates).await {
+            return Ok(metadata);
+        }
+
+        // Servers from before metadata discovery use default endpoint paths
+        warn!(target: "oauth", "No authorization server metadata at {}, using default endpoints", issuer);
+        Ok(AuthorizationServerMetadata {
+            authorization_endpoint: format!("{}/authorize", origin),
+            token_endpoint: format!("{}/token", origin),
+            registration_endpoint: Some(format!("{}/register", origin)),
+            code_challenge_methods_supported: Vec::new(),
+        })
+    }
+
+    /// First candidate URL answering with a parsable JSON document
+    async fn fetch_first<T: DeserializeOwned>(&self, urls: &[String]) -> Option<T> {
+        for url in urls {
+            match self.client.get(url).header(ACCEPT, "application/json").send().await {
+                Ok(response) if response.status().is_success() => match response.json().await {
+                    Ok(document) => {
+                        debug!(target: "oauth", "Fetched metadata from {}", url);
+                        return Some(document);
+                    }
+                    Err(e) => debug!(target: "oauth", "Invalid metadata at {}: {}", url, e),
+                },
+                Ok(response) => {
+                    debug!(target: "oauth", "Metadata at {} returned {}", url, response.status())
+                }
+                Err(e) => debug!(target: "oauth", "Failed to fetch metadata from {}: {}", url, e),
+            }
+        }
+        None
+    }
+}
+
+/// Store a token response on the credentials; a missing refresh token keeps the old one
+fn apply_token(mut credentials: McpOAuthCredentials, token: TokenResponse) -> McpOAuthCredentials {
+    credentials.access_token = Some(token.access_token);
+    credentials.token_type = token.token_type;
+    credentials.expires_at = token.expires_in.map(|secs| unix_now() + secs);
+    if token.refresh_token.is_some() {
+        credentials.refresh_token = token.refresh_token;
+    }
+    if token.scope.is_some() {
+        credentials.scope = token.scope;
+    }
+    credentials
+}
+
+/// Parse a JSON response body, turning error statuses into errors that carry the body
+async fn read_json<T: DeserializeOwned>(
+    response: reqwest::Response,
+    what: &str,
+) -> Result<T, AppError> {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    if !status.is_success() {
+        return Err(AppError::Io(format!("{} failed ({}): {}", what, status, body)));
+    }
+    serde_json::from_str(&body)
+        .map_err(|e| AppError::Io(format!("{} returned an invalid response: {}", what, e)))
+}
+
+/// Origin and path (without trailing slash) of a URL
+fn split_url(url: &str) -> Result<(String, String), AppError> {
+    let parsed = Url::parse(&http_url(url))
+        .map_err(|e| AppError::Domain(format!("Invalid URL {}: {}", url, e)))?;
+    let path = parsed.path().trim_end_matches('/').to_string();
+    Ok((parsed.origin().ascii_serialization(), path))
+}
+
+/// HTTP(S) URL of a WebSocket endpoint, where its metadata is served (other URLs unchanged)
+fn http_url(url: &str) -> String {
+    if let Some(rest) = url.strip_prefix("ws://") {
+        format!("http://{}", rest)
+    } else if let Some(rest) = url.strip_prefix("wss://") {
+        format!("https://{}", rest)
+    } else {
+        url.to_string()
+    }
+}
+
+/// Value of an auth-param (quoted or token) in a `WWW-Authenticate` challenge
+fn challenge_param(header: &str, name: &str) -> Option<String> {
+    let lower = header.to_ascii_lowercase();
+    let key = format!("{}=", name);
+    let mut from = 0;
+
+    while let Some(pos) = lower[from..].find(&key) {
+        let start = from + pos;
+        from = start + key.len();
+        if start > 0 && !matches!(lower.as_bytes()[start - 1], b' ' | b',' | b'\t') {
+            continue;
+        }
+
+        let value = &header[from..];
+        let value = match value.strip_prefix('"') {
+            Some(quoted) => quoted.split('"').next().unwrap_or_default(),
+            None => value.split([',', ' ']).next().unwrap_or_default(),
+        };
+        return Some(value.to_string()).filter(|v| !v.is_empty());
+    }
+    None
+}
@@ -0,0 +1,238 @@
+//! Raw JSON-RPC - hand-written messages sent over an existing connection.
+//!
+//! rmcp only sends the typed requests it knows, so raw messages bypass it: the manager
+//! hands the text to the transport worker, which writes it on the wire and routes the
+//! reply carrying the same id back here before rmcp ever sees it. rmcp numbers its own
+//! requests, so a raw request goes out under an id of its own and the reply gets the
+//! original id back.
+
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::error::AppError;
+
+/// Reply key of messages that aren't valid JSON-RPC (servers answer them with a null id)
+const INVALID_REPLY_KEY: &str = "null";
+
+type PendingReplies = Arc<Mutex<HashMap<String, oneshot::Sender<String>>>>;
+
+/// What came back for a raw message
+#[derive(Debug, Default)]
+pub struct RawRpcReply {
+    /// Raw reply (a JSON-RPC response, or the body of a rejected HTTP request)
+    pub body: Option<String>,
+    /// Transport-level failure (HTTP status, write error)
+    pub error: Option<String>,
+    /// No reply arrived in time
+    pub timed_out: bool,
+}
+
+/// How a raw message is classified before it is sent
+#[derive(Debug)]
+pub struct RawRpcMessage {
+    /// 'request', 'notification' or 'invalid'
+    pub kind: &'static str,
+    pub method: Option<String>,
+    /// Id of the reply to wait for (None = notification, nothing comes back)
+    pub reply_key: Option<String>,
+}
+
+impl RawRpcMessage {
+    pub fn inspect(text: &str) -> Self {
+        let value = serde_json::from_str::<Value>(text).ok();
+        let method = value
+            .as_ref()
+            .and_then(|v| v.get("method"))
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
+        match (&value, &method) {
+            (Some(Value::Object(message)), Some(_)) => match message.get("id") {
+                Some(id) => Self { kind: "request", method, reply_key: Some(id.to_string()) },
+                None => Self { kind: "notification", method, reply_key: None },
+            },
+            _ => Self { kind: "invalid", method, reply_key: Some(INVALID_REPLY_KEY.to_string()) },
+        }
+    }
+}
+
+/// Swap the id of a request for a unique one; returns the message to send, its reply key
+/// and the original id
+fn namespace_id(text: &str) -> Option<(String, String, Value)> {
+    let Ok(Value::Object(mut message)) = serde_json::from_str::<Value>(text) else {
+        return None;
+    };
+    let wire_id = Value::String(format!("raw-{}", Uuid::new_v4().simple()));
+    let original_id = message.insert("id".to_string(), wire_id.clone())?;
+    let text = serde_json::to_string(&message).ok()?;
+    Some((text, wire_id.to_string(), original_id))
+}
+
+/// Put the original id back on a reply
+fn restore_id(reply: String, original_id: &Value) -> String {
+    match serde_json::from_str::<Value>(&reply) {
+        Ok(Value::Object(mut message)) => {
+            message.insert("id".to_string(), original_id.clone());
+            serde_json::to_string(&message).unwrap_or(reply)
+        }
+        _ => reply,
+    }
+}
+
+/// Key of an incoming response (None for requests and notifications from the server)
+pub fn response_key(text: &str) -> Option<String> {
+    let Ok(Value::Object(message)) = serde_json::from_str::<Value>(text) else {
+        return None;
+    };
+    if message.contains_key("method")
+        || !(message.contains_key("result") || message.contains_key("error"))
+    {
+        return None;
+    }
+    Some(message.get("id").unwrap_or(&Value::Null).to_string())
+}
+
+/// Raw message queued for a transport worker
+pub struct RawRpcOutgoing {
+    pub text: String,
+    /// Ok once the message is on the wire; Err carries the final reply when sending failed
+    pub sent: oneshot::Sender<Result<(), RawRpcReply>>,
+}
+
+/// Manager side: sends raw messages and waits for their replies
+#[derive(Clone)]
+pub struct RawRpcSender {
+    tx: mpsc::UnboundedSender<RawRpcOutgoing>,
+    pending: PendingReplies,
+}
+
+/// Worker side: receives raw messages and claims their replies
+pub struct RawRpcChannel {
+    rx: tokio::sync::Mutex<mpsc::UnboundedReceiver<RawRpcOutgoing>>,
+    pending: PendingReplies,
+}
+
+/// Create the two ends of a connection's raw message channel
+pub fn raw_rpc_channel() -> (RawRpcSender, RawRpcChannel) {
+    let (tx, rx) = mpsc::unbounded_channel();
+    let pending = PendingReplies::default();
+    let rx = tokio::sync::Mutex::new(rx);
+    (RawRpcSender { tx, pending: pending.clone() }, RawRpcChannel { rx, pending })
+}
+
+impl RawRpcSender {
+    /// Send `text` (requests under a unique id); waits up to `timeout` for the reply when the
+    /// message expects one
+    pub async fn send(&self, text: &str, timeout: Duration) -> Result<RawRpcReply, AppError> {
+        let message = RawRpcMessage::inspect(text);
+        let (text, reply_key, original_id) = match message.kind {
+            "request" => match namespace_id(text) {
+                Some((text, reply_key, original_id)) => (text, Some(reply_key), Some(original_id)),
+                None => (text.to_string(), message.reply_key, None),
+            },
+            _ => (text.to_string(), message.reply_key, None),
+        };
+
+        let reply_rx = match &reply_key {
+            Some(key) => {
+                let mut pending = self.lock();
+                if pending.contains_key(key) {
+                    return Err(AppError::Domain(format!(
+                        "A raw message with id {} is still waiting for its reply",
+                        key
+                    )));
+                }
+                let (reply_tx, reply_rx) = oneshot::channel();
+                pending.insert(key.clone(), reply_tx);
+                Some(reply_rx)
+            }
+            None => None,
+        };
+
+        let (sent_tx, sent_rx) = oneshot::channel();
+        let queued = self.tx.send(RawRpcOutgoing { text, sent: sent_tx });
+        let sent = match queued {
+            Ok(()) => sent_rx.await.ok(),
+            Err(_) => None,
+        };
+
+        match sent {
+            Some(Ok(())) => {}
+            Some(Err(reply)) => {
+                self.forget(reply_key.as_deref());
+                return Ok(reply);
+            }
+            None => {
+                self.forget(reply_key.as_deref());
+                return Err(AppError::Io("Transport is closed".to_string()));
+            }
+        }
+
+        let Some(reply_rx) = reply_rx else {
+            return Ok(RawRpcReply::default());
+        };
+
+        match tokio::time::timeout(timeout, reply_rx).await {
+            Ok(Ok(body)) => {
+                let body = match &original_id {
+                    Some(original_id) => restore_id(body, original_id),
+                    None => body,
+                };
+                Ok(RawRpcReply { body: Some(body), ..Default::default() })
+            }
+            Ok(Err(_)) => {
+                Err(AppError::Io("Transport closed before the reply arrived".to_string()))
+            }
+            Err(_) => {
+                self.forget(reply_key.as_deref());
+                Ok(RawRpcReply {
+                    error: Some(format!("No reply within {} ms", timeout.as_millis())),
+                    timed_out: true,
+                    ..Default::default()
+                })
+            }
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, oneshot::Sender<String>>> {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn forget(&self, reply_key: Option<&str>) {
+        if let Some(key) = reply_key {
+            self.lock().remove(key);
+        }
+    }
+}
+
+impl RawRpcChannel {
+    /// Next raw message to write (pending forever once the manager side is gone)
+    pub async fn recv(&self) -> RawRpcOutgoing {
+        match self.rx.lock().await.recv().await {
+            Some(outgoing) => outgoing,
+            None => std::future::pending().await,
+        }
+    }
+
+    /// Hand an incoming message to a waiting raw request; true when it was claimed
+    pub fn claim_reply(&self, text: &str) -> bool {
+        let Some(key) = response_key(text) else {
+            return false;
+        };
+
+        let reply_tx = self.pending.lock().unwrap_or_else(|e| e.into_inner()).remove(&key);
+        match reply_tx {
+            Some(reply_tx) => {
+                debug!(target: "mcp_client", "Raw reply received for id {}", key);
+                let _ = reply_tx.send(text.to_string());
+                true
+            }
+            None => false,
+        }
+    }
+}
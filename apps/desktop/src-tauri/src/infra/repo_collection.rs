@@ -0,0 +1,409 @@
+//! SQLite Repository implementations for collections, their folders and saved calls.
+
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+use crate::domain::collection::{
+    IMcpCollectionFolderRepository, IMcpCollectionRepository, IMcpSavedCallRepository,
+    McpCollection, McpCollectionFolder, McpSavedCall,
+};
+use crate::error::AppError;
+
+pub struct SqliteMcpCollectionRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMcpCollectionRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IMcpCollectionRepository for SqliteMcpCollectionRepository {
+    async fn create(&self, collection: McpCollection) -> Result<McpCollection, AppError> {
+        sqlx::query(
+            r#"INSERT INTO mcp_collections (id, name, description, position, created_at, updated_at)
+               VALUES (?, ?, ?, ?, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)"#,
+        )
+        .bind(&collection.id)
+        .bind(&collection.name)
+        .bind(&collection.description)
+        .bind(collection.position)
+        .execute(&self.pool)
+        .await?;
+
+        self.find_by_id(&collection.id)
+            .await?
+            .ok_or_else(|| AppError::Database("Failed to create collection".to_string()))
+    }
+
+    async fn update(&self, collection: McpCollection) -> Result<McpCollection, AppError> {
+        let result = sqlx::query(
+            r#"UPDATE mcp_collections
+               SET name = ?, description = ?, position = ?, updated_at = CURRENT_TIMESTAMP
+               WHERE id = ?"#,
+        )
+        .bind(&collection.name)
+        .bind(&collection.description)
+        .bind(collection.position)
+        .bind(&collection.id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Collection {} not found", collection.id)));
+        }
+
+        self.find_by_id(&collection.id)
+            .await?
+            .ok_or_else(|| AppError::Database("Failed to update collection".to_string()))
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), AppError> {
+        let result = sqlx::query("DELETE FROM mcp_collections WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Collection {} not found", id)));
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<McpCollection>, AppError> {
+        let row = sqlx::query_as::<_, McpCollectionRow>(
+            "SELECT id, name, description, position, created_at, updated_at FROM mcp_collections WHERE id = ?"
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
+    async fn list(&self) -> Result<Vec<McpCollection>, AppError> {
+        let rows = sqlx::query_as::<_, McpCollectionRow>(
+            "SELECT id, name, description, position, created_at, updated_at FROM mcp_collections ORDER BY position ASC, created_at ASC"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn reorder(&self, ids: &[String]) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+        for (position, id) in ids.iter().enumerate() {
+            sqlx::query("UPDATE mcp_collections SET position = ? WHERE id = ?")
+                .bind(position as i64)
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn reorder_items(
+        &self,
+        folders: &[(&str, i64)],
+        calls: &[(&str, i64)],
+    ) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+        for (id, position) in folders {
+            sqlx::query("UPDATE mcp_collection_folders SET position = ? WHERE id = ?")
+                .bind(position)
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        for (id, position) in calls {
+            sqlx::query("UPDATE mcp_saved_calls SET position = ? WHERE id = ?")
+                .bind(position)
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct McpCollectionRow {
+    id: String,
+    name: String,
+    description: Option<String>,
+    position: i64,
+    created_at: String,
+    updated_at: String,
+}
+
+impl From<McpCollectionRow> for McpCollection {
+    fn from(row: McpCollectionRow) -> Self {
+        McpCollection {
+            id: row.id,
+            name: row.name,
+            description: row.description,
+            position: row.position,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+// ============ Collection Folder Repository ============
+
+pub struct SqliteMcpCollectionFolderRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMcpCollectionFolderRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IMcpCollectionFolderRepository for SqliteMcpCollectionFolderRepository {
+    async fn create(&self, folder: McpCollectionFolder) -> Result<McpCollectionFolder, AppError> {
+        sqlx::query(
+            r#"INSERT INTO mcp_collection_folders (id, collection_id, parent_id, name, position, created_at, updated_at)
+               VALUES (?, ?, ?, ?, ?, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)"#,
+        )
+        .bind(&folder.id)
+        .bind(&folder.collection_id)
+        .bind(&folder.parent_id)
+        .bind(&folder.name)
+        .bind(folder.position)
+        .execute(&self.pool)
+        .await?;
+
+        self.find_by_id(&folder.id)
+            .await?
+            .ok_or_else(|| AppError::Database("Failed to create folder".to_string()))
+    }
+
+    async fn update(&self, folder: McpCollectionFolder) -> Result<McpCollectionFolder, AppError> {
+        let result = sqlx::query(
+            r#"UPDATE mcp_collection_folders
+               SET parent_id = ?, name = ?, position = ?, updated_at = CURRENT_TIMESTAMP
+               WHERE id = ?"#,
+        )
+        .bind(&folder.parent_id)
+        .bind(&folder.name)
+        .bind(folder.position)
+        .bind(&folder.id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Folder {} not found", folder.id)));
+        }
+
+        self.find_by_id(&folder.id)
+            .await?
+            .ok_or_else(|| AppError::Database("Failed to update folder".to_string()))
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), AppError> {
+        let result = sqlx::query("DELETE FROM mcp_collection_folders WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Folder {} not found", id)));
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<McpCollectionFolder>, AppError> {
+        let row = sqlx::query_as::<_, McpCollectionFolderRow>(
+            "SELECT id, collection_id, parent_id, name, position, created_at, updated_at FROM mcp_collection_folders WHERE id = ?"
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
+    async fn list(&self, collection_id: &str) -> Result<Vec<McpCollectionFolder>, AppError> {
+        let rows = sqlx::query_as::<_, McpCollectionFolderRow>(
+            "SELECT id, collection_id, parent_id, name, position, created_at, updated_at FROM mcp_collection_folders WHERE collection_id = ? ORDER BY position ASC, created_at ASC"
+        )
+        .bind(collection_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct McpCollectionFolderRow {
+    id: String,
+    collection_id: String,
+    parent_id: Option<String>,
+    name: String,
+    position: i64,
+    created_at: String,
+    updated_at: String,
+}
+
+impl From<McpCollectionFolderRow> for McpCollectionFolder {
+    fn from(row: McpCollectionFolderRow) -> Self {
+        McpCollectionFolder {
+            id: row.id,
+            collection_id: row.collection_id,
+            parent_id: row.parent_id,
+            name: row.name,
+            position: row.position,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+// ============ Saved Call Repository ============
+
+pub struct SqliteMcpSavedCallRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMcpSavedCallRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IMcpSavedCallRepository for SqliteMcpSavedCallRepository {
+    async fn create(&self, call: McpSavedCall) -> Result<McpSavedCall, AppError> {
+        sqlx::query(
+            r#"INSERT INTO mcp_saved_calls (id, collection_id, folder_id, name, server_id, tool_name, params, notes, assertions, position, created_at, updated_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)"#,
+        )
+        .bind(&call.id)
+        .bind(&call.collection_id)
+        .bind(&call.folder_id)
+        .bind(&call.name)
+        .bind(&call.server_id)
+        .bind(&call.tool_name)
+        .bind(&call.params)
+        .bind(&call.notes)
+        .bind(assertions_column(&call)?)
+        .bind(call.position)
+        .execute(&self.pool)
+        .await?;
+
+        self.find_by_id(&call.id)
+            .await?
+            .ok_or_else(|| AppError::Database("Failed to create saved call".to_string()))
+    }
+
+    async fn update(&self, call: McpSavedCall) -> Result<McpSavedCall, AppError> {
+        let result = sqlx::query(
+            r#"UPDATE mcp_saved_calls
+               SET folder_id = ?, name = ?, server_id = ?, tool_name = ?, params = ?, notes = ?,
+                   assertions = ?, position = ?, updated_at = CURRENT_TIMESTAMP
+               WHERE id = ?"#,
+        )
+        .bind(&call.folder_id)
+        .bind(&call.name)
+        .bind(&call.server_id)
+        .bind(&call.tool_name)
+        .bind(&call.params)
+        .bind(&call.notes)
+        .bind(assertions_column(&call)?)
+        .bind(call.position)
+        .bind(&call.id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Saved call {} not found", call.id)));
+        }
+
+        self.find_by_id(&call.id)
+            .await?
+            .ok_or_else(|| AppError::Database("Failed to update saved call".to_string()))
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), AppError> {
+        let result = sqlx::query("DELETE FROM mcp_saved_calls WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Saved call {} not found", id)));
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<McpSavedCall>, AppError> {
+        let row = sqlx::query_as::<_, McpSavedCallRow>(
+            "SELECT id, collection_id, folder_id, name, server_id, tool_name, params, notes, assertions, position, created_at, updated_at FROM mcp_saved_calls WHERE id = ?"
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
+    async fn list(&self, collection_id: &str) -> Result<Vec<McpSavedCall>, AppError> {
+        let rows = sqlx::query_as::<_, McpSavedCallRow>(
+            "SELECT id, collection_id, folder_id, name, server_id, tool_name, params, notes, assertions, position, created_at, updated_at FROM mcp_saved_calls WHERE collection_id = ? ORDER BY position ASC, created_at ASC"
+        )
+        .bind(collection_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+}
+
+fn assertions_column(call: &McpSavedCall) -> Result<String, AppError> {
+    serde_json::to_string(&call.assertions)
+        .map_err(|e| AppError::Unknown(format!("Failed to serialize assertions: {}", e)))
+}
+
+#[derive(sqlx::FromRow)]
+struct McpSavedCallRow {
+    id: String,
+    collection_id: String,
+    folder_id: Option<String>,
+    name: String,
+    server_id: Option<String>,
+    tool_name: String,
+    params: Option<String>,
+    notes: Option<String>,
+    assertions: String,
+    position: i64,
+    created_at: String,
+    updated_at: String,
+}
+
+impl From<McpSavedCallRow> for McpSavedCall {
+    fn from(row: McpSavedCallRow) -> Self {
+        McpSavedCall {
+            id: row.id,
+            collection_id: row.collection_id,
+            folder_id: row.folder_id,
+            name: row.name,
+            server_id: row.server_id,
+            tool_name: row.tool_name,
+            params: row.params,
+            notes: row.notes,
+            assertions: serde_json::from_str(&row.assertions).unwrap_or_default(),
+            position: row.position,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
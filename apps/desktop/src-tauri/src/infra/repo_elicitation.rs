@@ -0,0 +1,136 @@
+//! SQLite Repository implementation for elicitation rules.
+
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+use crate::domain::elicitation::{ElicitationRule, IElicitationRuleRepository};
+use crate::error::AppError;
+
+pub struct SqliteElicitationRuleRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteElicitationRuleRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IElicitationRuleRepository for SqliteElicitationRuleRepository {
+    async fn create(&self, rule: ElicitationRule) -> Result<ElicitationRule, AppError> {
+        sqlx::query(
+            r#"INSERT INTO elicitation_rules (id, name, server_id, match_type, pattern, action, content, enabled, priority, created_at, updated_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)"#,
+        )
+        .bind(&rule.id)
+        .bind(&rule.name)
+        .bind(&rule.server_id)
+        .bind(rule.match_type.to_string())
+        .bind(&rule.pattern)
+        .bind(rule.action.to_string())
+        .bind(&rule.content)
+        .bind(rule.enabled)
+        .bind(rule.priority)
+        .execute(&self.pool)
+        .await?;
+
+        self.find_by_id(&rule.id)
+            .await?
+            .ok_or_else(|| AppError::Database("Failed to create elicitation rule".to_string()))
+    }
+
+    async fn update(&self, rule: ElicitationRule) -> Result<ElicitationRule, AppError> {
+        let result = sqlx::query(
+            r#"UPDATE elicitation_rules
+               SET name = ?, server_id = ?, match_type = ?, pattern = ?, action = ?, content = ?,
+                   enabled = ?, priority = ?, updated_at = CURRENT_TIMESTAMP
+               WHERE id = ?"#,
+        )
+        .bind(&rule.name)
+        .bind(&rule.server_id)
+        .bind(rule.match_type.to_string())
+        .bind(&rule.pattern)
+        .bind(rule.action.to_string())
+        .bind(&rule.content)
+        .bind(rule.enabled)
+        .bind(rule.priority)
+        .bind(&rule.id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Elicitation rule {} not found", rule.id)));
+        }
+
+        self.find_by_id(&rule.id)
+            .await?
+            .ok_or_else(|| AppError::Database("Failed to update elicitation rule".to_string()))
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), AppError> {
+        let result = sqlx::query("DELETE FROM elicitation_rules WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Elicitation rule {} not found", id)));
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<ElicitationRule>, AppError> {
+        let row = sqlx::query_as::<_, ElicitationRuleRow>(
+            "SELECT id, name, server_id, match_type, pattern, action, content, enabled, priority, created_at, updated_at FROM elicitation_rules WHERE id = ?"
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
+    async fn list(&self) -> Result<Vec<ElicitationRule>, AppError> {
+        let rows = sqlx::query_as::<_, ElicitationRuleRow>(
+            "SELECT id, name, server_id, match_type, pattern, action, content, enabled, priority, created_at, updated_at FROM elicitation_rules ORDER BY priority ASC, created_at ASC"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct ElicitationRuleRow {
+    id: String,
+    name: String,
+    server_id: Option<String>,
+    match_type: String,
+    pattern: String,
+    action: String,
+    content: Option<String>,
+    enabled: bool,
+    priority: i64,
+    created_at: String,
+    updated_at: String,
+}
+
+impl From<ElicitationRuleRow> for ElicitationRule {
+    fn from(row: ElicitationRuleRow) -> Self {
+        ElicitationRule {
+            id: row.id,
+            name: row.name,
+            server_id: row.server_id,
+            match_type: row.match_type.into(),
+            pattern: row.pattern,
+            action: row.action.into(),
+            content: row.content,
+            enabled: row.enabled,
+            priority: row.priority,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
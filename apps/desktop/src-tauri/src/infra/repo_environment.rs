@@ -0,0 +1,116 @@
+//! SQLite Repository implementation for environments.
+
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+use crate::domain::environment::{IMcpEnvironmentRepository, McpEnvironment};
+use crate::error::AppError;
+
+pub struct SqliteMcpEnvironmentRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMcpEnvironmentRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IMcpEnvironmentRepository for SqliteMcpEnvironmentRepository {
+    async fn create(&self, environment: McpEnvironment) -> Result<McpEnvironment, AppError> {
+        sqlx::query(
+            r#"INSERT INTO mcp_environments (id, name, variables, created_at, updated_at)
+               VALUES (?, ?, ?, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)"#,
+        )
+        .bind(&environment.id)
+        .bind(&environment.name)
+        .bind(variables_column(&environment)?)
+        .execute(&self.pool)
+        .await?;
+
+        self.find_by_id(&environment.id)
+            .await?
+            .ok_or_else(|| AppError::Database("Failed to create environment".to_string()))
+    }
+
+    async fn update(&self, environment: McpEnvironment) -> Result<McpEnvironment, AppError> {
+        let result = sqlx::query(
+            r#"UPDATE mcp_environments
+               SET name = ?, variables = ?, updated_at = CURRENT_TIMESTAMP
+               WHERE id = ?"#,
+        )
+        .bind(&environment.name)
+        .bind(variables_column(&environment)?)
+        .bind(&environment.id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Environment {} not found", environment.id)));
+        }
+
+        self.find_by_id(&environment.id)
+            .await?
+            .ok_or_else(|| AppError::Database("Failed to update environment".to_string()))
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), AppError> {
+        let result = sqlx::query("DELETE FROM mcp_environments WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Environment {} not found", id)));
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<McpEnvironment>, AppError> {
+        let row = sqlx::query_as::<_, McpEnvironmentRow>(
+            "SELECT id, name, variables, created_at, updated_at FROM mcp_environments WHERE id = ?",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
+    async fn list(&self) -> Result<Vec<McpEnvironment>, AppError> {
+        let rows = sqlx::query_as::<_, McpEnvironmentRow>(
+            "SELECT id, name, variables, created_at, updated_at FROM mcp_environments ORDER BY name ASC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+}
+
+fn variables_column(environment: &McpEnvironment) -> Result<String, AppError> {
+    serde_json::to_string(&environment.variables)
+        .map_err(|e| AppError::Unknown(format!("Failed to serialize variables: {}", e)))
+}
+
+#[derive(sqlx::FromRow)]
+struct McpEnvironmentRow {
+    id: String,
+    name: String,
+    variables: String,
+    created_at: String,
+    updated_at: String,
+}
+
+impl From<McpEnvironmentRow> for McpEnvironment {
+    fn from(row: McpEnvironmentRow) -> Self {
+        McpEnvironment {
+            id: row.id,
+            name: row.name,
+            variables: serde_json::from_str(&row.variables).unwrap_or_default(),
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
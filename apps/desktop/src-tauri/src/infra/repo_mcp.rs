@@ -5,7 +5,9 @@ use sqlx::SqlitePool;
 
 use crate::domain::mcp::{
     HttpReceivedMessage, IHttpReceivedMessageRepository, IMcpCallHistoryRepository,
-    IMcpServerRepository, McpCallHistory, McpServer, McpServerStatus,
+    IMcpHeartbeatRepository, IMcpPromptHistoryRepository, IMcpRawRpcHistoryRepository,
+    IMcpServerRepository, McpCallHistory, McpHeartbeat, McpPromptHistory, McpRawRpcHistory,
+    McpServer, McpServerStatus,
 };
 use crate::error::AppError;
 
@@ -25,13 +27,27 @@ impl SqliteMcpServerRepository {
 impl IMcpServerRepository for SqliteMcpServerRepository {
     async fn create(&self, server: McpServer) -> Result<McpServer, AppError> {
         sqlx::query(
-            r#"INSERT INTO mcp_servers (id, name, url, server_type, created_at, updated_at)
-               VALUES (?, ?, ?, ?, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)"#,
+            r#"INSERT INTO mcp_servers (id, name, url, server_type, command, args, env, cwd, roots, headers, auth,
+                   heartbeat_strategy, heartbeat_interval_secs, heartbeat_max_failures, reconnect_policy, standalone_sse, sse_reconnect, created_at, updated_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)"#,
         )
         .bind(&server.id)
         .bind(&server.name)
         .bind(&server.url)
         .bind(server.server_type.to_string())
+        .bind(&server.command)
+        .bind(to_json_column(&server.args))
+        .bind(to_json_column(&server.env))
+        .bind(&server.cwd)
+        .bind(to_json_column(&server.roots))
+        .bind(to_json_column(&server.headers))
+        .bind(to_json_column(&server.auth))
+        .bind(server.heartbeat_strategy.to_string())
+        .bind(server.heartbeat_interval_secs)
+        .bind(server.heartbeat_max_failures)
+        .bind(to_json_column(&server.reconnect_policy))
+        .bind(server.standalone_sse)
+        .bind(server.sse_reconnect)
         .execute(&self.pool)
         .await?;
 
@@ -43,12 +59,27 @@ impl IMcpServerRepository for SqliteMcpServerRepository {
     async fn update(&self, server: McpServer) -> Result<McpServer, AppError> {
         let result = sqlx::query(
             r#"UPDATE mcp_servers
-               SET name = ?, url = ?, server_type = ?, updated_at = CURRENT_TIMESTAMP
+               SET name = ?, url = ?, server_type = ?, command = ?, args = ?, env = ?, cwd = ?,
+                   roots = ?, headers = ?, auth = ?, heartbeat_strategy = ?, heartbeat_interval_secs = ?,
+                   heartbeat_max_failures = ?, reconnect_policy = ?, standalone_sse = ?, sse_reconnect = ?, updated_at = CURRENT_TIMESTAMP
                WHERE id = ?"#,
         )
         .bind(&server.name)
         .bind(&server.url)
         .bind(server.server_type.to_string())
+        .bind(&server.command)
+        .bind(to_json_column(&server.args))
+        .bind(to_json_column(&server.env))
+        .bind(&server.cwd)
+        .bind(to_json_column(&server.roots))
+        .bind(to_json_column(&server.headers))
+        .bind(to_json_column(&server.auth))
+        .bind(server.heartbeat_strategy.to_string())
+        .bind(server.heartbeat_interval_secs)
+        .bind(server.heartbeat_max_failures)
+        .bind(to_json_column(&server.reconnect_policy))
+        .bind(server.standalone_sse)
+        .bind(server.sse_reconnect)
         .bind(&server.id)
         .execute(&self.pool)
         .await?;
@@ -76,7 +107,7 @@ impl IMcpServerRepository for SqliteMcpServerRepository {
 
     async fn find_by_id(&self, id: &str) -> Result<Option<McpServer>, AppError> {
         let row = sqlx::query_as::<_, McpServerRow>(
-            "SELECT id, name, url, server_type, created_at, updated_at FROM mcp_servers WHERE id = ?"
+            "SELECT id, name, url, server_type, command, args, env, cwd, roots, headers, auth, heartbeat_strategy, heartbeat_interval_secs, heartbeat_max_failures, reconnect_policy, standalone_sse, sse_reconnect, created_at, updated_at FROM mcp_servers WHERE id = ?"
         )
         .bind(id)
         .fetch_optional(&self.pool)
@@ -87,7 +118,7 @@ impl IMcpServerRepository for SqliteMcpServerRepository {
 
     async fn list(&self) -> Result<Vec<McpServer>, AppError> {
         let rows = sqlx::query_as::<_, McpServerRow>(
-            "SELECT id, name, url, server_type, created_at, updated_at FROM mcp_servers ORDER BY created_at DESC"
+            "SELECT id, name, url, server_type, command, args, env, cwd, roots, headers, auth, heartbeat_strategy, heartbeat_interval_secs, heartbeat_max_failures, reconnect_policy, standalone_sse, sse_reconnect, created_at, updated_at FROM mcp_servers ORDER BY created_at DESC"
         )
         .fetch_all(&self.pool)
         .await?;
@@ -102,6 +133,19 @@ struct McpServerRow {
     name: String,
     url: String,
     server_type: String,
+    command: Option<String>,
+    args: Option<String>,
+    env: Option<String>,
+    cwd: Option<String>,
+    roots: Option<String>,
+    headers: Option<String>,
+    auth: Option<String>,
+    heartbeat_strategy: String,
+    heartbeat_interval_secs: Option<i64>,
+    heartbeat_max_failures: Option<i64>,
+    reconnect_policy: Option<String>,
+    standalone_sse: bool,
+    sse_reconnect: bool,
     created_at: String,
     updated_at: String,
 }
@@ -113,6 +157,19 @@ impl From<McpServerRow> for McpServer {
             name: row.name,
             url: row.url,
             server_type: row.server_type.into(),
+            command: row.command,
+            args: from_json_column(row.args.as_deref()),
+            env: from_json_column(row.env.as_deref()),
+            cwd: row.cwd,
+            roots: from_json_column(row.roots.as_deref()),
+            headers: from_json_column(row.headers.as_deref()),
+            auth: from_json_column(row.auth.as_deref()),
+            heartbeat_strategy: row.heartbeat_strategy.into(),
+            heartbeat_interval_secs: row.heartbeat_interval_secs,
+            heartbeat_max_failures: row.heartbeat_max_failures,
+            reconnect_policy: from_json_column(row.reconnect_policy.as_deref()),
+            standalone_sse: row.standalone_sse,
+            sse_reconnect: row.sse_reconnect,
             status: McpServerStatus::Disconnected, // Default to disconnected
             last_error: None,
             created_at: row.created_at,
@@ -121,6 +178,16 @@ impl From<McpServerRow> for McpServer {
     }
 }
 
+/// Serialize a collection into a JSON TEXT column (NULL when empty)
+fn to_json_column<T: serde::Serialize>(value: &T) -> Option<String> {
+    serde_json::to_string(value).ok().filter(|s| s != "[]" && s != "{}")
+}
+
+/// Parse a JSON TEXT column, falling back to the default value when NULL or malformed
+fn from_json_column<T: serde::de::DeserializeOwned + Default>(value: Option<&str>) -> T {
+    value.and_then(|v| serde_json::from_str(v).ok()).unwrap_or_default()
+}
+
 // MCP Tool Repository removed - tools are now cached in memory by McpClientManager
 // Tools are retrieved via tools/list RPC call and should listen to ToolsListChanged notifications
 
@@ -140,8 +207,8 @@ impl SqliteMcpCallHistoryRepository {
 impl IMcpCallHistoryRepository for SqliteMcpCallHistoryRepository {
     async fn create(&self, history: McpCallHistory) -> Result<McpCallHistory, AppError> {
         sqlx::query(
-            r#"INSERT INTO mcp_call_history (id, server_id, tool_name, input_params, output_result, status, error_message, duration_ms, created_at)
-               VALUES (?, ?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP)"#
+            r#"INSERT INTO mcp_call_history (id, server_id, tool_name, input_params, output_result, status, error_message, duration_ms, replayed_from, input_template, created_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP)"#
         )
         .bind(&history.id)
         .bind(&history.server_id)
@@ -151,12 +218,26 @@ impl IMcpCallHistoryRepository for SqliteMcpCallHistoryRepository {
         .bind(&history.status)
         .bind(&history.error_message)
         .bind(history.duration_ms)
+        .bind(&history.replayed_from)
+        .bind(&history.input_template)
         .execute(&self.pool)
         .await?;
 
         Ok(history)
     }
 
+    async fn find_by_id(&self, id: &str) -> Result<Option<McpCallHistory>, AppError> {
+        let row = sqlx::query_as::<_, McpCallHistoryRow>(
+            r#"SELECT id, server_id, tool_name, input_params, output_result, status, error_message, duration_ms, replayed_from, input_template, created_at
+               FROM mcp_call_history WHERE id = ?"#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
     async fn list(
         &self,
         server_id: Option<&str>,
@@ -166,7 +247,7 @@ impl IMcpCallHistoryRepository for SqliteMcpCallHistoryRepository {
 
         let rows = if let Some(sid) = server_id {
             sqlx::query_as::<_, McpCallHistoryRow>(
-                r#"SELECT id, server_id, tool_name, input_params, output_result, status, error_message, duration_ms, created_at
+                r#"SELECT id, server_id, tool_name, input_params, output_result, status, error_message, duration_ms, replayed_from, input_template, created_at
                    FROM mcp_call_history WHERE server_id = ? ORDER BY created_at DESC LIMIT ?"#
             )
             .bind(sid)
@@ -175,7 +256,7 @@ impl IMcpCallHistoryRepository for SqliteMcpCallHistoryRepository {
             .await?
         } else {
             sqlx::query_as::<_, McpCallHistoryRow>(
-                r#"SELECT id, server_id, tool_name, input_params, output_result, status, error_message, duration_ms, created_at
+                r#"SELECT id, server_id, tool_name, input_params, output_result, status, error_message, duration_ms, replayed_from, input_template, created_at
                    FROM mcp_call_history ORDER BY created_at DESC LIMIT ?"#
             )
             .bind(limit)
@@ -209,6 +290,8 @@ struct McpCallHistoryRow {
     status: String,
     error_message: Option<String>,
     duration_ms: Option<i64>,
+    replayed_from: Option<String>,
+    input_template: Option<String>,
     created_at: String,
 }
 
@@ -224,6 +307,306 @@ impl From<McpCallHistoryRow> for McpCallHistory {
             error_message: row.error_message,
             duration_ms: row.duration_ms,
             created_at: row.created_at,
+            replayed_from: row.replayed_from,
+            input_template: row.input_template,
+        }
+    }
+}
+
+// ============ MCP Prompt History Repository ============
+
+pub struct SqliteMcpPromptHistoryRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMcpPromptHistoryRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IMcpPromptHistoryRepository for SqliteMcpPromptHistoryRepository {
+    async fn create(&self, history: McpPromptHistory) -> Result<McpPromptHistory, AppError> {
+        sqlx::query(
+            r#"INSERT INTO mcp_prompt_history (id, server_id, prompt_name, arguments, output_result, status, error_message, duration_ms, created_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP)"#
+        )
+        .bind(&history.id)
+        .bind(&history.server_id)
+        .bind(&history.prompt_name)
+        .bind(&history.arguments)
+        .bind(&history.output_result)
+        .bind(&history.status)
+        .bind(&history.error_message)
+        .bind(history.duration_ms)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(history)
+    }
+
+    async fn list(
+        &self,
+        server_id: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<Vec<McpPromptHistory>, AppError> {
+        let limit = limit.unwrap_or(100);
+
+        let rows = if let Some(sid) = server_id {
+            sqlx::query_as::<_, McpPromptHistoryRow>(
+                r#"SELECT id, server_id, prompt_name, arguments, output_result, status, error_message, duration_ms, created_at
+                   FROM mcp_prompt_history WHERE server_id = ? ORDER BY created_at DESC LIMIT ?"#
+            )
+            .bind(sid)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?
+        } else {
+            sqlx::query_as::<_, McpPromptHistoryRow>(
+                r#"SELECT id, server_id, prompt_name, arguments, output_result, status, error_message, duration_ms, created_at
+                   FROM mcp_prompt_history ORDER BY created_at DESC LIMIT ?"#
+            )
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?
+        };
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn clear(&self, server_id: Option<&str>) -> Result<(), AppError> {
+        if let Some(sid) = server_id {
+            sqlx::query("DELETE FROM mcp_prompt_history WHERE server_id = ?")
+                .bind(sid)
+                .execute(&self.pool)
+                .await?;
+        } else {
+            sqlx::query("DELETE FROM mcp_prompt_history").execute(&self.pool).await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct McpPromptHistoryRow {
+    id: String,
+    server_id: String,
+    prompt_name: String,
+    arguments: Option<String>,
+    output_result: Option<String>,
+    status: String,
+    error_message: Option<String>,
+    duration_ms: Option<i64>,
+    created_at: String,
+}
+
+impl From<McpPromptHistoryRow> for McpPromptHistory {
+    fn from(row: McpPromptHistoryRow) -> Self {
+        McpPromptHistory {
+            id: row.id,
+            server_id: row.server_id,
+            prompt_name: row.prompt_name,
+            arguments: row.arguments,
+            output_result: row.output_result,
+            status: row.status,
+            error_message: row.error_message,
+            duration_ms: row.duration_ms,
+            created_at: row.created_at,
+        }
+    }
+}
+
+// ============ MCP Raw JSON-RPC History Repository ============
+
+pub struct SqliteMcpRawRpcHistoryRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMcpRawRpcHistoryRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IMcpRawRpcHistoryRepository for SqliteMcpRawRpcHistoryRepository {
+    async fn create(&self, history: McpRawRpcHistory) -> Result<McpRawRpcHistory, AppError> {
+        sqlx::query(
+            r#"INSERT INTO mcp_raw_rpc_history (id, server_id, kind, method, request, response, status, error_message, duration_ms, created_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP)"#
+        )
+        .bind(&history.id)
+        .bind(&history.server_id)
+        .bind(&history.kind)
+        .bind(&history.method)
+        .bind(&history.request)
+        .bind(&history.response)
+        .bind(&history.status)
+        .bind(&history.error_message)
+        .bind(history.duration_ms)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(history)
+    }
+
+    async fn list(
+        &self,
+        server_id: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<Vec<McpRawRpcHistory>, AppError> {
+        let limit = limit.unwrap_or(100);
+
+        let rows = if let Some(sid) = server_id {
+            sqlx::query_as::<_, McpRawRpcHistoryRow>(
+                r#"SELECT id, server_id, kind, method, request, response, status, error_message, duration_ms, created_at
+                   FROM mcp_raw_rpc_history WHERE server_id = ? ORDER BY created_at DESC LIMIT ?"#
+            )
+            .bind(sid)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?
+        } else {
+            sqlx::query_as::<_, McpRawRpcHistoryRow>(
+                r#"SELECT id, server_id, kind, method, request, response, status, error_message, duration_ms, created_at
+                   FROM mcp_raw_rpc_history ORDER BY created_at DESC LIMIT ?"#
+            )
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?
+        };
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn clear(&self, server_id: Option<&str>) -> Result<(), AppError> {
+        if let Some(sid) = server_id {
+            sqlx::query("DELETE FROM mcp_raw_rpc_history WHERE server_id = ?")
+                .bind(sid)
+                .execute(&self.pool)
+                .await?;
+        } else {
+            sqlx::query("DELETE FROM mcp_raw_rpc_history").execute(&self.pool).await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct McpRawRpcHistoryRow {
+    id: String,
+    server_id: String,
+    kind: String,
+    method: Option<String>,
+    request: String,
+    response: Option<String>,
+    status: String,
+    error_message: Option<String>,
+    duration_ms: Option<i64>,
+    created_at: String,
+}
+
+impl From<McpRawRpcHistoryRow> for McpRawRpcHistory {
+    fn from(row: McpRawRpcHistoryRow) -> Self {
+        McpRawRpcHistory {
+            id: row.id,
+            server_id: row.server_id,
+            kind: row.kind,
+            method: row.method,
+            request: row.request,
+            response: row.response,
+            status: row.status,
+            error_message: row.error_message,
+            duration_ms: row.duration_ms,
+            created_at: row.created_at,
+        }
+    }
+}
+
+// ============ MCP Heartbeat Repository ============
+
+/// Heartbeats kept per server; older ones are pruned on insert
+const MAX_HEARTBEATS_PER_SERVER: i64 = 1000;
+
+pub struct SqliteMcpHeartbeatRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMcpHeartbeatRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IMcpHeartbeatRepository for SqliteMcpHeartbeatRepository {
+    async fn create(&self, heartbeat: McpHeartbeat) -> Result<McpHeartbeat, AppError> {
+        sqlx::query(
+            r#"INSERT INTO mcp_heartbeats (id, server_id, strategy, success, rtt_ms, error_message, created_at)
+               VALUES (?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP)"#,
+        )
+        .bind(&heartbeat.id)
+        .bind(&heartbeat.server_id)
+        .bind(heartbeat.strategy.to_string())
+        .bind(heartbeat.success)
+        .bind(heartbeat.rtt_ms)
+        .bind(&heartbeat.error_message)
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"DELETE FROM mcp_heartbeats WHERE server_id = ? AND rowid NOT IN
+               (SELECT rowid FROM mcp_heartbeats WHERE server_id = ? ORDER BY rowid DESC LIMIT ?)"#,
+        )
+        .bind(&heartbeat.server_id)
+        .bind(&heartbeat.server_id)
+        .bind(MAX_HEARTBEATS_PER_SERVER)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(heartbeat)
+    }
+
+    async fn list(
+        &self,
+        server_id: &str,
+        limit: Option<i64>,
+    ) -> Result<Vec<McpHeartbeat>, AppError> {
+        let rows = sqlx::query_as::<_, McpHeartbeatRow>(
+            r#"SELECT id, server_id, strategy, success, rtt_ms, error_message, created_at
+               FROM mcp_heartbeats WHERE server_id = ? ORDER BY rowid DESC LIMIT ?"#,
+        )
+        .bind(server_id)
+        .bind(limit.unwrap_or(100))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct McpHeartbeatRow {
+    id: String,
+    server_id: String,
+    strategy: String,
+    success: bool,
+    rtt_ms: i64,
+    error_message: Option<String>,
+    created_at: String,
+}
+
+impl From<McpHeartbeatRow> for McpHeartbeat {
+    fn from(row: McpHeartbeatRow) -> Self {
+        McpHeartbeat {
+            id: row.id,
+            server_id: row.server_id,
+            strategy: row.strategy.into(),
+            success: row.success,
+            rtt_ms: row.rtt_ms,
+            error_message: row.error_message,
+            created_at: row.created_at,
         }
     }
 }
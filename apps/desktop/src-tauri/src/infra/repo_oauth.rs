@@ -0,0 +1,123 @@
+//! SQLite Repository implementation for OAuth credentials.
+
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+use crate::domain::oauth::{IMcpOAuthRepository, McpOAuthCredentials};
+use crate::error::AppError;
+
+pub struct SqliteMcpOAuthRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMcpOAuthRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IMcpOAuthRepository for SqliteMcpOAuthRepository {
+    async fn save(
+        &self,
+        credentials: McpOAuthCredentials,
+    ) -> Result<McpOAuthCredentials, AppError> {
+        sqlx::query(
+            r#"INSERT INTO mcp_oauth_credentials (server_id, authorization_server, token_endpoint, resource, client_id,
+                   client_secret, redirect_uri, access_token, refresh_token, token_type, scope, expires_at,
+                   created_at, updated_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+               ON CONFLICT(server_id) DO UPDATE SET
+                   authorization_server = excluded.authorization_server,
+                   token_endpoint = excluded.token_endpoint,
+                   resource = excluded.resource,
+                   client_id = excluded.client_id,
+                   client_secret = excluded.client_secret,
+                   redirect_uri = excluded.redirect_uri,
+                   access_token = excluded.access_token,
+                   refresh_token = excluded.refresh_token,
+                   token_type = excluded.token_type,
+                   scope = excluded.scope,
+                   expires_at = excluded.expires_at,
+                   updated_at = CURRENT_TIMESTAMP"#,
+        )
+        .bind(&credentials.server_id)
+        .bind(&credentials.authorization_server)
+        .bind(&credentials.token_endpoint)
+        .bind(&credentials.resource)
+        .bind(&credentials.client_id)
+        .bind(&credentials.client_secret)
+        .bind(&credentials.redirect_uri)
+        .bind(&credentials.access_token)
+        .bind(&credentials.refresh_token)
+        .bind(&credentials.token_type)
+        .bind(&credentials.scope)
+        .bind(credentials.expires_at)
+        .execute(&self.pool)
+        .await?;
+
+        self.find(&credentials.server_id)
+            .await?
+            .ok_or_else(|| AppError::Database("Failed to save OAuth credentials".to_string()))
+    }
+
+    async fn find(&self, server_id: &str) -> Result<Option<McpOAuthCredentials>, AppError> {
+        let row = sqlx::query_as::<_, McpOAuthCredentialsRow>(
+            r#"SELECT server_id, authorization_server, token_endpoint, resource, client_id, client_secret,
+                   redirect_uri, access_token, refresh_token, token_type, scope, expires_at, created_at, updated_at
+               FROM mcp_oauth_credentials WHERE server_id = ?"#,
+        )
+        .bind(server_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
+    async fn delete(&self, server_id: &str) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM mcp_oauth_credentials WHERE server_id = ?")
+            .bind(server_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct McpOAuthCredentialsRow {
+    server_id: String,
+    authorization_server: String,
+    token_endpoint: String,
+    resource: String,
+    client_id: String,
+    client_secret: Option<String>,
+    redirect_uri: String,
+    access_token: Option<String>,
+    refresh_token: Option<String>,
+    token_type: Option<String>,
+    scope: Option<String>,
+    expires_at: Option<i64>,
+    created_at: String,
+    updated_at: String,
+}
+
+impl From<McpOAuthCredentialsRow> for McpOAuthCredentials {
+    fn from(row: McpOAuthCredentialsRow) -> Self {
+        McpOAuthCredentials {
+            server_id: row.server_id,
+            authorization_server: row.authorization_server,
+            token_endpoint: row.token_endpoint,
+            resource: row.resource,
+            client_id: row.client_id,
+            client_secret: row.client_secret,
+            redirect_uri: row.redirect_uri,
+            access_token: row.access_token,
+            refresh_token: row.refresh_token,
+            token_type: row.token_type,
+            scope: row.scope,
+            expires_at: row.expires_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
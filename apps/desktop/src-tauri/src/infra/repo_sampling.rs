@@ -0,0 +1,274 @@
+//! SQLite Repository implementations for sampling rules and exchanges.
+
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+use crate::domain::sampling::{
+    ISamplingExchangeRepository, ISamplingRuleRepository, SamplingExchange, SamplingRule,
+};
+use crate::error::AppError;
+
+// ============ Sampling Rule Repository ============
+
+pub struct SqliteSamplingRuleRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteSamplingRuleRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ISamplingRuleRepository for SqliteSamplingRuleRepository {
+    async fn create(&self, rule: SamplingRule) -> Result<SamplingRule, AppError> {
+        sqlx::query(
+            r#"INSERT INTO sampling_rules (id, name, server_id, match_type, pattern, response_text, model, stop_reason, enabled, priority, created_at, updated_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)"#,
+        )
+        .bind(&rule.id)
+        .bind(&rule.name)
+        .bind(&rule.server_id)
+        .bind(rule.match_type.to_string())
+        .bind(&rule.pattern)
+        .bind(&rule.response_text)
+        .bind(&rule.model)
+        .bind(&rule.stop_reason)
+        .bind(rule.enabled)
+        .bind(rule.priority)
+        .execute(&self.pool)
+        .await?;
+
+        self.find_by_id(&rule.id)
+            .await?
+            .ok_or_else(|| AppError::Database("Failed to create sampling rule".to_string()))
+    }
+
+    async fn update(&self, rule: SamplingRule) -> Result<SamplingRule, AppError> {
+        let result = sqlx::query(
+            r#"UPDATE sampling_rules
+               SET name = ?, server_id = ?, match_type = ?, pattern = ?, response_text = ?, model = ?,
+                   stop_reason = ?, enabled = ?, priority = ?, updated_at = CURRENT_TIMESTAMP
+               WHERE id = ?"#,
+        )
+        .bind(&rule.name)
+        .bind(&rule.server_id)
+        .bind(rule.match_type.to_string())
+        .bind(&rule.pattern)
+        .bind(&rule.response_text)
+        .bind(&rule.model)
+        .bind(&rule.stop_reason)
+        .bind(rule.enabled)
+        .bind(rule.priority)
+        .bind(&rule.id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Sampling rule {} not found", rule.id)));
+        }
+
+        self.find_by_id(&rule.id)
+            .await?
+            .ok_or_else(|| AppError::Database("Failed to update sampling rule".to_string()))
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), AppError> {
+        let result = sqlx::query("DELETE FROM sampling_rules WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Sampling rule {} not found", id)));
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<SamplingRule>, AppError> {
+        let row = sqlx::query_as::<_, SamplingRuleRow>(
+            "SELECT id, name, server_id, match_type, pattern, response_text, model, stop_reason, enabled, priority, created_at, updated_at FROM sampling_rules WHERE id = ?"
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
+    async fn list(&self) -> Result<Vec<SamplingRule>, AppError> {
+        let rows = sqlx::query_as::<_, SamplingRuleRow>(
+            "SELECT id, name, server_id, match_type, pattern, response_text, model, stop_reason, enabled, priority, created_at, updated_at FROM sampling_rules ORDER BY priority ASC, created_at ASC"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct SamplingRuleRow {
+    id: String,
+    name: String,
+    server_id: Option<String>,
+    match_type: String,
+    pattern: String,
+    response_text: String,
+    model: String,
+    stop_reason: Option<String>,
+    enabled: bool,
+    priority: i64,
+    created_at: String,
+    updated_at: String,
+}
+
+impl From<SamplingRuleRow> for SamplingRule {
+    fn from(row: SamplingRuleRow) -> Self {
+        SamplingRule {
+            id: row.id,
+            name: row.name,
+            server_id: row.server_id,
+            match_type: row.match_type.into(),
+            pattern: row.pattern,
+            response_text: row.response_text,
+            model: row.model,
+            stop_reason: row.stop_reason,
+            enabled: row.enabled,
+            priority: row.priority,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+// ============ Sampling Exchange Repository ============
+
+pub struct SqliteSamplingExchangeRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteSamplingExchangeRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ISamplingExchangeRepository for SqliteSamplingExchangeRepository {
+    async fn create(&self, exchange: SamplingExchange) -> Result<SamplingExchange, AppError> {
+        sqlx::query(
+            r#"INSERT INTO sampling_exchanges (id, server_id, request, response, status, source, rule_id, error_message, duration_ms, created_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP)"#,
+        )
+        .bind(&exchange.id)
+        .bind(&exchange.server_id)
+        .bind(&exchange.request)
+        .bind(&exchange.response)
+        .bind(&exchange.status)
+        .bind(&exchange.source)
+        .bind(&exchange.rule_id)
+        .bind(&exchange.error_message)
+        .bind(exchange.duration_ms)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(exchange)
+    }
+
+    async fn update(&self, exchange: SamplingExchange) -> Result<SamplingExchange, AppError> {
+        let result = sqlx::query(
+            r#"UPDATE sampling_exchanges
+               SET response = ?, status = ?, source = ?, rule_id = ?, error_message = ?, duration_ms = ?
+               WHERE id = ?"#,
+        )
+        .bind(&exchange.response)
+        .bind(&exchange.status)
+        .bind(&exchange.source)
+        .bind(&exchange.rule_id)
+        .bind(&exchange.error_message)
+        .bind(exchange.duration_ms)
+        .bind(&exchange.id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Sampling exchange {} not found", exchange.id)));
+        }
+
+        Ok(exchange)
+    }
+
+    async fn list(
+        &self,
+        server_id: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<Vec<SamplingExchange>, AppError> {
+        let limit = limit.unwrap_or(100);
+
+        let rows = if let Some(sid) = server_id {
+            sqlx::query_as::<_, SamplingExchangeRow>(
+                r#"SELECT id, server_id, request, response, status, source, rule_id, error_message, duration_ms, created_at
+                   FROM sampling_exchanges WHERE server_id = ? ORDER BY created_at DESC LIMIT ?"#
+            )
+            .bind(sid)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?
+        } else {
+            sqlx::query_as::<_, SamplingExchangeRow>(
+                r#"SELECT id, server_id, request, response, status, source, rule_id, error_message, duration_ms, created_at
+                   FROM sampling_exchanges ORDER BY created_at DESC LIMIT ?"#
+            )
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?
+        };
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn clear(&self, server_id: Option<&str>) -> Result<(), AppError> {
+        if let Some(sid) = server_id {
+            sqlx::query("DELETE FROM sampling_exchanges WHERE server_id = ?")
+                .bind(sid)
+                .execute(&self.pool)
+                .await?;
+        } else {
+            sqlx::query("DELETE FROM sampling_exchanges").execute(&self.pool).await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct SamplingExchangeRow {
+    id: String,
+    server_id: String,
+    request: String,
+    response: Option<String>,
+    status: String,
+    source: Option<String>,
+    rule_id: Option<String>,
+    error_message: Option<String>,
+    duration_ms: Option<i64>,
+    created_at: String,
+}
+
+impl From<SamplingExchangeRow> for SamplingExchange {
+    fn from(row: SamplingExchangeRow) -> Self {
+        SamplingExchange {
+            id: row.id,
+            server_id: row.server_id,
+            request: row.request,
+            response: row.response,
+            status: row.status,
+            source: row.source,
+            rule_id: row.rule_id,
+            error_message: row.error_message,
+            duration_ms: row.duration_ms,
+            created_at: row.created_at,
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! SQLite Repository implementation for server log records.
+
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+use crate::domain::server_log::{IMcpServerLogRepository, McpLogLevel, McpServerLog};
+use crate::error::AppError;
+
+pub struct SqliteMcpServerLogRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMcpServerLogRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IMcpServerLogRepository for SqliteMcpServerLogRepository {
+    async fn create(&self, log: McpServerLog) -> Result<McpServerLog, AppError> {
+        sqlx::query(
+            r#"INSERT INTO mcp_server_logs (id, server_id, level, severity, logger, data, created_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?)"#,
+        )
+        .bind(&log.id)
+        .bind(&log.server_id)
+        .bind(log.level.to_string())
+        .bind(log.level.severity())
+        .bind(&log.logger)
+        .bind(&log.data)
+        .bind(&log.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(log)
+    }
+
+    async fn list(
+        &self,
+        server_id: Option<&str>,
+        min_level: Option<McpLogLevel>,
+        limit: Option<i64>,
+    ) -> Result<Vec<McpServerLog>, AppError> {
+        let limit = limit.unwrap_or(500);
+        let min_severity = min_level.unwrap_or(McpLogLevel::Debug).severity();
+
+        let rows = if let Some(sid) = server_id {
+            sqlx::query_as::<_, McpServerLogRow>(
+                r#"SELECT id, server_id, level, logger, data, created_at
+                   FROM mcp_server_logs WHERE server_id = ? AND severity >= ?
+                   ORDER BY created_at DESC, rowid DESC LIMIT ?"#,
+            )
+            .bind(sid)
+            .bind(min_severity)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?
+        } else {
+            sqlx::query_as::<_, McpServerLogRow>(
+                r#"SELECT id, server_id, level, logger, data, created_at
+                   FROM mcp_server_logs WHERE severity >= ?
+                   ORDER BY created_at DESC, rowid DESC LIMIT ?"#,
+            )
+            .bind(min_severity)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?
+        };
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn clear(&self, server_id: Option<&str>) -> Result<(), AppError> {
+        if let Some(sid) = server_id {
+            sqlx::query("DELETE FROM mcp_server_logs WHERE server_id = ?")
+                .bind(sid)
+                .execute(&self.pool)
+                .await?;
+        } else {
+            sqlx::query("DELETE FROM mcp_server_logs").execute(&self.pool).await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct McpServerLogRow {
+    id: String,
+    server_id: String,
+    level: String,
+    logger: Option<String>,
+    data: String,
+    created_at: String,
+}
+
+impl From<McpServerLogRow> for McpServerLog {
+    fn from(row: McpServerLogRow) -> Self {
+        McpServerLog {
+            id: row.id,
+            server_id: row.server_id,
+            level: row.level.into(),
+            logger: row.logger,
+            data: row.data,
+            created_at: row.created_at,
+        }
+    }
+}
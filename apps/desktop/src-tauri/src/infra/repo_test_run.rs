@@ -0,0 +1,222 @@
+//! SQLite Repository implementation for recorded test runs and their results.
+
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+use crate::domain::test_run::{IMcpTestRunRepository, McpTestResult, McpTestRun, McpTestRunDetail};
+use crate::error::AppError;
+
+pub struct SqliteMcpTestRunRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMcpTestRunRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IMcpTestRunRepository for SqliteMcpTestRunRepository {
+    async fn create(&self, detail: McpTestRunDetail) -> Result<McpTestRunDetail, AppError> {
+        let run = &detail.run;
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(
+            r#"INSERT INTO mcp_test_runs (id, collection_id, collection_name, folder_id, server_id, started_at, duration_ms, total, passed, failed, skipped, success, created_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP)"#,
+        )
+        .bind(&run.id)
+        .bind(&run.collection_id)
+        .bind(&run.collection_name)
+        .bind(&run.folder_id)
+        .bind(&run.server_id)
+        .bind(&run.started_at)
+        .bind(run.duration_ms)
+        .bind(run.total as i64)
+        .bind(run.passed as i64)
+        .bind(run.failed as i64)
+        .bind(run.skipped as i64)
+        .bind(run.success)
+        .execute(&mut *tx)
+        .await?;
+
+        for result in &detail.results {
+            sqlx::query(
+                r#"INSERT INTO mcp_test_results (id, run_id, saved_call_id, name, folder_path, server_id, tool_name, status, passed, error, history_id, duration_ms, assertions, position)
+                   VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+            )
+            .bind(&result.id)
+            .bind(&result.run_id)
+            .bind(&result.saved_call_id)
+            .bind(&result.name)
+            .bind(json_column(&result.folder_path)?)
+            .bind(&result.server_id)
+            .bind(&result.tool_name)
+            .bind(&result.status)
+            .bind(result.passed)
+            .bind(&result.error)
+            .bind(&result.history_id)
+            .bind(result.duration_ms)
+            .bind(json_column(&result.assertions)?)
+            .bind(result.position)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        self.find_by_id(&run.id)
+            .await?
+            .ok_or_else(|| AppError::Database("Failed to create test run".to_string()))
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), AppError> {
+        let result = sqlx::query("DELETE FROM mcp_test_runs WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Test run {} not found", id)));
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<McpTestRunDetail>, AppError> {
+        let row = sqlx::query_as::<_, McpTestRunRow>(
+            r#"SELECT id, collection_id, collection_name, folder_id, server_id, started_at, duration_ms, total, passed, failed, skipped, success, created_at
+               FROM mcp_test_runs WHERE id = ?"#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let results = sqlx::query_as::<_, McpTestResultRow>(
+            r#"SELECT id, run_id, saved_call_id, name, folder_path, server_id, tool_name, status, passed, error, history_id, duration_ms, assertions, position
+               FROM mcp_test_results WHERE run_id = ? ORDER BY position ASC"#,
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(Some(McpTestRunDetail {
+            run: row.into(),
+            results: results.into_iter().map(|r| r.into()).collect(),
+        }))
+    }
+
+    async fn list(
+        &self,
+        collection_id: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<Vec<McpTestRun>, AppError> {
+        let limit = limit.unwrap_or(100);
+
+        let rows = if let Some(cid) = collection_id {
+            sqlx::query_as::<_, McpTestRunRow>(
+                r#"SELECT id, collection_id, collection_name, folder_id, server_id, started_at, duration_ms, total, passed, failed, skipped, success, created_at
+                   FROM mcp_test_runs WHERE collection_id = ? ORDER BY created_at DESC, rowid DESC LIMIT ?"#,
+            )
+            .bind(cid)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?
+        } else {
+            sqlx::query_as::<_, McpTestRunRow>(
+                r#"SELECT id, collection_id, collection_name, folder_id, server_id, started_at, duration_ms, total, passed, failed, skipped, success, created_at
+                   FROM mcp_test_runs ORDER BY created_at DESC, rowid DESC LIMIT ?"#,
+            )
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?
+        };
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+}
+
+fn json_column<T: serde::Serialize>(value: &T) -> Result<String, AppError> {
+    serde_json::to_string(value)
+        .map_err(|e| AppError::Unknown(format!("Failed to serialize test result: {}", e)))
+}
+
+#[derive(sqlx::FromRow)]
+struct McpTestRunRow {
+    id: String,
+    collection_id: Option<String>,
+    collection_name: String,
+    folder_id: Option<String>,
+    server_id: Option<String>,
+    started_at: String,
+    duration_ms: i64,
+    total: i64,
+    passed: i64,
+    failed: i64,
+    skipped: i64,
+    success: bool,
+    created_at: String,
+}
+
+impl From<McpTestRunRow> for McpTestRun {
+    fn from(row: McpTestRunRow) -> Self {
+        McpTestRun {
+            id: row.id,
+            collection_id: row.collection_id,
+            collection_name: row.collection_name,
+            folder_id: row.folder_id,
+            server_id: row.server_id,
+            started_at: row.started_at,
+            duration_ms: row.duration_ms,
+            total: row.total as usize,
+            passed: row.passed as usize,
+            failed: row.failed as usize,
+            skipped: row.skipped as usize,
+            success: row.success,
+            created_at: row.created_at,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct McpTestResultRow {
+    id: String,
+    run_id: String,
+    saved_call_id: Option<String>,
+    name: String,
+    folder_path: String,
+    server_id: Option<String>,
+    tool_name: String,
+    status: String,
+    passed: bool,
+    error: Option<String>,
+    history_id: Option<String>,
+    duration_ms: i64,
+    assertions: String,
+    position: i64,
+}
+
+impl From<McpTestResultRow> for McpTestResult {
+    fn from(row: McpTestResultRow) -> Self {
+        McpTestResult {
+            id: row.id,
+            run_id: row.run_id,
+            saved_call_id: row.saved_call_id,
+            name: row.name,
+            folder_path: serde_json::from_str(&row.folder_path).unwrap_or_default(),
+            server_id: row.server_id,
+            tool_name: row.tool_name,
+            status: row.status,
+            passed: row.passed,
+            error: row.error,
+            history_id: row.history_id,
+            duration_ms: row.duration_ms,
+            assertions: serde_json::from_str(&row.assertions).unwrap_or_default(),
+            position: row.position,
+        }
+    }
+}
@@ -0,0 +1,250 @@
+//! SQLite Repository implementations for recorded traffic frames and imported transcripts.
+
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+use crate::domain::traffic::{
+    IMcpTrafficRepository, IMcpTranscriptRepository, McpTrafficDirection, McpTrafficFrame,
+    McpTranscript,
+};
+use crate::error::AppError;
+
+/// Frames kept per server; older ones go when the recorder prunes
+const MAX_FRAMES_PER_SERVER: i64 = 5000;
+
+pub struct SqliteMcpTrafficRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMcpTrafficRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IMcpTrafficRepository for SqliteMcpTrafficRepository {
+    async fn create(&self, frame: McpTrafficFrame) -> Result<McpTrafficFrame, AppError> {
+        sqlx::query(
+            r#"INSERT INTO mcp_traffic (id, server_id, connection_id, transport, direction, kind, method, correlation_id, frame, size_bytes, created_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+        )
+        .bind(&frame.id)
+        .bind(&frame.server_id)
+        .bind(&frame.connection_id)
+        .bind(frame.transport.to_string())
+        .bind(frame.direction.to_string())
+        .bind(&frame.kind)
+        .bind(&frame.method)
+        .bind(&frame.correlation_id)
+        .bind(&frame.frame)
+        .bind(frame.size_bytes)
+        .bind(&frame.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(frame)
+    }
+
+    async fn list(
+        &self,
+        server_id: Option<&str>,
+        connection_id: Option<&str>,
+        direction: Option<McpTrafficDirection>,
+        correlation_id: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<Vec<McpTrafficFrame>, AppError> {
+        let direction = direction.map(|d| d.to_string());
+
+        let rows = sqlx::query_as::<_, McpTrafficFrameRow>(
+            r#"SELECT id, server_id, connection_id, transport, direction, kind, method, correlation_id, frame, size_bytes, created_at
+               FROM mcp_traffic
+               WHERE (? IS NULL OR server_id = ?)
+                 AND (? IS NULL OR connection_id = ?)
+                 AND (? IS NULL OR direction = ?)
+                 AND (? IS NULL OR correlation_id = ?)
+               ORDER BY created_at DESC, rowid DESC LIMIT ?"#,
+        )
+        .bind(server_id)
+        .bind(server_id)
+        .bind(connection_id)
+        .bind(connection_id)
+        .bind(&direction)
+        .bind(&direction)
+        .bind(correlation_id)
+        .bind(correlation_id)
+        .bind(limit.unwrap_or(500))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn clear(&self, server_id: Option<&str>) -> Result<(), AppError> {
+        if let Some(sid) = server_id {
+            sqlx::query("DELETE FROM mcp_traffic WHERE server_id = ?")
+                .bind(sid)
+                .execute(&self.pool)
+                .await?;
+        } else {
+            sqlx::query("DELETE FROM mcp_traffic").execute(&self.pool).await?;
+        }
+        Ok(())
+    }
+
+    async fn prune(&self, server_id: &str) -> Result<(), AppError> {
+        sqlx::query(
+            r#"DELETE FROM mcp_traffic WHERE server_id = ? AND rowid <=
+               (SELECT rowid FROM mcp_traffic WHERE server_id = ? ORDER BY rowid DESC LIMIT 1 OFFSET ?)"#,
+        )
+        .bind(server_id)
+        .bind(server_id)
+        .bind(MAX_FRAMES_PER_SERVER)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct McpTrafficFrameRow {
+    id: String,
+    server_id: String,
+    connection_id: String,
+    transport: String,
+    direction: String,
+    kind: String,
+    method: Option<String>,
+    correlation_id: Option<String>,
+    frame: String,
+    size_bytes: i64,
+    created_at: String,
+}
+
+impl From<McpTrafficFrameRow> for McpTrafficFrame {
+    fn from(row: McpTrafficFrameRow) -> Self {
+        McpTrafficFrame {
+            id: row.id,
+            server_id: row.server_id,
+            connection_id: row.connection_id,
+            transport: row.transport.into(),
+            direction: row.direction.into(),
+            kind: row.kind,
+            method: row.method,
+            correlation_id: row.correlation_id,
+            frame: row.frame,
+            size_bytes: row.size_bytes,
+            created_at: row.created_at,
+        }
+    }
+}
+
+// ============ MCP Transcript Repository ============
+
+pub struct SqliteMcpTranscriptRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMcpTranscriptRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IMcpTranscriptRepository for SqliteMcpTranscriptRepository {
+    async fn create(
+        &self,
+        transcript: McpTranscript,
+        content: &str,
+    ) -> Result<McpTranscript, AppError> {
+        sqlx::query(
+            r#"INSERT INTO mcp_transcripts (id, name, server_name, server_url, transport, connection_id, frame_count, call_count, started_at, content, created_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+        )
+        .bind(&transcript.id)
+        .bind(&transcript.name)
+        .bind(&transcript.server_name)
+        .bind(&transcript.server_url)
+        .bind(transcript.transport.to_string())
+        .bind(&transcript.connection_id)
+        .bind(transcript.frame_count)
+        .bind(transcript.call_count)
+        .bind(&transcript.started_at)
+        .bind(content)
+        .bind(&transcript.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(transcript)
+    }
+
+    async fn list(&self) -> Result<Vec<McpTranscript>, AppError> {
+        let rows = sqlx::query_as::<_, McpTranscriptRow>(
+            r#"SELECT id, name, server_name, server_url, transport, connection_id, frame_count, call_count, started_at, created_at
+               FROM mcp_transcripts ORDER BY created_at DESC, rowid DESC"#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<(McpTranscript, String)>, AppError> {
+        let row = sqlx::query_as::<_, McpTranscriptContentRow>(
+            r#"SELECT id, name, server_name, server_url, transport, connection_id, frame_count, call_count, started_at, created_at, content
+               FROM mcp_transcripts WHERE id = ?"#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| (r.transcript.into(), r.content)))
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM mcp_transcripts WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct McpTranscriptRow {
+    id: String,
+    name: String,
+    server_name: String,
+    server_url: String,
+    transport: String,
+    connection_id: String,
+    frame_count: i64,
+    call_count: i64,
+    started_at: Option<String>,
+    created_at: String,
+}
+
+#[derive(sqlx::FromRow)]
+struct McpTranscriptContentRow {
+    #[sqlx(flatten)]
+    transcript: McpTranscriptRow,
+    content: String,
+}
+
+impl From<McpTranscriptRow> for McpTranscript {
+    fn from(row: McpTranscriptRow) -> Self {
+        McpTranscript {
+            id: row.id,
+            name: row.name,
+            server_name: row.server_name,
+            server_url: row.server_url,
+            transport: row.transport.into(),
+            connection_id: row.connection_id,
+            frame_count: row.frame_count,
+            call_count: row.call_count,
+            started_at: row.started_at,
+            created_at: row.created_at,
+        }
+    }
+}
@@ -0,0 +1,260 @@
+//! Sampling Responder - answers `sampling/createMessage` requests sent by MCP servers.
+//!
+//! Studio has no model of its own, so requests are answered from user-defined rules
+//! (first enabled match by priority wins) or, when nothing matches, paused until a
+//! manual reply arrives from the UI. Every exchange is persisted for inspection.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{oneshot, Mutex};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+use rmcp::model::{
+    Content, CreateMessageRequestParam, CreateMessageResult, ErrorData as McpError, Role,
+    SamplingMessage,
+};
+
+use crate::domain::sampling::{
+    ISamplingExchangeRepository, ISamplingRuleRepository, PendingSamplingRequest, SamplingExchange,
+    SamplingRule,
+};
+use crate::error::AppError;
+use crate::infra::clock::now_timestamp;
+use crate::infra::event_publisher::EventPublisher;
+
+/// How long a request waits for a manual reply before the server gets an error
+const MANUAL_REPLY_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Model name reported when a reply doesn't specify one
+pub const DEFAULT_SAMPLING_MODEL: &str = "mcp-studio";
+
+enum ManualReply {
+    Respond(Box<CreateMessageResult>),
+    Reject(String),
+}
+
+struct PendingReply {
+    info: PendingSamplingRequest,
+    sender: oneshot::Sender<ManualReply>,
+}
+
+pub struct SamplingResponder {
+    rule_repo: Arc<dyn ISamplingRuleRepository>,
+    exchange_repo: Arc<dyn ISamplingExchangeRepository>,
+    event_publisher: Arc<dyn EventPublisher>,
+    /// Requests waiting for a manual reply, keyed by request id
+    pending: Mutex<HashMap<String, PendingReply>>,
+}
+
+impl SamplingResponder {
+    pub fn new(
+        rule_repo: Arc<dyn ISamplingRuleRepository>,
+        exchange_repo: Arc<dyn ISamplingExchangeRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self { rule_repo, exchange_repo, event_publisher, pending: Mutex::new(HashMap::new()) }
+    }
+
+    /// Answer a sampling request from a server (called by the connection's ClientHandler)
+    pub async fn handle_create_message(
+        &self,
+        server_id: &str,
+        params: CreateMessageRequestParam,
+        ct: CancellationToken,
+    ) -> Result<CreateMessageResult, McpError> {
+        let start = Instant::now();
+        let request_id = Uuid::new_v4().to_string();
+        let request_value = serde_json::to_value(&params).unwrap_or(serde_json::Value::Null);
+
+        info!(target: "sampling", "Sampling request {} from server {}", request_id, server_id);
+        debug!(target: "sampling", "Sampling request params: {}", request_value);
+
+        let mut exchange = SamplingExchange {
+            id: request_id.clone(),
+            server_id: server_id.to_string(),
+            request: request_value.to_string(),
+            response: None,
+            status: "pending".to_string(),
+            source: None,
+            rule_id: None,
+            error_message: None,
+            duration_ms: None,
+            created_at: String::new(),
+        };
+
+        if let Err(e) = self.exchange_repo.create(exchange.clone()).await {
+            warn!(target: "sampling", "Failed to save sampling exchange: {}", e);
+        }
+
+        let outcome = match self.find_matching_rule(server_id, &params).await {
+            Some(rule) => {
+                info!(target: "sampling", "Sampling request {} answered by rule '{}'", request_id, rule.name);
+                exchange.source = Some("rule".to_string());
+                exchange.rule_id = Some(rule.id.clone());
+                Ok(build_result(rule.response_text, Some(rule.model), rule.stop_reason))
+            }
+            None => {
+                exchange.source = Some("manual".to_string());
+                self.wait_for_manual_reply(&request_id, server_id, request_value, ct).await
+            }
+        };
+
+        exchange.duration_ms = Some(start.elapsed().as_millis() as i64);
+        match &outcome {
+            Ok(result) => {
+                exchange.status = "answered".to_string();
+                exchange.response = serde_json::to_string(result).ok();
+            }
+            Err((status, message)) => {
+                exchange.status = status.to_string();
+                exchange.error_message = Some(message.clone());
+            }
+        }
+
+        if let Err(e) = self.exchange_repo.update(exchange.clone()).await {
+            warn!(target: "sampling", "Failed to update sampling exchange: {}", e);
+        }
+
+        let event_data = serde_json::json!({
+            "request_id": request_id,
+            "server_id": server_id,
+            "status": exchange.status,
+            "source": exchange.source,
+        });
+        self.event_publisher.publish("mcp:sampling_completed", event_data).await;
+
+        outcome.map_err(|(_, message)| McpError::internal_error(message, None))
+    }
+
+    /// Submit a manual reply for a pending request
+    pub async fn respond(
+        &self,
+        request_id: &str,
+        text: String,
+        model: Option<String>,
+        stop_reason: Option<String>,
+    ) -> Result<(), AppError> {
+        let result = build_result(text, model, stop_reason);
+        self.resolve(request_id, ManualReply::Respond(Box::new(result))).await
+    }
+
+    /// Reject a pending request; the server receives a JSON-RPC error
+    pub async fn reject(&self, request_id: &str, message: Option<String>) -> Result<(), AppError> {
+        let message = message.unwrap_or_else(|| "Sampling request rejected by user".to_string());
+        self.resolve(request_id, ManualReply::Reject(message)).await
+    }
+
+    /// Requests currently waiting for a manual reply (oldest first)
+    pub async fn pending_requests(&self) -> Vec<PendingSamplingRequest> {
+        let pending = self.pending.lock().await;
+        let mut requests: Vec<PendingSamplingRequest> =
+            pending.values().map(|p| p.info.clone()).collect();
+        requests.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        requests
+    }
+
+    async fn resolve(&self, request_id: &str, reply: ManualReply) -> Result<(), AppError> {
+        let pending = self.pending.lock().await.remove(request_id);
+        let pending = pending.ok_or_else(|| {
+            AppError::NotFound(format!("Sampling request {} is not pending", request_id))
+        })?;
+
+        pending.sender.send(reply).map_err(|_| {
+            AppError::Domain(format!("Sampling request {} is no longer waiting", request_id))
+        })
+    }
+
+    /// First enabled rule (by priority) that applies to the server and matches the messages
+    async fn find_matching_rule(
+        &self,
+        server_id: &str,
+        params: &CreateMessageRequestParam,
+    ) -> Option<SamplingRule> {
+        let rules = match self.rule_repo.list().await {
+            Ok(rules) => rules,
+            Err(e) => {
+                error!(target: "sampling", "Failed to load sampling rules: {}", e);
+                return None;
+            }
+        };
+
+        let text = message_text(params);
+
+        rules.into_iter().find(|rule| {
+            rule.enabled
+                && rule.server_id.as_deref().is_none_or(|sid| sid == server_id)
+                && rule.match_type.matches(&rule.pattern, &text)
+        })
+    }
+
+    async fn wait_for_manual_reply(
+        &self,
+        request_id: &str,
+        server_id: &str,
+        request: serde_json::Value,
+        ct: CancellationToken,
+    ) -> Result<CreateMessageResult, (&'static str, String)> {
+        let (sender, receiver) = oneshot::channel();
+        let info = PendingSamplingRequest {
+            request_id: request_id.to_string(),
+            server_id: server_id.to_string(),
+            request,
+            created_at: now_timestamp(),
+        };
+
+        self.pending
+            .lock()
+            .await
+            .insert(request_id.to_string(), PendingReply { info: info.clone(), sender });
+
+        info!(target: "sampling", "Sampling request {} waiting for manual reply", request_id);
+        let event_data = serde_json::to_value(&info).unwrap_or(serde_json::Value::Null);
+        self.event_publisher.publish("mcp:sampling_request", event_data).await;
+
+        let outcome = tokio::select! {
+            reply = receiver => match reply {
+                Ok(ManualReply::Respond(result)) => Ok(*result),
+                Ok(ManualReply::Reject(message)) => Err(("rejected", message)),
+                Err(_) => Err(("cancelled", "Sampling request dropped".to_string())),
+            },
+            _ = ct.cancelled() => Err(("cancelled", "Sampling request cancelled by server".to_string())),
+            _ = tokio::time::sleep(MANUAL_REPLY_TIMEOUT) => {
+                Err(("timeout", "Timed out waiting for a manual sampling reply".to_string()))
+            }
+        };
+
+        // Resolved requests were already removed by `resolve`
+        self.pending.lock().await.remove(request_id);
+
+        outcome
+    }
+}
+
+fn build_result(
+    text: String,
+    model: Option<String>,
+    stop_reason: Option<String>,
+) -> CreateMessageResult {
+    CreateMessageResult {
+        model: model
+            .filter(|m| !m.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_SAMPLING_MODEL.to_string()),
+        stop_reason: Some(
+            stop_reason.unwrap_or_else(|| CreateMessageResult::STOP_REASON_END_TURN.to_string()),
+        ),
+        message: SamplingMessage { role: Role::Assistant, content: Content::text(text) },
+    }
+}
+
+/// Text content of all request messages, one message per line
+fn message_text(params: &CreateMessageRequestParam) -> String {
+    params
+        .messages
+        .iter()
+        .filter_map(|m| m.content.as_text().map(|t| t.text.as_str()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -3,27 +3,74 @@
 //! This implements the deprecated HTTP+SSE transport for backward compatibility
 //! with older MCP servers that use /sse endpoints.
 
-use eventsource_stream::Eventsource;
+use eventsource_stream::{Event, EventStreamError, Eventsource};
+use futures::stream::BoxStream;
 use futures::StreamExt;
 use std::sync::{
     atomic::{AtomicBool, Ordering},
     Arc,
 };
-use tokio::sync::Mutex;
-use tracing::{debug, error, info};
+use std::time::Duration;
+use tokio::task::JoinSet;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info, warn};
 
+use rmcp::model::{ClientJsonRpcMessage, ClientNotification, ConstString, JsonRpcMessage};
 use rmcp::transport::worker::{
     Worker, WorkerContext, WorkerQuitReason, WorkerSendRequest, WorkerTransport,
 };
 use rmcp::RoleClient;
 use serde_json::Value;
 
-type DisconnectCallback = Arc<dyn Fn(String) + Send + Sync + 'static>;
+use crate::infra::raw_rpc::{RawRpcChannel, RawRpcReply};
+use crate::infra::traffic::TrafficTap;
+
+/// Invoked once with a reason when the transport is lost
+pub type DisconnectCallback = Arc<dyn Fn(String) + Send + Sync + 'static>;
+
+/// Error that stopped a worker before it was connected; rmcp only reports a closed channel,
+/// so the caller reads the cause (e.g. a 401 on the stream or handshake) from here
+pub type StartupError = Arc<std::sync::Mutex<Option<String>>>;
+
+/// Method and id of an outgoing message, for the transports' logs; params and results are
+/// left out since they can hold secrets resolved from the environment
+pub fn describe_message(message: &ClientJsonRpcMessage) -> String {
+    match message {
+        JsonRpcMessage::Request(request) => {
+            format!("request {} (id {})", request.request.method(), request.id)
+        }
+        JsonRpcMessage::Notification(notification) => {
+            let method = match &notification.notification {
+                ClientNotification::CancelledNotification(n) => n.method.as_str(),
+                ClientNotification::ProgressNotification(n) => n.method.as_str(),
+                ClientNotification::InitializedNotification(n) => n.method.as_str(),
+                ClientNotification::RootsListChangedNotification(n) => n.method.as_str(),
+                ClientNotification::CustomClientNotification(n) => n.method.as_str(),
+            };
+            format!("notification {}", method)
+        }
+        JsonRpcMessage::Response(response) => format!("response (id {})", response.id),
+        JsonRpcMessage::Error(error) => format!("error (id {})", error.id),
+    }
+}
+
+/// Times a dropped GET stream is reopened before the transport gives up
+const MAX_STREAM_RECONNECTS: u32 = 5;
+
+/// Delay before the first stream reconnect; doubled after every failure
+const STREAM_RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// Longest response body quoted in a POST error
+const MAX_ERROR_BODY_CHARS: usize = 1000;
+
+type SseEventStream = BoxStream<'static, Result<Event, EventStreamError<reqwest::Error>>>;
 
 #[derive(Debug, thiserror::Error)]
 pub enum SseTransportError {
     #[error("Connection error: {0}")]
     Connection(String),
+    #[error("POST failed with status {status}: {body}")]
+    Post { status: u16, body: String },
     #[error("Channel closed")]
     Closed,
     #[error("Join error: {0}")]
@@ -32,40 +79,40 @@ pub enum SseTransportError {
 
 pub struct SseWorker {
     url: String,
-    base_url: String, // Base URL for constructing full endpoint URLs
     client: reqwest::Client,
     server_id: String,
+    /// Reopen the GET stream when it drops instead of ending the transport
+    reconnect: bool,
+    raw_rpc: RawRpcChannel,
+    traffic: TrafficTap,
     disconnect_callback: Option<DisconnectCallback>,
     disconnect_notified: AtomicBool,
+    startup_error: StartupError,
 }
 
 impl SseWorker {
+    /// `client` carries the server's default headers and auth; it is used for both the GET and the POSTs
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         url: impl Into<String>,
+        client: reqwest::Client,
         server_id: impl Into<String>,
+        reconnect: bool,
+        raw_rpc: RawRpcChannel,
+        traffic: TrafficTap,
         disconnect_callback: Option<DisconnectCallback>,
+        startup_error: StartupError,
     ) -> Self {
-        let client =
-            reqwest::Client::builder().no_proxy().build().expect("Failed to create HTTP client");
-
-        let url_string = url.into();
-        let server_id = server_id.into();
-
-        // Extract base URL (scheme + host + port)
-        let base_url = if let Ok(parsed) = reqwest::Url::parse(&url_string) {
-            format!("{}://{}", parsed.scheme(), parsed.host_str().unwrap_or("localhost"))
-                + &parsed.port().map(|p| format!(":{}", p)).unwrap_or_default()
-        } else {
-            url_string.clone()
-        };
-
         Self {
-            url: url_string,
-            base_url,
+            url: url.into(),
             client,
-            server_id,
+            server_id: server_id.into(),
+            reconnect,
+            raw_rpc,
+            traffic,
             disconnect_callback,
             disconnect_notified: AtomicBool::new(false),
+            startup_error,
         }
     }
 
@@ -80,52 +127,48 @@ impl SseWorker {
             callback(reason.to_string());
         }
     }
-}
 
-impl Worker for SseWorker {
-    type Error = SseTransportError;
-    type Role = RoleClient;
-
-    fn err_closed() -> Self::Error {
-        SseTransportError::Closed
-    }
-
-    fn err_join(e: tokio::task::JoinError) -> Self::Error {
-        SseTransportError::Join(e.to_string())
-    }
-
-    async fn run(
-        self,
-        mut context: WorkerContext<Self>,
-    ) -> Result<(), WorkerQuitReason<Self::Error>> {
-        info!(target: "sse_transport", "Connecting to SSE endpoint: {}", self.url);
+    /// Open the GET event stream (`Last-Event-ID` is sent when reconnecting)
+    async fn open_stream(
+        &self,
+        last_event_id: Option<&str>,
+    ) -> Result<SseEventStream, SseTransportError> {
+        let mut request = self.client.get(&self.url).header("Accept", "text/event-stream");
+        if let Some(last_event_id) = last_event_id {
+            request = request.header("Last-Event-ID", last_event_id);
+        }
 
-        // Start SSE connection
-        let response =
-            self.client.get(&self.url).header("Accept", "text/event-stream").send().await.map_err(
-                |e| {
-                    WorkerQuitReason::fatal(
-                        SseTransportError::Connection(format!("Failed to connect: {}", e)),
-                        "connecting to SSE endpoint",
-                    )
-                },
-            )?;
+        let response = request
+            .send()
+            .await
+            .map_err(|e| SseTransportError::Connection(format!("Failed to connect: {}", e)))?;
 
         if !response.status().is_success() {
-            self.notify_disconnect("sse_initial_response_error");
-            return Err(WorkerQuitReason::fatal(
-                SseTransportError::Connection(format!("Server error: {}", response.status())),
-                "checking SSE response status",
-            ));
+            return Err(SseTransportError::Connection(format!(
+                "Server error: {}",
+                response.status()
+            )));
         }
 
-        let sse_stream = response.bytes_stream().eventsource();
-        tokio::pin!(sse_stream);
+        Ok(response.bytes_stream().eventsource().boxed())
+    }
 
-        let post_url = Arc::new(Mutex::new(None::<String>));
-        let ct = context.cancellation_token.clone();
+    /// Resolve an `endpoint` event against the SSE URL (relative paths keep its path prefix)
+    fn resolve_endpoint(&self, endpoint: &str) -> Result<String, SseTransportError> {
+        reqwest::Url::parse(&self.url)
+            .and_then(|base| base.join(endpoint.trim()))
+            .map(String::from)
+            .map_err(|e| {
+                SseTransportError::Connection(format!("Invalid endpoint '{}': {}", endpoint, e))
+            })
+    }
 
-        // PHASE 1: Wait for endpoint event before accepting messages
+    /// Read events until the server announces the POST endpoint
+    async fn wait_for_endpoint(
+        &self,
+        sse_stream: &mut SseEventStream,
+        ct: &CancellationToken,
+    ) -> Result<String, WorkerQuitReason<SseTransportError>> {
         info!(target: "sse_transport", "Waiting for endpoint event...");
         loop {
             tokio::select! {
@@ -135,31 +178,21 @@ impl Worker for SseWorker {
                             debug!(target: "sse_transport", "Received SSE event: {}", event.event);
 
                             if event.event.as_str() == "endpoint" {
-                                let endpoint = event.data.trim().to_string();
-                                info!(target: "sse_transport", "Received endpoint: {}", endpoint);
-
-                                // Construct full URL if endpoint is relative
-                                let full_url = if endpoint.starts_with("http://") || endpoint.starts_with("https://") {
-                                    endpoint
-                                } else {
-                                    format!("{}{}", self.base_url, endpoint)
-                                };
-
+                                info!(target: "sse_transport", "Received endpoint: {}", event.data.trim());
+                                let full_url = self.resolve_endpoint(&event.data).map_err(|e| {
+                                    WorkerQuitReason::fatal(e, "resolving endpoint")
+                                })?;
                                 info!(target: "sse_transport", "Full POST URL: {}", full_url);
-                                let mut url = post_url.lock().await;
-                                *url = Some(full_url);
-                                break; // Exit phase 1, proceed to phase 2
+                                return Ok(full_url);
                             }
                         }
                         Some(Err(e)) => {
-                            self.notify_disconnect("sse_stream_error_before_endpoint");
                             return Err(WorkerQuitReason::fatal(
                                 SseTransportError::Connection(format!("SSE error while waiting for endpoint: {:?}", e)),
                                 "waiting for endpoint"
                             ));
                         }
                         None => {
-                            self.notify_disconnect("sse_stream_closed_before_endpoint");
                             return Err(WorkerQuitReason::fatal(
                                 SseTransportError::Connection("SSE stream closed before receiving endpoint".to_string()),
                                 "waiting for endpoint"
@@ -169,80 +202,210 @@ impl Worker for SseWorker {
                 }
 
                 _ = ct.cancelled() => {
-                    info!(target: "sse_transport", "SSE transport cancelled during initialization");
+                    info!(target: "sse_transport", "SSE transport cancelled while waiting for endpoint");
                     return Err(WorkerQuitReason::Cancelled);
                 }
             }
         }
+    }
+
+    /// Reopen a dropped GET stream with backoff and wait for the endpoint it announces
+    async fn reconnect_stream(
+        &self,
+        last_event_id: Option<&str>,
+        ct: &CancellationToken,
+    ) -> Result<(SseEventStream, String), WorkerQuitReason<SseTransportError>> {
+        let mut delay = STREAM_RECONNECT_DELAY;
+        let mut last_error = String::new();
+
+        for attempt in 1..=MAX_STREAM_RECONNECTS {
+            info!(target: "sse_transport", "Reconnecting SSE stream in {:?} (attempt {}/{})",
+                delay, attempt, MAX_STREAM_RECONNECTS);
+            tokio::select! {
+                _ = tokio::time::sleep(delay) => {}
+                _ = ct.cancelled() => return Err(WorkerQuitReason::Cancelled),
+            }
+            delay *= 2;
+
+            let mut sse_stream = match self.open_stream(last_event_id).await {
+                Ok(sse_stream) => sse_stream,
+                Err(e) => {
+                    warn!(target: "sse_transport", "SSE stream reconnect failed: {}", e);
+                    last_error = e.to_string();
+                    continue;
+                }
+            };
+
+            match self.wait_for_endpoint(&mut sse_stream, ct).await {
+                Ok(endpoint) => {
+                    info!(target: "sse_transport", "SSE stream reconnected");
+                    return Ok((sse_stream, endpoint));
+                }
+                Err(WorkerQuitReason::Cancelled) => return Err(WorkerQuitReason::Cancelled),
+                Err(e) => {
+                    warn!(target: "sse_transport", "SSE stream reconnect failed: {:?}", e);
+                    last_error = format!("{:?}", e);
+                }
+            }
+        }
+
+        Err(WorkerQuitReason::fatal(
+            SseTransportError::Connection(format!(
+                "SSE stream lost and {} reconnects failed: {}",
+                MAX_STREAM_RECONNECTS, last_error
+            )),
+            "reconnecting SSE stream",
+        ))
+    }
+}
+
+impl Worker for SseWorker {
+    type Error = SseTransportError;
+    type Role = RoleClient;
+
+    fn err_closed() -> Self::Error {
+        SseTransportError::Closed
+    }
+
+    fn err_join(e: tokio::task::JoinError) -> Self::Error {
+        SseTransportError::Join(e.to_string())
+    }
+
+    async fn run(
+        self,
+        mut context: WorkerContext<Self>,
+    ) -> Result<(), WorkerQuitReason<Self::Error>> {
+        info!(target: "sse_transport", "Connecting to SSE endpoint: {}", self.url);
+
+        // Start SSE connection
+        let mut sse_stream = match self.open_stream(None).await {
+            Ok(sse_stream) => sse_stream,
+            Err(e) => {
+                if let Ok(mut startup_error) = self.startup_error.lock() {
+                    *startup_error = Some(e.to_string());
+                }
+                self.notify_disconnect("sse_initial_response_error");
+                return Err(WorkerQuitReason::fatal(e, "connecting to SSE endpoint"));
+            }
+        };
+
+        let ct = context.cancellation_token.clone();
+
+        // PHASE 1: Wait for endpoint event before accepting messages
+        let mut post_url = match self.wait_for_endpoint(&mut sse_stream, &ct).await {
+            Ok(endpoint) => endpoint,
+            Err(WorkerQuitReason::Cancelled) => return Err(WorkerQuitReason::Cancelled),
+            Err(e) => {
+                self.notify_disconnect("sse_stream_closed_before_endpoint");
+                return Err(e);
+            }
+        };
+        let mut last_event_id: Option<String> = None;
+
+        // POSTs in flight; they run outside the loop so a slow server doesn't hold up the
+        // event stream, and are aborted when the transport ends
+        let mut posts = JoinSet::new();
 
         info!(target: "sse_transport", "Endpoint received, ready to handle messages");
 
         // PHASE 2: Normal operation - handle messages and SSE events
         loop {
-            tokio::select! {
+            // Why the stream ended, when it did
+            let stream_lost = tokio::select! {
                 // Handle outgoing messages from MCP client
                 request = context.recv_from_handler() => {
                     let WorkerSendRequest { message, responder } = request?;
 
-                    debug!(target: "sse_transport", "Sending message: {:?}", message);
+                    debug!(target: "sse_transport", "Sending {}", describe_message(&message));
 
                     // Serialize the JSON-RPC message
-                    let json_value = serde_json::to_value(&message)
+                    let body = serde_json::to_string(&message)
                         .map_err(|e| WorkerQuitReason::fatal(
                             SseTransportError::Connection(format!("Failed to serialize: {}", e)),
                             "serializing message"
                         ))?;
+                    self.traffic.outbound(&body);
 
-                    // Get POST endpoint (should always be Some in Phase 2)
-                    let endpoint = {
-                        let url_guard = post_url.lock().await;
-                        url_guard.clone().expect("Endpoint must be available in Phase 2")
-                    };
-
-                    // Send POST request
-                    let result = self.client
-                        .post(&endpoint)
+                    // A failed POST fails that message only; the event stream stays up
+                    let request = self.client
+                        .post(&post_url)
                         .header("Content-Type", "application/json")
-                        .json(&json_value)
-                        .send()
-                        .await;
-
-                    let send_result = match result {
-                        Ok(response) if response.status().is_success() => Ok(()),
-                        Ok(response) => Err(SseTransportError::Connection(
-                            format!("POST failed: {}", response.status())
-                        )),
-                        Err(e) => Err(SseTransportError::Connection(
-                            format!("POST error: {}", e)
-                        )),
-                    };
-
-                    if let Err(err) = send_result {
-                        error!(target: "sse_transport", "POST to MCP server failed: {}", err);
-                        self.notify_disconnect("post_send_error");
-                        let _ = responder.send(Err(err));
-                        return Err(WorkerQuitReason::fatal(
-                            SseTransportError::Connection("POST request failed, terminating transport".to_string()),
-                            "sending POST request"
-                        ));
-                    }
+                        .body(body);
+                    posts.spawn(async move {
+                        let send_result = match request.send().await {
+                            Ok(response) if response.status().is_success() => Ok(()),
+                            Ok(response) => {
+                                let status = response.status().as_u16();
+                                let body = response.text().await.unwrap_or_default();
+                                Err(SseTransportError::Post {
+                                    status,
+                                    body: body.chars().take(MAX_ERROR_BODY_CHARS).collect(),
+                                })
+                            }
+                            Err(e) => Err(SseTransportError::Connection(
+                                format!("POST error: {}", e)
+                            )),
+                        };
+                        if let Err(err) = &send_result {
+                            error!(target: "sse_transport", "POST to MCP server failed: {}", err);
+                        }
+                        let _ = responder.send(send_result);
+                    });
+                    None
+                }
 
-                    let _ = responder.send(Ok(()));
+                // Raw messages from the console; a rejected POST is their reply, not a transport failure
+                outgoing = self.raw_rpc.recv() => {
+                    self.traffic.outbound(&outgoing.text);
+                    let request = self.client
+                        .post(&post_url)
+                        .header("Content-Type", "application/json")
+                        .body(outgoing.text);
+                    posts.spawn(async move {
+                        let sent = match request.send().await {
+                            Ok(response) if response.status().is_success() => Ok(()),
+                            Ok(response) => {
+                                let status = response.status();
+                                Err(RawRpcReply {
+                                    body: response.text().await.ok(),
+                                    error: Some(format!("POST failed with status {}", status)),
+                                    ..Default::default()
+                                })
+                            }
+                            Err(e) => Err(RawRpcReply {
+                                error: Some(format!("POST error: {}", e)),
+                                ..Default::default()
+                            }),
+                        };
+                        let _ = outgoing.sent.send(sent);
+                    });
+                    None
                 }
 
+                // Reap finished POSTs
+                Some(_) = posts.join_next(), if !posts.is_empty() => None,
+
                 // Handle incoming SSE events
                 event = sse_stream.next() => {
                     match event {
                         Some(Ok(event)) => {
                             debug!(target: "sse_transport", "Received SSE event: {}", event.event);
+                            if !event.id.is_empty() {
+                                last_event_id = Some(event.id.clone());
+                            }
+                            if event.event == "message" {
+                                self.traffic.inbound(&event.data);
+                            }
 
                             match event.event.as_str() {
                                 "endpoint" => {
-                                    let endpoint = event.data.trim().to_string();
-                                    info!(target: "sse_transport", "Received endpoint: {}", endpoint);
-                                    let mut url = post_url.lock().await;
-                                    *url = Some(endpoint);
+                                    info!(target: "sse_transport", "Received endpoint: {}", event.data.trim());
+                                    match self.resolve_endpoint(&event.data) {
+                                        Ok(full_url) => post_url = full_url,
+                                        Err(e) => error!(target: "sse_transport", "Ignoring endpoint: {}", e),
+                                    }
                                 }
+                                "message" if self.raw_rpc.claim_reply(&event.data) => {}
                                 "message" => {
                                     match serde_json::from_str::<Value>(&event.data) {
                                         Ok(json_value) => {
@@ -265,19 +428,15 @@ impl Worker for SseWorker {
                                     debug!(target: "sse_transport", "Unknown event type: {}", event.event);
                                 }
                             }
+                            None
                         }
                         Some(Err(e)) => {
                             error!(target: "sse_transport", "SSE stream error: {:?}", e);
-                            self.notify_disconnect("sse_stream_error");
-                            return Err(WorkerQuitReason::fatal(
-                                SseTransportError::Connection(format!("SSE stream error: {:?}", e)),
-                                "receiving SSE event"
-                            ));
+                            Some(("sse_stream_error", format!("SSE stream error: {:?}", e)))
                         }
                         None => {
                             info!(target: "sse_transport", "SSE stream closed by server");
-                            self.notify_disconnect("sse_stream_closed");
-                            return Err(WorkerQuitReason::TransportClosed);
+                            Some(("sse_stream_closed", "SSE stream closed by server".to_string()))
                         }
                     }
                 }
@@ -287,6 +446,34 @@ impl Worker for SseWorker {
                     info!(target: "sse_transport", "SSE transport cancelled");
                     return Err(WorkerQuitReason::Cancelled);
                 }
+            };
+
+            let Some((reason, message)) = stream_lost else {
+                continue;
+            };
+
+            if !self.reconnect {
+                self.notify_disconnect(reason);
+                return Err(if reason == "sse_stream_closed" {
+                    WorkerQuitReason::TransportClosed
+                } else {
+                    WorkerQuitReason::fatal(
+                        SseTransportError::Connection(message),
+                        "receiving SSE event",
+                    )
+                });
+            }
+
+            match self.reconnect_stream(last_event_id.as_deref(), &ct).await {
+                Ok((new_stream, endpoint)) => {
+                    sse_stream = new_stream;
+                    post_url = endpoint;
+                }
+                Err(WorkerQuitReason::Cancelled) => return Err(WorkerQuitReason::Cancelled),
+                Err(e) => {
+                    self.notify_disconnect(reason);
+                    return Err(e);
+                }
             }
         }
     }
@@ -0,0 +1,289 @@
+//! Stdio Transport for MCP (Model Context Protocol)
+//!
+//! Spawns a local MCP server process (`npx`, `uvx`, a plain binary, ...) and speaks
+//! newline-delimited JSON-RPC over its stdin/stdout. Anything the server writes to
+//! stderr is forwarded to the application log.
+
+use std::collections::HashMap;
+use std::process::Stdio;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::{Child, ChildStdin, Command};
+use tracing::{debug, error, info, warn};
+
+use rmcp::model::ServerJsonRpcMessage;
+use rmcp::transport::worker::{
+    Worker, WorkerContext, WorkerQuitReason, WorkerSendRequest, WorkerTransport,
+};
+use rmcp::RoleClient;
+
+use crate::infra::raw_rpc::{RawRpcChannel, RawRpcReply};
+use crate::infra::sse_transport::{describe_message, DisconnectCallback};
+use crate::infra::traffic::TrafficTap;
+
+/// How long a child gets to exit on its own after stdin is closed before it is killed
+const SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(3);
+
+#[derive(Debug, thiserror::Error)]
+pub enum StdioTransportError {
+    #[error("Failed to spawn process: {0}")]
+    Spawn(String),
+    #[error("IO error: {0}")]
+    Io(String),
+    #[error("Channel closed")]
+    Closed,
+    #[error("Join error: {0}")]
+    Join(String),
+}
+
+pub struct StdioWorker {
+    command: String,
+    args: Vec<String>,
+    env: HashMap<String, String>,
+    cwd: Option<String>,
+    server_id: String,
+    raw_rpc: RawRpcChannel,
+    traffic: TrafficTap,
+    disconnect_callback: Option<DisconnectCallback>,
+    disconnect_notified: AtomicBool,
+}
+
+impl StdioWorker {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        command: impl Into<String>,
+        args: Vec<String>,
+        env: HashMap<String, String>,
+        cwd: Option<String>,
+        server_id: impl Into<String>,
+        raw_rpc: RawRpcChannel,
+        traffic: TrafficTap,
+        disconnect_callback: Option<DisconnectCallback>,
+    ) -> Self {
+        Self {
+            command: command.into(),
+            args,
+            env,
+            cwd,
+            server_id: server_id.into(),
+            raw_rpc,
+            traffic,
+            disconnect_callback,
+            disconnect_notified: AtomicBool::new(false),
+        }
+    }
+
+    fn notify_disconnect(&self, reason: &str) {
+        if self.disconnect_notified.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        info!(target: "stdio_transport", server_id = %self.server_id, reason = reason, "Notifying disconnect");
+
+        if let Some(callback) = &self.disconnect_callback {
+            callback(reason.to_string());
+        }
+    }
+
+    fn spawn_child(&self) -> Result<Child, StdioTransportError> {
+        let mut command = Command::new(&self.command);
+        command
+            .args(&self.args)
+            .envs(&self.env)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+
+        if let Some(cwd) = self.cwd.as_deref().filter(|c| !c.trim().is_empty()) {
+            command.current_dir(cwd);
+        }
+
+        // Don't flash a console window for every server on Windows
+        #[cfg(windows)]
+        command.creation_flags(0x0800_0000); // CREATE_NO_WINDOW
+
+        command.spawn().map_err(|e| StdioTransportError::Spawn(format!("{} ({})", self.command, e)))
+    }
+
+    /// Close stdin so the server can exit on its own, then kill it after the grace period
+    async fn shutdown(&self, mut child: Child, stdin: ChildStdin) {
+        drop(stdin);
+
+        match tokio::time::timeout(SHUTDOWN_GRACE_PERIOD, child.wait()).await {
+            Ok(Ok(status)) => {
+                info!(target: "stdio_transport", server_id = %self.server_id, "Process exited: {}", status);
+            }
+            Ok(Err(e)) => {
+                warn!(target: "stdio_transport", server_id = %self.server_id, "Failed to wait for process: {}", e);
+            }
+            Err(_) => {
+                warn!(target: "stdio_transport", server_id = %self.server_id, "Process did not exit in time, killing it");
+                if let Err(e) = child.kill().await {
+                    error!(target: "stdio_transport", server_id = %self.server_id, "Failed to kill process: {}", e);
+                }
+            }
+        }
+    }
+}
+
+impl Worker for StdioWorker {
+    type Error = StdioTransportError;
+    type Role = RoleClient;
+
+    fn err_closed() -> Self::Error {
+        StdioTransportError::Closed
+    }
+
+    fn err_join(e: tokio::task::JoinError) -> Self::Error {
+        StdioTransportError::Join(e.to_string())
+    }
+
+    async fn run(
+        self,
+        mut context: WorkerContext<Self>,
+    ) -> Result<(), WorkerQuitReason<Self::Error>> {
+        info!(target: "stdio_transport", "Spawning MCP server process: {} {:?}", self.command, self.args);
+
+        let mut child = self
+            .spawn_child()
+            .map_err(|e| WorkerQuitReason::fatal(e, "spawning server process"))?;
+
+        info!(target: "stdio_transport", server_id = %self.server_id, "Process started with pid {:?}", child.id());
+
+        let (Some(mut stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            return Err(WorkerQuitReason::fatal(
+                StdioTransportError::Io("Process stdio is not piped".to_string()),
+                "taking process stdio",
+            ));
+        };
+
+        // Forward stderr to the log; servers use it for diagnostics
+        if let Some(stderr) = child.stderr.take() {
+            let server_id = self.server_id.clone();
+            tokio::spawn(async move {
+                let mut lines = BufReader::new(stderr).lines();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    info!(target: "stdio_transport", server_id = %server_id, "[stderr] {}", line);
+                }
+            });
+        }
+
+        let mut stdout_lines = BufReader::new(stdout).lines();
+        let ct = context.cancellation_token.clone();
+
+        loop {
+            tokio::select! {
+                // Handle outgoing messages from MCP client
+                request = context.recv_from_handler() => {
+                    let WorkerSendRequest { message, responder } = match request {
+                        Ok(request) => request,
+                        Err(reason) => {
+                            self.shutdown(child, stdin).await;
+                            return Err(reason);
+                        }
+                    };
+
+                    debug!(target: "stdio_transport", "Sending {}", describe_message(&message));
+
+                    let mut line = serde_json::to_string(&message).map_err(|e| WorkerQuitReason::fatal(
+                        StdioTransportError::Io(format!("Failed to serialize: {}", e)),
+                        "serializing message"
+                    ))?;
+                    self.traffic.outbound(&line);
+                    line.push('\n');
+
+                    let write_result = async {
+                        stdin.write_all(line.as_bytes()).await?;
+                        stdin.flush().await
+                    }
+                    .await;
+
+                    if let Err(e) = write_result {
+                        error!(target: "stdio_transport", "Failed to write to process stdin: {}", e);
+                        self.notify_disconnect("stdin_write_error");
+                        let _ = responder.send(Err(StdioTransportError::Io(e.to_string())));
+                        self.shutdown(child, stdin).await;
+                        return Err(WorkerQuitReason::fatal(
+                            StdioTransportError::Io("Write to stdin failed, terminating transport".to_string()),
+                            "writing to stdin"
+                        ));
+                    }
+
+                    let _ = responder.send(Ok(()));
+                }
+
+                // Raw messages from the console; line breaks would split them into several messages
+                outgoing = self.raw_rpc.recv() => {
+                    let mut line = outgoing.text.replace(['\r', '\n'], " ");
+                    self.traffic.outbound(&line);
+                    line.push('\n');
+
+                    let write_result = async {
+                        stdin.write_all(line.as_bytes()).await?;
+                        stdin.flush().await
+                    }
+                    .await;
+
+                    let sent = write_result.map_err(|e| RawRpcReply {
+                        error: Some(format!("Failed to write to process stdin: {}", e)),
+                        ..Default::default()
+                    });
+                    let _ = outgoing.sent.send(sent);
+                }
+
+                // Handle incoming lines from the server
+                line = stdout_lines.next_line() => {
+                    match line {
+                        Ok(Some(raw_line)) => {
+                            let line = raw_line.trim();
+                            if line.is_empty() {
+                                continue;
+                            }
+                            // Recorded as it was on the wire (without the line break)
+                            self.traffic.inbound(&raw_line);
+                            if self.raw_rpc.claim_reply(line) {
+                                continue;
+                            }
+
+                            match serde_json::from_str::<ServerJsonRpcMessage>(line) {
+                                Ok(jsonrpc_msg) => {
+                                    context.send_to_handler(jsonrpc_msg).await?;
+                                }
+                                Err(e) => {
+                                    // Misbehaving servers sometimes log to stdout; don't kill the session for it
+                                    warn!(target: "stdio_transport", "Ignoring non JSON-RPC stdout line ({}): {}", e, line);
+                                }
+                            }
+                        }
+                        Ok(None) => {
+                            let status = tokio::time::timeout(SHUTDOWN_GRACE_PERIOD, child.wait()).await;
+                            info!(target: "stdio_transport", server_id = %self.server_id, "Process closed stdout (exit: {:?})", status);
+                            self.notify_disconnect("process_exited");
+                            return Err(WorkerQuitReason::TransportClosed);
+                        }
+                        Err(e) => {
+                            error!(target: "stdio_transport", "Failed to read process stdout: {}", e);
+                            self.notify_disconnect("stdout_read_error");
+                            self.shutdown(child, stdin).await;
+                            return Err(WorkerQuitReason::fatal(
+                                StdioTransportError::Io(format!("Failed to read stdout: {}", e)),
+                                "reading stdout"
+                            ));
+                        }
+                    }
+                }
+
+                // Handle cancellation (disconnect)
+                _ = ct.cancelled() => {
+                    info!(target: "stdio_transport", "Stdio transport cancelled");
+                    self.shutdown(child, stdin).await;
+                    return Err(WorkerQuitReason::Cancelled);
+                }
+            }
+        }
+    }
+}
+
+pub type StdioTransport = WorkerTransport<StdioWorker>;
@@ -0,0 +1,456 @@
+//! Streamable HTTP Client - reqwest-backed `StreamableHttpClient` that exposes the session.
+//!
+//! rmcp drives the protocol; this client sits underneath it to record the `Mcp-Session-Id`
+//! and the last SSE event id, skip the standalone GET stream when it is disabled, resume
+//! POST streams that end before their response with `Last-Event-ID`, and end the session
+//! with `DELETE` exactly once. POSTs are sent here rather than by rmcp's reqwest client so
+//! the traffic tap sees the bodies exactly as they went over the wire.
+
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use reqwest::header::{ACCEPT, CONTENT_TYPE, WWW_AUTHENTICATE};
+use reqwest::StatusCode;
+use rmcp::model::{ClientJsonRpcMessage, ServerJsonRpcMessage};
+use rmcp::transport::streamable_http_client::{
+    AuthRequiredError, SseError, StreamableHttpClient, StreamableHttpError,
+    StreamableHttpPostResponse,
+};
+use sse_stream::{Sse, SseStream};
+use std::borrow::Cow;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+use crate::domain::mcp::McpStreamableSession;
+use crate::error::AppError;
+use crate::infra::raw_rpc::{response_key, RawRpcReply};
+use crate::infra::traffic::TrafficTap;
+
+const HEADER_SESSION_ID: &str = "Mcp-Session-Id";
+
+const EVENT_STREAM_MIME_TYPE: &str = "text/event-stream";
+const JSON_MIME_TYPE: &str = "application/json";
+
+/// Times the response stream of one POST is resumed after ending early
+const MAX_STREAM_RESUMPTIONS: u32 = 5;
+
+/// Wait before resuming when the server didn't send a `retry` hint
+const DEFAULT_RESUME_DELAY: Duration = Duration::from_secs(1);
+
+/// Limit on the session DELETE, so an unresponsive server can't hold up a disconnect
+const TERMINATE_TIMEOUT: Duration = Duration::from_secs(5);
+
+type SseEventStream = BoxStream<'static, Result<Sse, SseError>>;
+
+#[derive(Default)]
+struct SessionState {
+    session_id: Option<String>,
+    last_event_id: Option<String>,
+    resumptions: u32,
+    terminated: bool,
+}
+
+/// HTTP client of one streamable HTTP connection (clones share the session state)
+#[derive(Clone)]
+pub struct SessionHttpClient {
+    client: reqwest::Client,
+    server_id: String,
+    standalone_sse: bool,
+    traffic: TrafficTap,
+    state: Arc<Mutex<SessionState>>,
+}
+
+impl SessionHttpClient {
+    /// `client` carries the server's default headers and auth
+    pub fn new(
+        client: reqwest::Client,
+        server_id: impl Into<String>,
+        standalone_sse: bool,
+        traffic: TrafficTap,
+    ) -> Self {
+        Self {
+            client,
+            server_id: server_id.into(),
+            standalone_sse,
+            traffic,
+            state: Arc::new(Mutex::new(SessionState::default())),
+        }
+    }
+
+    /// Current session state (for the connection info)
+    pub fn session(&self) -> McpStreamableSession {
+        let state = self.lock();
+        McpStreamableSession {
+            session_id: state.session_id.clone(),
+            standalone_sse: self.standalone_sse,
+            last_event_id: state.last_event_id.clone(),
+            resumptions: state.resumptions,
+            terminated: state.terminated,
+        }
+    }
+
+    /// End the session with `DELETE`; returns the session id and the HTTP status
+    pub async fn terminate(&self, uri: &str) -> Result<(String, u16), AppError> {
+        let session_id = {
+            let state = self.lock();
+            if state.terminated {
+                return Err(AppError::Domain("Session was already terminated".to_string()));
+            }
+            state.session_id.clone().ok_or_else(|| {
+                AppError::Domain("Server did not assign a session (stateless server)".to_string())
+            })?
+        };
+
+        info!(target: "mcp_client", "Terminating session {} of server {}", session_id, self.server_id);
+        let response = self
+            .client
+            .delete(uri)
+            .header(HEADER_SESSION_ID, &session_id)
+            .timeout(TERMINATE_TIMEOUT)
+            .send()
+            .await
+            .map_err(|e| AppError::Io(format!("Failed to terminate session: {}", e)))?;
+
+        // The session is over once the server ended it, no longer knows it (404) or doesn't
+        // let clients end it (405); rmcp's own DELETE on close then becomes a no-op
+        let status = response.status();
+        if status.is_success()
+            || matches!(status, StatusCode::NOT_FOUND | StatusCode::METHOD_NOT_ALLOWED)
+        {
+            self.lock().terminated = true;
+        } else {
+            warn!(target: "mcp_client", "Session {} of server {} not terminated: {}", session_id, self.server_id, status);
+        }
+
+        Ok((session_id, status.as_u16()))
+    }
+
+    /// POST a raw message in the session; the JSON body or the first response event is the reply
+    pub async fn send_raw(
+        &self,
+        uri: &str,
+        text: &str,
+        timeout: Duration,
+    ) -> Result<RawRpcReply, AppError> {
+        let mut request = self
+            .client
+            .post(uri)
+            .header(ACCEPT, "application/json, text/event-stream")
+            .header(CONTENT_TYPE, "application/json")
+            .body(text.to_string());
+        if let Some(session_id) = self.lock().session_id.clone() {
+            request = request.header(HEADER_SESSION_ID, session_id);
+        }
+        self.traffic.outbound(text);
+
+        let exchange = async {
+            let response =
+                request.send().await.map_err(|e| AppError::Io(format!("POST error: {}", e)))?;
+            let status = response.status();
+            let is_sse = response
+                .headers()
+                .get(CONTENT_TYPE)
+                .is_some_and(|ct| ct.as_bytes().starts_with(b"text/event-stream"));
+
+            if !status.is_success() {
+                return Ok(RawRpcReply {
+                    body: response.text().await.ok().filter(|b| !b.is_empty()),
+                    error: Some(format!("POST failed with status {}", status)),
+                    ..Default::default()
+                });
+            }
+
+            if !is_sse {
+                // 202 Accepted: notifications and responses get no body
+                let body = response
+                    .text()
+                    .await
+                    .map_err(|e| AppError::Io(format!("Failed to read reply: {}", e)))?;
+                if !body.is_empty() {
+                    self.traffic.inbound(&body);
+                }
+                return Ok(RawRpcReply {
+                    body: Some(body).filter(|b| !b.is_empty()),
+                    ..Default::default()
+                });
+            }
+
+            let mut events =
+                self.track_events(SseStream::from_bytes_stream(response.bytes_stream()).boxed());
+            while let Some(event) = events.next().await {
+                let sse = event.map_err(|e| AppError::Io(format!("SSE error: {}", e)))?;
+                if let Some(data) = sse.data.filter(|data| response_key(data).is_some()) {
+                    return Ok(RawRpcReply { body: Some(data), ..Default::default() });
+                }
+            }
+            Ok(RawRpcReply {
+                error: Some("Stream ended without a reply".to_string()),
+                ..Default::default()
+            })
+        };
+
+        match tokio::time::timeout(timeout, exchange).await {
+            Ok(reply) => reply,
+            Err(_) => Ok(RawRpcReply {
+                error: Some(format!("No reply within {} ms", timeout.as_millis())),
+                timed_out: true,
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, SessionState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn record_session_id(&self, session_id: Option<&str>) {
+        if let Some(session_id) = session_id {
+            debug!(target: "mcp_client", "Server {} assigned session {}", self.server_id, session_id);
+            self.lock().session_id = Some(session_id.to_string());
+        }
+    }
+
+    /// Remember the id of every event so the connection info shows the resume point, and
+    /// record the messages events carry as inbound frames
+    fn track_events(&self, stream: SseEventStream) -> SseEventStream {
+        let state = self.state.clone();
+        let traffic = self.traffic.clone();
+        stream
+            .inspect(move |event| {
+                let Ok(sse) = event else {
+                    return;
+                };
+                if let Some(id) = &sse.id {
+                    state.lock().unwrap_or_else(|e| e.into_inner()).last_event_id =
+                        Some(id.clone());
+                }
+                if let Some(data) = sse.data.as_deref().filter(|data| !data.is_empty()) {
+                    if matches!(sse.event.as_deref(), None | Some("") | Some("message")) {
+                        traffic.inbound(data);
+                    }
+                }
+            })
+            .boxed()
+    }
+
+    /// Response stream of a POST, resumed with `Last-Event-ID` when it ends before the response
+    fn resumable(
+        &self,
+        stream: SseEventStream,
+        uri: Arc<str>,
+        session_id: Option<Arc<str>>,
+        auth_header: Option<String>,
+    ) -> SseEventStream {
+        let post_stream = PostStream {
+            client: self.clone(),
+            stream: self.track_events(stream),
+            uri,
+            session_id,
+            auth_header,
+            last_event_id: None,
+            retry: None,
+            answered: false,
+            resumptions: 0,
+        };
+
+        futures::stream::unfold(post_stream, |mut post_stream| async move {
+            loop {
+                match post_stream.stream.next().await {
+                    Some(Ok(sse)) => {
+                        post_stream.observe(&sse);
+                        return Some((Ok(sse), post_stream));
+                    }
+                    // rmcp reconnects streams that fail with an error itself
+                    Some(Err(e)) => return Some((Err(e), post_stream)),
+                    None => post_stream.stream = post_stream.resume().await?,
+                }
+            }
+        })
+        .boxed()
+    }
+}
+
+/// State of one POST response stream
+struct PostStream {
+    client: SessionHttpClient,
+    stream: SseEventStream,
+    uri: Arc<str>,
+    session_id: Option<Arc<str>>,
+    auth_header: Option<String>,
+    last_event_id: Option<String>,
+    /// Reconnect delay requested by the server
+    retry: Option<Duration>,
+    /// The JSON-RPC response (or error) arrived; nothing left to resume
+    answered: bool,
+    resumptions: u32,
+}
+
+impl PostStream {
+    fn observe(&mut self, sse: &Sse) {
+        if let Some(id) = &sse.id {
+            self.last_event_id = Some(id.clone());
+        }
+        if let Some(retry) = sse.retry {
+            self.retry = Some(Duration::from_millis(retry));
+        }
+        if !self.answered && is_response(sse) {
+            self.answered = true;
+        }
+    }
+
+    /// Reopen the stream with a GET from the last event id (None when there is nothing to resume)
+    async fn resume(&mut self) -> Option<SseEventStream> {
+        if self.answered || self.resumptions >= MAX_STREAM_RESUMPTIONS {
+            return None;
+        }
+        let (Some(session_id), Some(last_event_id)) =
+            (self.session_id.clone(), self.last_event_id.clone())
+        else {
+            return None;
+        };
+        self.resumptions += 1;
+
+        tokio::time::sleep(self.retry.unwrap_or(DEFAULT_RESUME_DELAY)).await;
+        match self
+            .client
+            .get_stream(self.uri.clone(), session_id, Some(last_event_id), self.auth_header.clone())
+            .await
+        {
+            Ok(stream) => Some(stream),
+            Err(e) => {
+                warn!(target: "mcp_client", "Failed to resume stream of server {}: {}", self.client.server_id, e);
+                None
+            }
+        }
+    }
+}
+
+/// Whether an SSE event carries a JSON-RPC response or error
+fn is_response(sse: &Sse) -> bool {
+    if !matches!(sse.event.as_deref(), None | Some("") | Some("message")) {
+        return false;
+    }
+    let Some(data) = sse.data.as_deref() else {
+        return false;
+    };
+    matches!(
+        serde_json::from_str::<ServerJsonRpcMessage>(data),
+        Ok(ServerJsonRpcMessage::Response(_) | ServerJsonRpcMessage::Error(_))
+    )
+}
+
+impl StreamableHttpClient for SessionHttpClient {
+    type Error = reqwest::Error;
+
+    async fn post_message(
+        &self,
+        uri: Arc<str>,
+        message: ClientJsonRpcMessage,
+        session_id: Option<Arc<str>>,
+        auth_header: Option<String>,
+    ) -> Result<StreamableHttpPostResponse, StreamableHttpError<Self::Error>> {
+        let body = serde_json::to_string(&message)?;
+        self.traffic.outbound(&body);
+
+        let mut request = self
+            .client
+            .post(uri.as_ref())
+            .header(ACCEPT, [EVENT_STREAM_MIME_TYPE, JSON_MIME_TYPE].join(", "))
+            .header(CONTENT_TYPE, JSON_MIME_TYPE)
+            .body(body);
+        if let Some(auth_header) = auth_header.clone() {
+            request = request.bearer_auth(auth_header);
+        }
+        if let Some(session_id) = &session_id {
+            request = request.header(HEADER_SESSION_ID, session_id.as_ref());
+        }
+
+        let response = request.send().await?;
+        if response.status() == StatusCode::UNAUTHORIZED {
+            if let Some(header) = response.headers().get(WWW_AUTHENTICATE) {
+                let www_authenticate_header = header
+                    .to_str()
+                    .map_err(|_| {
+                        StreamableHttpError::UnexpectedServerResponse(Cow::from(
+                            "invalid www-authenticate header value",
+                        ))
+                    })?
+                    .to_string();
+                return Err(StreamableHttpError::AuthRequired(AuthRequiredError {
+                    www_authenticate_header,
+                }));
+            }
+        }
+        let status = response.status();
+        let response = response.error_for_status()?;
+        if matches!(status, StatusCode::ACCEPTED | StatusCode::NO_CONTENT) {
+            return Ok(StreamableHttpPostResponse::Accepted);
+        }
+
+        let new_session_id = response
+            .headers()
+            .get(HEADER_SESSION_ID)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        self.record_session_id(new_session_id.as_deref());
+
+        let content_type = response.headers().get(CONTENT_TYPE).cloned();
+        match content_type {
+            Some(ct) if ct.as_bytes().starts_with(EVENT_STREAM_MIME_TYPE.as_bytes()) => {
+                let stream = SseStream::from_bytes_stream(response.bytes_stream()).boxed();
+                let session_id = session_id.or_else(|| new_session_id.as_deref().map(Arc::from));
+                let stream = self.resumable(stream, uri, session_id, auth_header);
+                Ok(StreamableHttpPostResponse::Sse(stream, new_session_id))
+            }
+            Some(ct) if ct.as_bytes().starts_with(JSON_MIME_TYPE.as_bytes()) => {
+                let text = response.text().await?;
+                self.traffic.inbound(&text);
+                let message: ServerJsonRpcMessage = serde_json::from_str(&text)?;
+                Ok(StreamableHttpPostResponse::Json(message, new_session_id))
+            }
+            _ => Err(StreamableHttpError::UnexpectedContentType(
+                content_type.map(|ct| String::from_utf8_lossy(ct.as_bytes()).to_string()),
+            )),
+        }
+    }
+
+    async fn delete_session(
+        &self,
+        uri: Arc<str>,
+        session_id: Arc<str>,
+        auth_header: Option<String>,
+    ) -> Result<(), StreamableHttpError<Self::Error>> {
+        let already_terminated = std::mem::replace(&mut self.lock().terminated, true);
+        if already_terminated {
+            debug!(target: "mcp_client", "Session {} of server {} already terminated", session_id, self.server_id);
+            return Ok(());
+        }
+
+        info!(target: "mcp_client", "Deleting session {} of server {}", session_id, self.server_id);
+        self.client.delete_session(uri, session_id, auth_header).await
+    }
+
+    async fn get_stream(
+        &self,
+        uri: Arc<str>,
+        session_id: Arc<str>,
+        last_event_id: Option<String>,
+        auth_header: Option<String>,
+    ) -> Result<SseEventStream, StreamableHttpError<Self::Error>> {
+        match &last_event_id {
+            // rmcp skips the standalone stream of servers without SSE support
+            None if !self.standalone_sse => {
+                debug!(target: "mcp_client", "Standalone SSE stream disabled for server {}", self.server_id);
+                return Err(StreamableHttpError::ServerDoesNotSupportSse);
+            }
+            Some(last_event_id) => {
+                info!(target: "mcp_client", "Resuming stream of server {} after event {}", self.server_id, last_event_id);
+                self.lock().resumptions += 1;
+            }
+            None => {}
+        }
+
+        let stream = self.client.get_stream(uri, session_id, last_event_id, auth_header).await?;
+        Ok(self.track_events(stream))
+    }
+}
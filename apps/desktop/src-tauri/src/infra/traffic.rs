@@ -0,0 +1,137 @@
+//! Traffic Tap - records every JSON-RPC frame a transport writes or reads, as it was on the wire.
+//!
+//! Each connection gets a `TrafficTap`. Transports hand it the exact text of every outbound
+//! and inbound frame (including initialize, notifications and heartbeats); a writer task
+//! stores the frames in order and publishes each one as an `mcp:traffic` event. Secrets of
+//! the environment the connection was resolved with are masked before frames leave the tap.
+//! The writer prunes the server's oldest frames every few hundred frames and when the
+//! connection ends.
+
+use serde_json::Value;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+use crate::domain::mcp::McpServerType;
+use crate::domain::traffic::{IMcpTrafficRepository, McpTrafficDirection, McpTrafficFrame};
+use crate::infra::clock::now_timestamp_millis;
+use crate::infra::environment::Variables;
+use crate::infra::event_publisher::EventPublisher;
+
+/// Frames stored between two prunes of the server's traffic
+const PRUNE_INTERVAL_FRAMES: u32 = 500;
+
+/// Recorder of one connection's frames (clones feed the same writer task)
+#[derive(Clone)]
+pub struct TrafficTap {
+    server_id: Arc<str>,
+    connection_id: Arc<str>,
+    transport: McpServerType,
+    tx: mpsc::UnboundedSender<McpTrafficFrame>,
+}
+
+impl TrafficTap {
+    /// Start the writer task; it ends once every clone of the tap is dropped
+    pub fn new(
+        server_id: &str,
+        transport: McpServerType,
+        repo: Option<Arc<dyn IMcpTrafficRepository>>,
+        event_publisher: Arc<dyn EventPublisher>,
+        variables: Variables,
+    ) -> Self {
+        let (tx, mut rx) = mpsc::unbounded_channel::<McpTrafficFrame>();
+
+        let writer_server_id = server_id.to_string();
+        tokio::spawn(async move {
+            let mut unpruned = 0;
+            while let Some(mut frame) = rx.recv().await {
+                frame.frame = variables.mask_secrets(&frame.frame);
+                let event_data = serde_json::to_value(&frame).unwrap_or(Value::Null);
+                if let Some(repo) = &repo {
+                    match repo.create(frame).await {
+                        Ok(_) => unpruned += 1,
+                        Err(e) => {
+                            warn!(target: "mcp_client", "Failed to save traffic frame: {}", e)
+                        }
+                    }
+                    if unpruned >= PRUNE_INTERVAL_FRAMES {
+                        unpruned = 0;
+                        prune(repo.as_ref(), &writer_server_id).await;
+                    }
+                }
+                event_publisher.publish("mcp:traffic", event_data).await;
+            }
+
+            if let Some(repo) = repo.filter(|_| unpruned > 0) {
+                prune(repo.as_ref(), &writer_server_id).await;
+            }
+        });
+
+        Self {
+            server_id: Arc::from(server_id),
+            connection_id: Arc::from(uuid::Uuid::new_v4().to_string()),
+            transport,
+            tx,
+        }
+    }
+
+    /// Id shared by all frames of this connection
+    pub fn connection_id(&self) -> &str {
+        &self.connection_id
+    }
+
+    /// A frame the client wrote
+    pub fn outbound(&self, text: &str) {
+        self.record(McpTrafficDirection::Outbound, text);
+    }
+
+    /// A frame the server sent
+    pub fn inbound(&self, text: &str) {
+        self.record(McpTrafficDirection::Inbound, text);
+    }
+
+    fn record(&self, direction: McpTrafficDirection, text: &str) {
+        let (kind, method, correlation_id) = classify(text);
+        let frame = McpTrafficFrame {
+            id: uuid::Uuid::new_v4().to_string(),
+            server_id: self.server_id.to_string(),
+            connection_id: self.connection_id.to_string(),
+            transport: self.transport.clone(),
+            direction,
+            kind: kind.to_string(),
+            method,
+            correlation_id,
+            frame: text.to_string(),
+            size_bytes: text.len() as i64,
+            created_at: now_timestamp_millis(),
+        };
+        // Only fails once the writer task is gone (runtime shutting down)
+        let _ = self.tx.send(frame);
+    }
+}
+
+async fn prune(repo: &dyn IMcpTrafficRepository, server_id: &str) {
+    if let Err(e) = repo.prune(server_id).await {
+        warn!(target: "mcp_client", "Failed to prune traffic of server {}: {}", server_id, e);
+    }
+}
+
+/// Kind, method and JSON-RPC id (as JSON text) of a frame
+fn classify(text: &str) -> (&'static str, Option<String>, Option<String>) {
+    let message = match serde_json::from_str::<Value>(text) {
+        Ok(Value::Object(message)) => message,
+        Ok(Value::Array(_)) => return ("batch", None, None),
+        _ => return ("invalid", None, None),
+    };
+
+    let method = message.get("method").and_then(Value::as_str).map(str::to_string);
+    let correlation_id = message.get("id").map(Value::to_string);
+    let kind = match (&method, &correlation_id) {
+        (Some(_), Some(_)) => "request",
+        (Some(_), None) => "notification",
+        (None, _) if message.contains_key("error") => "error",
+        (None, _) if message.contains_key("result") => "response",
+        _ => "invalid",
+    };
+    (kind, method, correlation_id)
+}
@@ -0,0 +1,298 @@
+//! Traffic Export - turns the recorded frames of a connection into files for bug reports.
+//!
+//! Two formats are written:
+//! - A newline-delimited JSON-RPC transcript (any transport): a header line with the server
+//!   and its initialize result, then one line per frame and per tool call. It is also the
+//!   format `parse_transcript` reads back when a transcript is imported.
+//! - A HAR file (SSE and streamable HTTP): one entry per POSTed frame, with the response
+//!   matched by JSON-RPC id. Frames carry no HTTP details, so headers are reduced to the
+//!   content type and server-initiated messages go to a `_mcpServerMessages` extension.
+
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, PrimitiveDateTime};
+
+use crate::domain::mcp::McpCallHistory;
+use crate::domain::traffic::{
+    McpTrafficDirection, McpTrafficFrame, McpTranscriptFrame, McpTranscriptHeader,
+    McpTranscriptLine, TRANSCRIPT_FORMAT, TRANSCRIPT_VERSION,
+};
+use crate::error::AppError;
+
+/// Parse a stored timestamp (UTC, with or without milliseconds)
+fn parse_timestamp(timestamp: &str) -> Option<OffsetDateTime> {
+    let with_millis = time::macros::format_description!(
+        "[year]-[month]-[day] [hour]:[minute]:[second].[subsecond]"
+    );
+    let without_millis =
+        time::macros::format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+
+    PrimitiveDateTime::parse(timestamp, &with_millis)
+        .or_else(|_| PrimitiveDateTime::parse(timestamp, &without_millis))
+        .ok()
+        .map(PrimitiveDateTime::assume_utc)
+}
+
+/// Milliseconds from `from` to `to` (0 when either can't be parsed)
+fn millis_between(from: &str, to: &str) -> i64 {
+    match (parse_timestamp(from), parse_timestamp(to)) {
+        (Some(from), Some(to)) => (to - from).whole_milliseconds() as i64,
+        _ => 0,
+    }
+}
+
+/// Timestamp as ISO 8601, as HAR requires
+fn iso8601(timestamp: &str) -> String {
+    parse_timestamp(timestamp)
+        .and_then(|t| t.format(&Rfc3339).ok())
+        .unwrap_or_else(|| timestamp.to_string())
+}
+
+/// Frames of a connection (oldest first) with their timings
+pub fn transcript_frames(frames: &[McpTrafficFrame]) -> Vec<McpTranscriptFrame> {
+    let Some(first) = frames.first() else {
+        return Vec::new();
+    };
+
+    // Requests waiting for their response, by direction and id
+    let mut pending: HashMap<(McpTrafficDirection, String), &str> = HashMap::new();
+
+    frames
+        .iter()
+        .map(|frame| {
+            let mut latency_ms = None;
+            if let Some(id) = &frame.correlation_id {
+                match frame.kind.as_str() {
+                    "request" => {
+                        pending.insert((frame.direction, id.clone()), &frame.created_at);
+                    }
+                    "response" | "error" => {
+                        let request_direction = match frame.direction {
+                            McpTrafficDirection::Inbound => McpTrafficDirection::Outbound,
+                            McpTrafficDirection::Outbound => McpTrafficDirection::Inbound,
+                        };
+                        latency_ms = pending
+                            .remove(&(request_direction, id.clone()))
+                            .map(|sent_at| millis_between(sent_at, &frame.created_at));
+                    }
+                    _ => {}
+                }
+            }
+
+            McpTranscriptFrame {
+                direction: frame.direction,
+                kind: frame.kind.clone(),
+                method: frame.method.clone(),
+                correlation_id: frame.correlation_id.clone(),
+                timestamp: frame.created_at.clone(),
+                elapsed_ms: millis_between(&first.created_at, &frame.created_at),
+                latency_ms,
+                size_bytes: frame.size_bytes,
+                frame: frame.frame.clone(),
+            }
+        })
+        .collect()
+}
+
+/// Initialize result the server answered with, if the handshake was captured
+pub fn server_info(frames: &[McpTrafficFrame]) -> Option<Value> {
+    let request = frames.iter().find(|f| {
+        f.direction == McpTrafficDirection::Outbound && f.method.as_deref() == Some("initialize")
+    })?;
+    let response = frames.iter().find(|f| {
+        f.direction == McpTrafficDirection::Inbound
+            && f.kind == "response"
+            && f.correlation_id == request.correlation_id
+    })?;
+
+    serde_json::from_str::<Value>(&response.frame).ok()?.get("result").cloned()
+}
+
+/// Write a transcript: the header, then frames and calls in time order
+pub fn transcript(
+    header: &McpTranscriptHeader,
+    frames: &[McpTranscriptFrame],
+    calls: &[McpCallHistory],
+) -> Result<String, AppError> {
+    let mut lines = vec![McpTranscriptLine::Header(header.clone())];
+
+    // History is saved once a call finished, with whole seconds: a call goes after the
+    // frames of the second it was saved in
+    let mut calls = calls.iter().peekable();
+    for frame in frames {
+        while let Some(call) =
+            calls.next_if(|call| format!("{}.999", call.created_at) < frame.timestamp)
+        {
+            lines.push(McpTranscriptLine::Call(call.clone()));
+        }
+        lines.push(McpTranscriptLine::Frame(frame.clone()));
+    }
+    lines.extend(calls.cloned().map(McpTranscriptLine::Call));
+
+    let mut content = String::new();
+    for line in &lines {
+        let line = serde_json::to_string(line)
+            .map_err(|e| AppError::Unknown(format!("Failed to serialize transcript: {}", e)))?;
+        content.push_str(&line);
+        content.push('\n');
+    }
+    Ok(content)
+}
+
+/// Read a transcript back; the first line must be a header this build understands
+pub fn parse_transcript(
+    content: &str,
+) -> Result<(McpTranscriptHeader, Vec<McpTranscriptFrame>, Vec<McpCallHistory>), AppError> {
+    let mut lines = content.lines().enumerate().filter(|(_, line)| !line.trim().is_empty());
+
+    let header = match lines.next() {
+        Some((_, line)) => match serde_json::from_str::<McpTranscriptLine>(line) {
+            Ok(McpTranscriptLine::Header(header)) => header,
+            _ => {
+                return Err(AppError::Domain("Transcript does not start with a header".to_string()))
+            }
+        },
+        None => return Err(AppError::Domain("Transcript is empty".to_string())),
+    };
+    if header.format != TRANSCRIPT_FORMAT {
+        return Err(AppError::Domain(format!("Not an MCP Studio transcript: {}", header.format)));
+    }
+    if header.version > TRANSCRIPT_VERSION {
+        return Err(AppError::Domain(format!(
+            "Transcript version {} is newer than this Studio supports ({})",
+            header.version, TRANSCRIPT_VERSION
+        )));
+    }
+
+    let mut frames = Vec::new();
+    let mut calls = Vec::new();
+    for (index, line) in lines {
+        match serde_json::from_str::<McpTranscriptLine>(line) {
+            Ok(McpTranscriptLine::Frame(frame)) => frames.push(frame),
+            Ok(McpTranscriptLine::Call(call)) => calls.push(call),
+            Ok(McpTranscriptLine::Header(_)) => {
+                return Err(AppError::Domain(format!("Unexpected header on line {}", index + 1)))
+            }
+            Err(e) => {
+                return Err(AppError::Domain(format!("Invalid line {}: {}", index + 1, e)));
+            }
+        }
+    }
+
+    Ok((header, frames, calls))
+}
+
+/// Write a HAR file; `url` is the endpoint the frames were POSTed to
+pub fn har(
+    header: &McpTranscriptHeader,
+    frames: &[McpTranscriptFrame],
+    url: &str,
+) -> Result<String, AppError> {
+    // Inbound frames used as the response of an entry, by index
+    let mut answered = HashSet::new();
+    let mut entries = Vec::new();
+
+    for frame in frames.iter().filter(|f| f.direction == McpTrafficDirection::Outbound) {
+        let response = match (&frame.correlation_id, frame.kind.as_str()) {
+            (Some(id), "request") => frames.iter().enumerate().find(|(index, f)| {
+                f.direction == McpTrafficDirection::Inbound
+                    && matches!(f.kind.as_str(), "response" | "error")
+                    && f.correlation_id.as_ref() == Some(id)
+                    && f.elapsed_ms >= frame.elapsed_ms
+                    && !answered.contains(index)
+            }),
+            _ => None,
+        };
+
+        let (status, status_text, text, wait_ms) = match response {
+            Some((index, response)) => {
+                answered.insert(index);
+                (200, "OK", response.frame.as_str(), response.latency_ms.unwrap_or(0))
+            }
+            // Notifications and responses to the server get no body
+            None if frame.kind != "request" => (202, "Accepted", "", 0),
+            None => (0, "No response", "", 0),
+        };
+
+        entries.push(json!({
+            "startedDateTime": iso8601(&frame.timestamp),
+            "time": wait_ms,
+            "request": {
+                "method": "POST",
+                "url": url,
+                "httpVersion": "HTTP/1.1",
+                "cookies": [],
+                "headers": [{ "name": "Content-Type", "value": "application/json" }],
+                "queryString": [],
+                "postData": { "mimeType": "application/json", "text": frame.frame },
+                "headersSize": -1,
+                "bodySize": frame.size_bytes,
+            },
+            "response": {
+                "status": status,
+                "statusText": status_text,
+                "httpVersion": "HTTP/1.1",
+                "cookies": [],
+                "headers": [],
+                "content": { "size": text.len(), "mimeType": "application/json", "text": text },
+                "redirectURL": "",
+                "headersSize": -1,
+                "bodySize": text.len(),
+            },
+            "cache": {},
+            "timings": { "send": 0, "wait": wait_ms, "receive": 0 },
+            "_mcpMethod": frame.method,
+            "_mcpCorrelationId": frame.correlation_id,
+        }));
+    }
+
+    let server_messages: Vec<Value> = frames
+        .iter()
+        .enumerate()
+        .filter(|(index, f)| {
+            f.direction == McpTrafficDirection::Inbound && !answered.contains(index)
+        })
+        .map(|(_, f)| {
+            json!({
+                "receivedDateTime": iso8601(&f.timestamp),
+                "kind": f.kind,
+                "method": f.method,
+                "correlationId": f.correlation_id,
+                "text": f.frame,
+            })
+        })
+        .collect();
+
+    // HAR requires a start time; fall back to the first frame, then to the export time
+    let started_at = header
+        .started_at
+        .as_deref()
+        .or_else(|| frames.first().map(|f| f.timestamp.as_str()))
+        .unwrap_or(&header.exported_at);
+
+    let page_id = format!("connection_{}", header.connection_id);
+    let har = json!({
+        "log": {
+            "version": "1.2",
+            "creator": { "name": "MCP Studio", "version": env!("CARGO_PKG_VERSION") },
+            "pages": [{
+                "startedDateTime": iso8601(started_at),
+                "id": page_id,
+                "title": format!("{} ({})", header.server.name, header.server.transport),
+                "pageTimings": {},
+            }],
+            "entries": entries.into_iter().map(|mut entry| {
+                entry["pageref"] = json!(page_id);
+                entry
+            }).collect::<Vec<_>>(),
+            "comment": "Reconstructed from JSON-RPC frames: responses are matched to requests by id, headers are not recorded",
+            "_mcpServer": header.server,
+            "_mcpServerInfo": header.server_info,
+            "_mcpServerMessages": server_messages,
+        }
+    });
+
+    serde_json::to_string_pretty(&har)
+        .map_err(|e| AppError::Unknown(format!("Failed to serialize HAR: {}", e)))
+}
@@ -0,0 +1,285 @@
+//! WebSocket Transport for MCP (Model Context Protocol)
+//!
+//! Speaks JSON-RPC over a WebSocket (`ws://` / `wss://`), one message per text frame,
+//! negotiating the `mcp` subprotocol. The connection is kept alive with ping frames;
+//! a server that stops answering them is treated as lost.
+
+use futures::{SinkExt, StreamExt};
+use reqwest::header::HeaderMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, error, info, warn};
+
+use rmcp::model::ServerJsonRpcMessage;
+use rmcp::transport::worker::{
+    Worker, WorkerContext, WorkerQuitReason, WorkerSendRequest, WorkerTransport,
+};
+use rmcp::RoleClient;
+
+use crate::infra::raw_rpc::{RawRpcChannel, RawRpcReply};
+use crate::infra::sse_transport::{describe_message, DisconnectCallback, StartupError};
+use crate::infra::traffic::TrafficTap;
+
+/// Subprotocol offered in the handshake
+const MCP_SUBPROTOCOL: &str = "mcp";
+
+/// Time between keepalive pings
+const PING_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Pings left unanswered before the connection is considered lost
+const MAX_MISSED_PONGS: u32 = 2;
+
+#[derive(Debug, thiserror::Error)]
+pub enum WebSocketTransportError {
+    #[error("Connection error: {0}")]
+    Connection(String),
+    #[error("WebSocket error: {0}")]
+    WebSocket(String),
+    #[error("Channel closed")]
+    Closed,
+    #[error("Join error: {0}")]
+    Join(String),
+}
+
+pub struct WebSocketWorker {
+    url: String,
+    headers: HeaderMap,
+    server_id: String,
+    raw_rpc: RawRpcChannel,
+    traffic: TrafficTap,
+    disconnect_callback: Option<DisconnectCallback>,
+    disconnect_notified: AtomicBool,
+    startup_error: StartupError,
+}
+
+impl WebSocketWorker {
+    /// `headers` are sent with the handshake (custom headers and auth)
+    pub fn new(
+        url: impl Into<String>,
+        headers: HeaderMap,
+        server_id: impl Into<String>,
+        raw_rpc: RawRpcChannel,
+        traffic: TrafficTap,
+        disconnect_callback: Option<DisconnectCallback>,
+        startup_error: StartupError,
+    ) -> Self {
+        Self {
+            url: url.into(),
+            headers,
+            server_id: server_id.into(),
+            raw_rpc,
+            traffic,
+            disconnect_callback,
+            disconnect_notified: AtomicBool::new(false),
+            startup_error,
+        }
+    }
+
+    fn notify_disconnect(&self, reason: &str) {
+        if self.disconnect_notified.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        info!(target: "websocket_transport", server_id = %self.server_id, reason = reason, "Notifying disconnect");
+
+        if let Some(callback) = &self.disconnect_callback {
+            callback(reason.to_string());
+        }
+    }
+
+    /// Forward a JSON-RPC frame to the handler; raw replies are claimed, other payloads logged and dropped
+    fn parse_message(&self, text: &str) -> Option<ServerJsonRpcMessage> {
+        self.traffic.inbound(text);
+        if self.raw_rpc.claim_reply(text) {
+            return None;
+        }
+
+        match serde_json::from_str::<ServerJsonRpcMessage>(text) {
+            Ok(jsonrpc_msg) => Some(jsonrpc_msg),
+            Err(e) => {
+                warn!(target: "websocket_transport", "Ignoring non JSON-RPC frame ({}): {}", e, text);
+                None
+            }
+        }
+    }
+}
+
+impl Worker for WebSocketWorker {
+    type Error = WebSocketTransportError;
+    type Role = RoleClient;
+
+    fn err_closed() -> Self::Error {
+        WebSocketTransportError::Closed
+    }
+
+    fn err_join(e: tokio::task::JoinError) -> Self::Error {
+        WebSocketTransportError::Join(e.to_string())
+    }
+
+    async fn run(
+        self,
+        mut context: WorkerContext<Self>,
+    ) -> Result<(), WorkerQuitReason<Self::Error>> {
+        info!(target: "websocket_transport", "Connecting to WebSocket endpoint: {}", self.url);
+
+        let mut request = self.url.as_str().into_client_request().map_err(|e| {
+            WorkerQuitReason::fatal(
+                WebSocketTransportError::Connection(format!("Invalid URL: {}", e)),
+                "building handshake request",
+            )
+        })?;
+        request.headers_mut().extend(self.headers.clone());
+        request
+            .headers_mut()
+            .insert("Sec-WebSocket-Protocol", HeaderValue::from_static(MCP_SUBPROTOCOL));
+
+        let (socket, response) = match tokio_tungstenite::connect_async(request).await {
+            Ok(connected) => connected,
+            Err(e) => {
+                error!(target: "websocket_transport", "WebSocket handshake failed: {}", e);
+                if let Ok(mut startup_error) = self.startup_error.lock() {
+                    *startup_error = Some(e.to_string());
+                }
+                self.notify_disconnect("websocket_handshake_error");
+                return Err(WorkerQuitReason::fatal(
+                    WebSocketTransportError::Connection(format!("Failed to connect: {}", e)),
+                    "connecting to WebSocket endpoint",
+                ));
+            }
+        };
+
+        info!(target: "websocket_transport", server_id = %self.server_id,
+            "WebSocket connected (status: {}, subprotocol: {:?})",
+            response.status(), response.headers().get("Sec-WebSocket-Protocol"));
+
+        let (mut sink, mut stream) = socket.split();
+        let ct = context.cancellation_token.clone();
+
+        let mut keepalive = tokio::time::interval(PING_INTERVAL);
+        keepalive.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        keepalive.tick().await; // The first tick completes immediately
+        let mut missed_pongs = 0u32;
+
+        loop {
+            tokio::select! {
+                // Handle outgoing messages from MCP client
+                request = context.recv_from_handler() => {
+                    let WorkerSendRequest { message, responder } = request?;
+
+                    debug!(target: "websocket_transport", "Sending {}", describe_message(&message));
+
+                    let text = serde_json::to_string(&message).map_err(|e| WorkerQuitReason::fatal(
+                        WebSocketTransportError::Connection(format!("Failed to serialize: {}", e)),
+                        "serializing message"
+                    ))?;
+                    self.traffic.outbound(&text);
+
+                    if let Err(e) = sink.send(Message::text(text)).await {
+                        error!(target: "websocket_transport", "Failed to send WebSocket frame: {}", e);
+                        self.notify_disconnect("websocket_send_error");
+                        let _ = responder.send(Err(WebSocketTransportError::WebSocket(e.to_string())));
+                        return Err(WorkerQuitReason::fatal(
+                            WebSocketTransportError::WebSocket("Send failed, terminating transport".to_string()),
+                            "sending WebSocket frame"
+                        ));
+                    }
+
+                    let _ = responder.send(Ok(()));
+                }
+
+                // Raw messages from the console
+                outgoing = self.raw_rpc.recv() => {
+                    self.traffic.outbound(&outgoing.text);
+                    let sent = sink.send(Message::text(outgoing.text)).await.map_err(|e| RawRpcReply {
+                        error: Some(format!("Failed to send WebSocket frame: {}", e)),
+                        ..Default::default()
+                    });
+                    let _ = outgoing.sent.send(sent);
+                }
+
+                // Handle incoming frames from the server
+                frame = stream.next() => {
+                    match frame {
+                        Some(Ok(Message::Text(text))) => {
+                            if let Some(jsonrpc_msg) = self.parse_message(text.as_str()) {
+                                context.send_to_handler(jsonrpc_msg).await?;
+                            }
+                        }
+                        Some(Ok(Message::Binary(data))) => {
+                            match std::str::from_utf8(&data) {
+                                Ok(text) => {
+                                    if let Some(jsonrpc_msg) = self.parse_message(text) {
+                                        context.send_to_handler(jsonrpc_msg).await?;
+                                    }
+                                }
+                                Err(_) => warn!(target: "websocket_transport", "Ignoring non UTF-8 binary frame ({} bytes)", data.len()),
+                            }
+                        }
+                        Some(Ok(Message::Pong(_))) => {
+                            debug!(target: "websocket_transport", "Received pong");
+                            missed_pongs = 0;
+                        }
+                        // Pings are answered by tungstenite
+                        Some(Ok(Message::Ping(_) | Message::Frame(_))) => {}
+                        Some(Ok(Message::Close(frame))) => {
+                            info!(target: "websocket_transport", server_id = %self.server_id, "WebSocket closed by server: {:?}", frame);
+                            self.notify_disconnect("websocket_closed");
+                            return Err(WorkerQuitReason::TransportClosed);
+                        }
+                        Some(Err(e)) => {
+                            error!(target: "websocket_transport", "WebSocket error: {}", e);
+                            self.notify_disconnect("websocket_error");
+                            return Err(WorkerQuitReason::fatal(
+                                WebSocketTransportError::WebSocket(e.to_string()),
+                                "receiving WebSocket frame"
+                            ));
+                        }
+                        None => {
+                            info!(target: "websocket_transport", server_id = %self.server_id, "WebSocket stream ended");
+                            self.notify_disconnect("websocket_closed");
+                            return Err(WorkerQuitReason::TransportClosed);
+                        }
+                    }
+                }
+
+                // Keepalive
+                _ = keepalive.tick() => {
+                    if missed_pongs >= MAX_MISSED_PONGS {
+                        warn!(target: "websocket_transport", server_id = %self.server_id, "No pong for {} pings", missed_pongs);
+                        self.notify_disconnect("websocket_pong_timeout");
+                        return Err(WorkerQuitReason::fatal(
+                            WebSocketTransportError::Connection(format!("No pong received for {} pings", missed_pongs)),
+                            "keepalive"
+                        ));
+                    }
+
+                    missed_pongs += 1;
+                    if let Err(e) = sink.send(Message::Ping(Default::default())).await {
+                        error!(target: "websocket_transport", "Failed to send ping: {}", e);
+                        self.notify_disconnect("websocket_send_error");
+                        return Err(WorkerQuitReason::fatal(
+                            WebSocketTransportError::WebSocket(e.to_string()),
+                            "sending ping"
+                        ));
+                    }
+                }
+
+                // Handle cancellation (disconnect)
+                _ = ct.cancelled() => {
+                    info!(target: "websocket_transport", "WebSocket transport cancelled");
+                    let close = CloseFrame { code: CloseCode::Normal, reason: "client disconnect".into() };
+                    let _ = sink.send(Message::Close(Some(close))).await;
+                    return Err(WorkerQuitReason::Cancelled);
+                }
+            }
+        }
+    }
+}
+
+pub type WebSocketTransport = WorkerTransport<WebSocketWorker>;
@@ -1,14 +1,68 @@
 use crate::application::{
-    ConfigCommandHandler, ConfigQueryHandler, McpCommandHandler, McpQueryHandler,
+    CollectionCommandHandler, CollectionQueryHandler, ConfigCommandHandler, ConfigQueryHandler,
+    ElicitationCommandHandler, ElicitationQueryHandler, EnvironmentCommandHandler,
+    EnvironmentQueryHandler, McpCommandHandler, McpQueryHandler, OAuthCommandHandler,
+    OAuthQueryHandler, SamplingCommandHandler, SamplingQueryHandler, ServerLogCommandHandler,
+    ServerLogQueryHandler, TestRunCommandHandler, TestRunQueryHandler, TrafficCommandHandler,
+    TrafficQueryHandler,
+};
+use crate::domain::collection::{
+    CreateMcpCollectionCmd, CreateMcpCollectionFolderCmd, CreateMcpSavedCallCmd,
+    DeleteMcpCollectionCmd, DeleteMcpCollectionFolderCmd, DeleteMcpSavedCallCmd,
+    GetMcpCollectionQuery, ListMcpCollectionsQuery, McpCollection, McpCollectionDetail,
+    McpCollectionFolder, McpCollectionRunReport, McpSavedCall, ReorderMcpCollectionItemsCmd,
+    ReorderMcpCollectionsCmd, RunMcpCollectionCmd, UpdateMcpCollectionCmd,
+    UpdateMcpCollectionFolderCmd, UpdateMcpSavedCallCmd,
 };
 use crate::domain::config::{GetAllConfigQuery, GetConfigQuery, SetConfigCmd};
 use crate::domain::cqrs::{CommandHandler, QueryHandler};
+use crate::domain::elicitation::{
+    CreateElicitationRuleCmd, DeleteElicitationRuleCmd, ElicitationAction, ElicitationRule,
+    ListElicitationRulesQuery, ListPendingElicitationRequestsQuery, PendingElicitationRequest,
+    RespondElicitationRequestCmd, UpdateElicitationRuleCmd,
+};
+use crate::domain::environment::{
+    CreateMcpEnvironmentCmd, DeleteMcpEnvironmentCmd, GetActiveMcpEnvironmentQuery,
+    ListMcpEnvironmentsQuery, McpEnvironment, SetActiveMcpEnvironmentCmd, UpdateMcpEnvironmentCmd,
+};
 use crate::domain::mcp::{
-    CallMcpToolCmd, ConnectMcpServerCmd, CreateMcpServerCmd, DeleteHttpReceivedMessageCmd,
-    DeleteMcpServerCmd, DisconnectMcpServerCmd, GetMcpCallHistoryQuery, GetMcpServerQuery,
-    GetMcpToolsQuery, HttpReceivedMessage, ListHttpReceivedMessagesQuery, ListMcpServersQuery,
-    MarkMcpServerDisconnectedCmd, McpCallHistory, McpServer, McpTool, McpToolCallResult,
-    McpToolsListResult, RefreshMcpToolsCmd, SaveHttpReceivedMessageCmd, UpdateMcpServerCmd,
+    CallMcpToolCmd, CancelMcpToolCallCmd, ConnectMcpServerCmd, CreateMcpServerCmd,
+    DeleteHttpReceivedMessageCmd, DeleteMcpServerCmd, DisconnectMcpServerCmd,
+    GetMcpCallHistoryQuery, GetMcpConnectionInfoQuery, GetMcpHeartbeatsQuery, GetMcpPromptCmd,
+    GetMcpPromptHistoryQuery, GetMcpPromptsQuery, GetMcpRawRpcHistoryQuery,
+    GetMcpResourceSubscriptionsQuery, GetMcpResourceTemplatesQuery, GetMcpResourcesQuery,
+    GetMcpServerQuery, GetMcpToolsQuery, HeartbeatStrategy, HttpReceivedMessage,
+    ListHttpReceivedMessagesQuery, ListMcpServersQuery, MarkMcpServerDisconnectedCmd,
+    McpCallHistory, McpCallReplay, McpConnectionInfo, McpHeartbeat, McpPrompt, McpPromptGetResult,
+    McpPromptHistory, McpPromptsListResult, McpRawRpcHistory, McpReconnectPolicy, McpResource,
+    McpResourceReadResult, McpResourceTemplate, McpResourcesListResult, McpRoot, McpServer,
+    McpSessionTermination, McpTool, McpToolCallResult, McpToolsListResult, ReadMcpResourceCmd,
+    RefreshMcpPromptsCmd, RefreshMcpResourcesCmd, RefreshMcpToolsCmd, ReplayMcpCallCmd,
+    SaveHttpReceivedMessageCmd, SendMcpRawRpcCmd, SetMcpServerHeartbeatCmd,
+    SetMcpServerReconnectPolicyCmd, SetMcpServerRootsCmd, SubscribeMcpResourceCmd,
+    TerminateMcpSessionCmd, UnsubscribeMcpResourceCmd, UpdateMcpServerCmd,
+};
+use crate::domain::oauth::{
+    ClearMcpOAuthCmd, GetMcpOAuthStatusQuery, McpOAuthStart, McpOAuthStatus,
+    RefreshMcpOAuthTokenCmd, StartMcpOAuthCmd,
+};
+use crate::domain::sampling::{
+    ClearSamplingExchangesCmd, CreateSamplingRuleCmd, DeleteSamplingRuleCmd,
+    ListPendingSamplingRequestsQuery, ListSamplingExchangesQuery, ListSamplingRulesQuery,
+    PendingSamplingRequest, RejectSamplingRequestCmd, RespondSamplingRequestCmd, SamplingExchange,
+    SamplingRule, UpdateSamplingRuleCmd,
+};
+use crate::domain::server_log::{
+    ClearMcpServerLogsCmd, ListMcpServerLogsQuery, McpLogLevel, McpServerLog, SetMcpLogLevelCmd,
+};
+use crate::domain::test_run::{
+    DeleteMcpTestRunCmd, GetMcpTestRunQuery, ListMcpTestRunsQuery, McpTestRun, McpTestRunDetail,
+    RunMcpTestsCmd,
+};
+use crate::domain::traffic::{
+    ClearMcpTrafficCmd, DeleteMcpTranscriptCmd, ExportMcpTrafficQuery, GetMcpTranscriptQuery,
+    ImportMcpTranscriptCmd, ListMcpTrafficQuery, ListMcpTranscriptsQuery, McpTrafficDirection,
+    McpTrafficExport, McpTrafficExportFormat, McpTrafficFrame, McpTranscript, McpTranscriptDetail,
 };
 use crate::error::AppError;
 use crate::infra::http::{HttpClient, HttpRequest, HttpResponse};
@@ -192,6 +246,22 @@ pub async fn disconnect_mcp_server(
     handler.handle(DisconnectMcpServerCmd { id }).await
 }
 
+#[tauri::command]
+pub async fn terminate_mcp_session(
+    handler: State<'_, McpCommandHandler>,
+    server_id: String,
+) -> Result<McpSessionTermination, AppError> {
+    handler.handle(TerminateMcpSessionCmd { server_id }).await
+}
+
+#[tauri::command]
+pub async fn get_mcp_connection_info(
+    handler: State<'_, McpQueryHandler>,
+    server_id: String,
+) -> Result<McpConnectionInfo, AppError> {
+    handler.handle(GetMcpConnectionInfoQuery { server_id }).await
+}
+
 #[tauri::command]
 pub async fn mark_mcp_server_disconnected(
     handler: State<'_, McpCommandHandler>,
@@ -201,6 +271,46 @@ pub async fn mark_mcp_server_disconnected(
     handler.handle(MarkMcpServerDisconnectedCmd { id, error }).await
 }
 
+#[tauri::command]
+pub async fn set_mcp_server_roots(
+    handler: State<'_, McpCommandHandler>,
+    server_id: String,
+    roots: Vec<McpRoot>,
+) -> Result<McpServer, AppError> {
+    handler.handle(SetMcpServerRootsCmd { server_id, roots }).await
+}
+
+#[tauri::command]
+pub async fn set_mcp_server_heartbeat(
+    handler: State<'_, McpCommandHandler>,
+    server_id: String,
+    strategy: HeartbeatStrategy,
+    interval_secs: Option<i64>,
+    max_failures: Option<i64>,
+) -> Result<McpServer, AppError> {
+    handler
+        .handle(SetMcpServerHeartbeatCmd { server_id, strategy, interval_secs, max_failures })
+        .await
+}
+
+#[tauri::command]
+pub async fn set_mcp_server_reconnect_policy(
+    handler: State<'_, McpCommandHandler>,
+    server_id: String,
+    policy: McpReconnectPolicy,
+) -> Result<McpServer, AppError> {
+    handler.handle(SetMcpServerReconnectPolicyCmd { server_id, policy }).await
+}
+
+#[tauri::command]
+pub async fn get_mcp_heartbeats(
+    handler: State<'_, McpQueryHandler>,
+    server_id: String,
+    limit: Option<i64>,
+) -> Result<Vec<McpHeartbeat>, AppError> {
+    handler.handle(GetMcpHeartbeatsQuery { server_id, limit }).await
+}
+
 // --- MCP Tools Commands ---
 
 #[tauri::command]
@@ -225,8 +335,28 @@ pub async fn call_mcp_tool(
     server_id: String,
     tool_name: String,
     params: Option<serde_json::Value>,
+    call_id: Option<String>,
+    timeout_ms: Option<u64>,
 ) -> Result<McpToolCallResult, AppError> {
-    handler.handle(CallMcpToolCmd { server_id, tool_name, params }).await
+    handler.handle(CallMcpToolCmd { server_id, tool_name, params, call_id, timeout_ms }).await
+}
+
+#[tauri::command]
+pub async fn cancel_mcp_tool_call(
+    handler: State<'_, McpCommandHandler>,
+    call_id: String,
+) -> Result<(), AppError> {
+    handler.handle(CancelMcpToolCallCmd { call_id }).await
+}
+
+#[tauri::command]
+pub async fn replay_mcp_call(
+    handler: State<'_, McpCommandHandler>,
+    history_id: String,
+    server_id: Option<String>,
+    timeout_ms: Option<u64>,
+) -> Result<McpCallReplay, AppError> {
+    handler.handle(ReplayMcpCallCmd { history_id, server_id, timeout_ms }).await
 }
 
 #[tauri::command]
@@ -239,6 +369,104 @@ pub async fn export_mcp_tools_json(
         .map_err(|e| AppError::Unknown(format!("Failed to serialize tools: {}", e)))
 }
 
+// --- MCP Resources Commands ---
+
+#[tauri::command]
+pub async fn refresh_mcp_resources(
+    handler: State<'_, McpCommandHandler>,
+    server_id: String,
+) -> Result<McpResourcesListResult, AppError> {
+    handler.handle(RefreshMcpResourcesCmd { server_id }).await
+}
+
+#[tauri::command]
+pub async fn get_mcp_resources(
+    handler: State<'_, McpQueryHandler>,
+    server_id: String,
+) -> Result<Vec<McpResource>, AppError> {
+    handler.handle(GetMcpResourcesQuery { server_id }).await
+}
+
+#[tauri::command]
+pub async fn get_mcp_resource_templates(
+    handler: State<'_, McpQueryHandler>,
+    server_id: String,
+) -> Result<Vec<McpResourceTemplate>, AppError> {
+    handler.handle(GetMcpResourceTemplatesQuery { server_id }).await
+}
+
+#[tauri::command]
+pub async fn read_mcp_resource(
+    handler: State<'_, McpCommandHandler>,
+    server_id: String,
+    uri: String,
+) -> Result<McpResourceReadResult, AppError> {
+    handler.handle(ReadMcpResourceCmd { server_id, uri }).await
+}
+
+#[tauri::command]
+pub async fn subscribe_mcp_resource(
+    handler: State<'_, McpCommandHandler>,
+    server_id: String,
+    uri: String,
+) -> Result<(), AppError> {
+    handler.handle(SubscribeMcpResourceCmd { server_id, uri }).await
+}
+
+#[tauri::command]
+pub async fn unsubscribe_mcp_resource(
+    handler: State<'_, McpCommandHandler>,
+    server_id: String,
+    uri: String,
+) -> Result<(), AppError> {
+    handler.handle(UnsubscribeMcpResourceCmd { server_id, uri }).await
+}
+
+#[tauri::command]
+pub async fn get_mcp_resource_subscriptions(
+    handler: State<'_, McpQueryHandler>,
+    server_id: String,
+) -> Result<Vec<String>, AppError> {
+    handler.handle(GetMcpResourceSubscriptionsQuery { server_id }).await
+}
+
+// --- MCP Prompts Commands ---
+
+#[tauri::command]
+pub async fn refresh_mcp_prompts(
+    handler: State<'_, McpCommandHandler>,
+    server_id: String,
+) -> Result<McpPromptsListResult, AppError> {
+    handler.handle(RefreshMcpPromptsCmd { server_id }).await
+}
+
+#[tauri::command]
+pub async fn get_mcp_prompts(
+    handler: State<'_, McpQueryHandler>,
+    server_id: String,
+) -> Result<Vec<McpPrompt>, AppError> {
+    handler.handle(GetMcpPromptsQuery { server_id }).await
+}
+
+#[tauri::command]
+pub async fn get_mcp_prompt(
+    handler: State<'_, McpCommandHandler>,
+    server_id: String,
+    prompt_name: String,
+    arguments: Option<serde_json::Value>,
+) -> Result<McpPromptGetResult, AppError> {
+    handler.handle(GetMcpPromptCmd { server_id, prompt_name, arguments }).await
+}
+
+#[tauri::command]
+pub async fn get_mcp_prompt_history(
+    handler: State<'_, McpQueryHandler>,
+    server_id: Option<String>,
+    limit: Option<i64>,
+) -> Result<Vec<McpPromptHistory>, AppError> {
+    handler.handle(GetMcpPromptHistoryQuery { server_id, limit }).await
+}
+
 // --- MCP Call History ---
 
 #[tauri::command]
@@ -250,6 +478,484 @@ pub async fn get_mcp_call_history(
     handler.handle(GetMcpCallHistoryQuery { server_id, limit }).await
 }
 
+// --- MCP Raw JSON-RPC ---
+
+#[tauri::command]
+pub async fn send_mcp_raw_rpc(
+    handler: State<'_, McpCommandHandler>,
+    server_id: String,
+    message: String,
+    timeout_ms: Option<u64>,
+) -> Result<McpRawRpcHistory, AppError> {
+    handler.handle(SendMcpRawRpcCmd { server_id, message, timeout_ms }).await
+}
+
+#[tauri::command]
+pub async fn get_mcp_raw_rpc_history(
+    handler: State<'_, McpQueryHandler>,
+    server_id: Option<String>,
+    limit: Option<i64>,
+) -> Result<Vec<McpRawRpcHistory>, AppError> {
+    handler.handle(GetMcpRawRpcHistoryQuery { server_id, limit }).await
+}
+
+// --- Sampling Commands ---
+
+#[tauri::command]
+pub async fn list_sampling_rules(
+    handler: State<'_, SamplingQueryHandler>,
+) -> Result<Vec<SamplingRule>, AppError> {
+    handler.handle(ListSamplingRulesQuery).await
+}
+
+#[tauri::command]
+pub async fn create_sampling_rule(
+    handler: State<'_, SamplingCommandHandler>,
+    cmd: CreateSamplingRuleCmd,
+) -> Result<SamplingRule, AppError> {
+    handler.handle(cmd).await
+}
+
+#[tauri::command]
+pub async fn update_sampling_rule(
+    handler: State<'_, SamplingCommandHandler>,
+    cmd: UpdateSamplingRuleCmd,
+) -> Result<SamplingRule, AppError> {
+    handler.handle(cmd).await
+}
+
+#[tauri::command]
+pub async fn delete_sampling_rule(
+    handler: State<'_, SamplingCommandHandler>,
+    id: String,
+) -> Result<(), AppError> {
+    handler.handle(DeleteSamplingRuleCmd { id }).await
+}
+
+#[tauri::command]
+pub async fn list_pending_sampling_requests(
+    handler: State<'_, SamplingQueryHandler>,
+) -> Result<Vec<PendingSamplingRequest>, AppError> {
+    handler.handle(ListPendingSamplingRequestsQuery).await
+}
+
+#[tauri::command]
+pub async fn respond_sampling_request(
+    handler: State<'_, SamplingCommandHandler>,
+    cmd: RespondSamplingRequestCmd,
+) -> Result<(), AppError> {
+    handler.handle(cmd).await
+}
+
+#[tauri::command]
+pub async fn reject_sampling_request(
+    handler: State<'_, SamplingCommandHandler>,
+    request_id: String,
+    message: Option<String>,
+) -> Result<(), AppError> {
+    handler.handle(RejectSamplingRequestCmd { request_id, message }).await
+}
+
+#[tauri::command]
+pub async fn list_sampling_exchanges(
+    handler: State<'_, SamplingQueryHandler>,
+    server_id: Option<String>,
+    limit: Option<i64>,
+) -> Result<Vec<SamplingExchange>, AppError> {
+    handler.handle(ListSamplingExchangesQuery { server_id, limit }).await
+}
+
+#[tauri::command]
+pub async fn clear_sampling_exchanges(
+    handler: State<'_, SamplingCommandHandler>,
+    server_id: Option<String>,
+) -> Result<(), AppError> {
+    handler.handle(ClearSamplingExchangesCmd { server_id }).await
+}
+
+// --- Elicitation Commands ---
+
+#[tauri::command]
+pub async fn list_elicitation_rules(
+    handler: State<'_, ElicitationQueryHandler>,
+) -> Result<Vec<ElicitationRule>, AppError> {
+    handler.handle(ListElicitationRulesQuery).await
+}
+
+#[tauri::command]
+pub async fn create_elicitation_rule(
+    handler: State<'_, ElicitationCommandHandler>,
+    cmd: CreateElicitationRuleCmd,
+) -> Result<ElicitationRule, AppError> {
+    handler.handle(cmd).await
+}
+
+#[tauri::command]
+pub async fn update_elicitation_rule(
+    handler: State<'_, ElicitationCommandHandler>,
+    cmd: UpdateElicitationRuleCmd,
+) -> Result<ElicitationRule, AppError> {
+    handler.handle(cmd).await
+}
+
+#[tauri::command]
+pub async fn delete_elicitation_rule(
+    handler: State<'_, ElicitationCommandHandler>,
+    id: String,
+) -> Result<(), AppError> {
+    handler.handle(DeleteElicitationRuleCmd { id }).await
+}
+
+#[tauri::command]
+pub async fn list_pending_elicitation_requests(
+    handler: State<'_, ElicitationQueryHandler>,
+) -> Result<Vec<PendingElicitationRequest>, AppError> {
+    handler.handle(ListPendingElicitationRequestsQuery).await
+}
+
+#[tauri::command]
+pub async fn respond_elicitation_request(
+    handler: State<'_, ElicitationCommandHandler>,
+    request_id: String,
+    action: ElicitationAction,
+    content: Option<serde_json::Value>,
+) -> Result<(), AppError> {
+    handler.handle(RespondElicitationRequestCmd { request_id, action, content }).await
+}
+
+// --- MCP Server Log Commands ---
+
+#[tauri::command]
+pub async fn list_mcp_server_logs(
+    handler: State<'_, ServerLogQueryHandler>,
+    server_id: Option<String>,
+    min_level: Option<McpLogLevel>,
+    limit: Option<i64>,
+) -> Result<Vec<McpServerLog>, AppError> {
+    handler.handle(ListMcpServerLogsQuery { server_id, min_level, limit }).await
+}
+
+#[tauri::command]
+pub async fn set_mcp_log_level(
+    handler: State<'_, ServerLogCommandHandler>,
+    server_id: String,
+    level: McpLogLevel,
+) -> Result<(), AppError> {
+    handler.handle(SetMcpLogLevelCmd { server_id, level }).await
+}
+
+#[tauri::command]
+pub async fn clear_mcp_server_logs(
+    handler: State<'_, ServerLogCommandHandler>,
+    server_id: Option<String>,
+) -> Result<(), AppError> {
+    handler.handle(ClearMcpServerLogsCmd { server_id }).await
+}
+
+// --- MCP Traffic Commands ---
+
+#[tauri::command]
+pub async fn list_mcp_traffic(
+    handler: State<'_, TrafficQueryHandler>,
+    server_id: Option<String>,
+    connection_id: Option<String>,
+    direction: Option<McpTrafficDirection>,
+    correlation_id: Option<String>,
+    limit: Option<i64>,
+) -> Result<Vec<McpTrafficFrame>, AppError> {
+    handler
+        .handle(ListMcpTrafficQuery { server_id, connection_id, direction, correlation_id, limit })
+        .await
+}
+
+#[tauri::command]
+pub async fn clear_mcp_traffic(
+    handler: State<'_, TrafficCommandHandler>,
+    server_id: Option<String>,
+) -> Result<(), AppError> {
+    handler.handle(ClearMcpTrafficCmd { server_id }).await
+}
+
+/// Exports one connection's traffic (the most recent one by default); the UI saves the content
+#[tauri::command]
+pub async fn export_mcp_traffic(
+    handler: State<'_, TrafficQueryHandler>,
+    server_id: String,
+    connection_id: Option<String>,
+    format: McpTrafficExportFormat,
+) -> Result<McpTrafficExport, AppError> {
+    handler.handle(ExportMcpTrafficQuery { server_id, connection_id, format }).await
+}
+
+#[tauri::command]
+pub async fn import_mcp_transcript(
+    handler: State<'_, TrafficCommandHandler>,
+    name: Option<String>,
+    content: String,
+) -> Result<McpTranscript, AppError> {
+    handler.handle(ImportMcpTranscriptCmd { name, content }).await
+}
+
+#[tauri::command]
+pub async fn list_mcp_transcripts(
+    handler: State<'_, TrafficQueryHandler>,
+) -> Result<Vec<McpTranscript>, AppError> {
+    handler.handle(ListMcpTranscriptsQuery).await
+}
+
+#[tauri::command]
+pub async fn get_mcp_transcript(
+    handler: State<'_, TrafficQueryHandler>,
+    id: String,
+) -> Result<McpTranscriptDetail, AppError> {
+    handler.handle(GetMcpTranscriptQuery { id }).await
+}
+
+#[tauri::command]
+pub async fn delete_mcp_transcript(
+    handler: State<'_, TrafficCommandHandler>,
+    id: String,
+) -> Result<(), AppError> {
+    handler.handle(DeleteMcpTranscriptCmd { id }).await
+}
+
+// --- Collection Commands ---
+
+#[tauri::command]
+pub async fn list_mcp_collections(
+    handler: State<'_, CollectionQueryHandler>,
+) -> Result<Vec<McpCollection>, AppError> {
+    handler.handle(ListMcpCollectionsQuery).await
+}
+
+#[tauri::command]
+pub async fn get_mcp_collection(
+    handler: State<'_, CollectionQueryHandler>,
+    id: String,
+) -> Result<McpCollectionDetail, AppError> {
+    handler.handle(GetMcpCollectionQuery { id }).await
+}
+
+#[tauri::command]
+pub async fn create_mcp_collection(
+    handler: State<'_, CollectionCommandHandler>,
+    cmd: CreateMcpCollectionCmd,
+) -> Result<McpCollection, AppError> {
+    handler.handle(cmd).await
+}
+
+#[tauri::command]
+pub async fn update_mcp_collection(
+    handler: State<'_, CollectionCommandHandler>,
+    cmd: UpdateMcpCollectionCmd,
+) -> Result<McpCollection, AppError> {
+    handler.handle(cmd).await
+}
+
+#[tauri::command]
+pub async fn delete_mcp_collection(
+    handler: State<'_, CollectionCommandHandler>,
+    id: String,
+) -> Result<(), AppError> {
+    handler.handle(DeleteMcpCollectionCmd { id }).await
+}
+
+#[tauri::command]
+pub async fn reorder_mcp_collections(
+    handler: State<'_, CollectionCommandHandler>,
+    ids: Vec<String>,
+) -> Result<Vec<McpCollection>, AppError> {
+    handler.handle(ReorderMcpCollectionsCmd { ids }).await
+}
+
+#[tauri::command]
+pub async fn create_mcp_collection_folder(
+    handler: State<'_, CollectionCommandHandler>,
+    cmd: CreateMcpCollectionFolderCmd,
+) -> Result<McpCollectionFolder, AppError> {
+    handler.handle(cmd).await
+}
+
+#[tauri::command]
+pub async fn update_mcp_collection_folder(
+    handler: State<'_, CollectionCommandHandler>,
+    cmd: UpdateMcpCollectionFolderCmd,
+) -> Result<McpCollectionFolder, AppError> {
+    handler.handle(cmd).await
+}
+
+#[tauri::command]
+pub async fn delete_mcp_collection_folder(
+    handler: State<'_, CollectionCommandHandler>,
+    id: String,
+) -> Result<(), AppError> {
+    handler.handle(DeleteMcpCollectionFolderCmd { id }).await
+}
+
+#[tauri::command]
+pub async fn create_mcp_saved_call(
+    handler: State<'_, CollectionCommandHandler>,
+    cmd: CreateMcpSavedCallCmd,
+) -> Result<McpSavedCall, AppError> {
+    handler.handle(cmd).await
+}
+
+#[tauri::command]
+pub async fn update_mcp_saved_call(
+    handler: State<'_, CollectionCommandHandler>,
+    cmd: UpdateMcpSavedCallCmd,
+) -> Result<McpSavedCall, AppError> {
+    handler.handle(cmd).await
+}
+
+#[tauri::command]
+pub async fn delete_mcp_saved_call(
+    handler: State<'_, CollectionCommandHandler>,
+    id: String,
+) -> Result<(), AppError> {
+    handler.handle(DeleteMcpSavedCallCmd { id }).await
+}
+
+#[tauri::command]
+pub async fn reorder_mcp_collection_items(
+    handler: State<'_, CollectionCommandHandler>,
+    collection_id: String,
+    folder_id: Option<String>,
+    item_ids: Vec<String>,
+) -> Result<(), AppError> {
+    handler.handle(ReorderMcpCollectionItemsCmd { collection_id, folder_id, item_ids }).await
+}
+
+#[tauri::command]
+pub async fn run_mcp_collection(
+    handler: State<'_, CollectionCommandHandler>,
+    cmd: RunMcpCollectionCmd,
+) -> Result<McpCollectionRunReport, AppError> {
+    handler.handle(cmd).await
+}
+
+// --- Test Run Commands ---
+
+#[tauri::command]
+pub async fn run_mcp_tests(
+    handler: State<'_, TestRunCommandHandler>,
+    cmd: RunMcpTestsCmd,
+) -> Result<McpTestRunDetail, AppError> {
+    handler.handle(cmd).await
+}
+
+#[tauri::command]
+pub async fn list_mcp_test_runs(
+    handler: State<'_, TestRunQueryHandler>,
+    collection_id: Option<String>,
+    limit: Option<i64>,
+) -> Result<Vec<McpTestRun>, AppError> {
+    handler.handle(ListMcpTestRunsQuery { collection_id, limit }).await
+}
+
+#[tauri::command]
+pub async fn get_mcp_test_run(
+    handler: State<'_, TestRunQueryHandler>,
+    id: String,
+) -> Result<McpTestRunDetail, AppError> {
+    handler.handle(GetMcpTestRunQuery { id }).await
+}
+
+#[tauri::command]
+pub async fn delete_mcp_test_run(
+    handler: State<'_, TestRunCommandHandler>,
+    id: String,
+) -> Result<(), AppError> {
+    handler.handle(DeleteMcpTestRunCmd { id }).await
+}
+
+// --- Environment Commands ---
+
+#[tauri::command]
+pub async fn list_mcp_environments(
+    handler: State<'_, EnvironmentQueryHandler>,
+) -> Result<Vec<McpEnvironment>, AppError> {
+    handler.handle(ListMcpEnvironmentsQuery).await
+}
+
+#[tauri::command]
+pub async fn get_active_mcp_environment(
+    handler: State<'_, EnvironmentQueryHandler>,
+) -> Result<Option<McpEnvironment>, AppError> {
+    handler.handle(GetActiveMcpEnvironmentQuery).await
+}
+
+#[tauri::command]
+pub async fn create_mcp_environment(
+    handler: State<'_, EnvironmentCommandHandler>,
+    cmd: CreateMcpEnvironmentCmd,
+) -> Result<McpEnvironment, AppError> {
+    handler.handle(cmd).await
+}
+
+#[tauri::command]
+pub async fn update_mcp_environment(
+    handler: State<'_, EnvironmentCommandHandler>,
+    cmd: UpdateMcpEnvironmentCmd,
+) -> Result<McpEnvironment, AppError> {
+    handler.handle(cmd).await
+}
+
+#[tauri::command]
+pub async fn delete_mcp_environment(
+    handler: State<'_, EnvironmentCommandHandler>,
+    id: String,
+) -> Result<(), AppError> {
+    handler.handle(DeleteMcpEnvironmentCmd { id }).await
+}
+
+/// id: None turns substitution off
+#[tauri::command]
+pub async fn set_active_mcp_environment(
+    handler: State<'_, EnvironmentCommandHandler>,
+    id: Option<String>,
+) -> Result<Option<McpEnvironment>, AppError> {
+    handler.handle(SetActiveMcpEnvironmentCmd { id }).await
+}
+
+// --- MCP OAuth Commands ---
+
+/// Starts the authorization flow; the embedded HTTP server receives the redirect
+#[tauri::command]
+pub async fn start_mcp_oauth(
+    handler: State<'_, OAuthCommandHandler>,
+    http_server: State<'_, HttpServerManager>,
+    server_id: String,
+) -> Result<McpOAuthStart, AppError> {
+    let port = http_server.ensure_started().await?;
+    let redirect_uri = format!("http://127.0.0.1:{}/oauth/callback", port);
+    handler.handle(StartMcpOAuthCmd { server_id, redirect_uri }).await
+}
+
+#[tauri::command]
+pub async fn refresh_mcp_oauth_token(
+    handler: State<'_, OAuthCommandHandler>,
+    server_id: String,
+) -> Result<McpOAuthStatus, AppError> {
+    handler.handle(RefreshMcpOAuthTokenCmd { server_id }).await
+}
+
+#[tauri::command]
+pub async fn clear_mcp_oauth(
+    handler: State<'_, OAuthCommandHandler>,
+    server_id: String,
+) -> Result<(), AppError> {
+    handler.handle(ClearMcpOAuthCmd { server_id }).await
+}
+
+#[tauri::command]
+pub async fn get_mcp_oauth_status(
+    handler: State<'_, OAuthQueryHandler>,
+    server_id: String,
+) -> Result<McpOAuthStatus, AppError> {
+    handler.handle(GetMcpOAuthStatusQuery { server_id }).await
+}
+
 // --- HTTP Server Commands ---
 
 #[tauri::command]
@@ -0,0 +1,344 @@
+pub mod application;
+pub mod domain;
+pub mod error;
+pub mod infra;
+#[cfg(feature = "desktop")]
+pub mod interface;
+
+// The app itself (window, tray, plugins) only builds with the desktop feature
+#[cfg(feature = "desktop")]
+use std::sync::Arc;
+#[cfg(feature = "desktop")]
+use tauri::{Emitter, Manager, WindowEvent};
+#[cfg(feature = "desktop")]
+use tauri_plugin_window_state::{AppHandleExt, StateFlags};
+#[cfg(feature = "desktop")]
+use tracing::{error, info};
+
+#[cfg(feature = "desktop")]
+use crate::domain::mcp::{HttpReceivedMessage, IHttpReceivedMessageRepository};
+#[cfg(feature = "desktop")]
+use crate::infra::repo_mcp::SqliteHttpReceivedMessageRepository;
+
+// State wrapper to keep the file logger guard alive
+#[cfg(feature = "desktop")]
+struct LogGuardState(#[allow(dead_code)] infra::logging::WorkerGuard);
+
+/// Start the desktop app
+#[cfg(feature = "desktop")]
+pub fn run() {
+    tauri::Builder::default()
+        .plugin(tauri_plugin_fs::init())
+        .plugin(tauri_plugin_shell::init())
+        .plugin(tauri_plugin_dialog::init())
+        // 1. Window State (Auto save/restore position & size)
+        .plugin(tauri_plugin_window_state::Builder::default().build())
+        // 2. Single Instance Lock
+        .plugin(tauri_plugin_single_instance::init(|app, _args, _cwd| {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.set_focus();
+            }
+        }))
+        .setup(|app| {
+            // 1. Initialize Logging
+            let guard = infra::logging::setup_logging(app.handle())?;
+            app.manage(LogGuardState(guard));
+
+            // 2. Initialize Tray (Desktop Only)
+            #[cfg(desktop)]
+            {
+                interface::tray::create_tray(app.handle())?;
+            }
+
+            // 3. Initialize HTTP Client
+            let http_client = infra::http::HttpClient::new()
+                .map_err(|e| format!("Failed to init HTTP client: {}", e))?;
+            app.manage(http_client);
+
+            // 4. Create Event Publishers (Infra)
+            // Domain event publisher (for config changes etc.)
+            let domain_publisher: Arc<dyn crate::domain::events::IEventPublisher> = Arc::new(
+                infra::event_publisher::TauriEventPublisher::new(app.handle().clone())
+            );
+            // Generic event publisher (for MCP connection events)
+            let generic_publisher: Arc<dyn infra::event_publisher::EventPublisher> = Arc::new(
+                infra::event_publisher::TauriGenericEventPublisher::new(app.handle().clone())
+            );
+
+            // 5. Initialize HTTP Server Manager with app data path
+            let http_server_manager = infra::http_server::HttpServerManager::new();
+
+            // Set storage path to app data directory
+            let app_data_dir = app.path().app_data_dir()
+                .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+            let storage_path = app_data_dir.join("received_files");
+            tauri::async_runtime::block_on(async {
+                http_server_manager.set_storage_path(storage_path).await;
+            });
+
+            app.manage(http_server_manager);
+
+            // 6. Initialize Database, MCP Client Manager and CQRS Handlers (Async in setup)
+            let app_handle = app.handle().clone();
+
+            tauri::async_runtime::block_on(async move {
+                match infra::db::init_db(&app_handle).await {
+                    Ok(pool) => {
+                        info!("Database initialized successfully");
+                        app_handle.manage(pool.clone());
+
+                        // --- Repositories & CQRS Handlers (shared with the headless runner) ---
+                        let application::wiring::Handlers {
+                            client_manager,
+                            oauth_manager,
+                            config_cmd_handler,
+                            config_query_handler,
+                            environment_cmd_handler,
+                            environment_query_handler,
+                            mcp_cmd_handler,
+                            mcp_query_handler,
+                            sampling_cmd_handler,
+                            sampling_query_handler,
+                            elicitation_cmd_handler,
+                            elicitation_query_handler,
+                            server_log_cmd_handler,
+                            server_log_query_handler,
+                            traffic_cmd_handler,
+                            traffic_query_handler,
+                            collection_cmd_handler,
+                            collection_query_handler,
+                            test_run_cmd_handler,
+                            test_run_query_handler,
+                            oauth_cmd_handler,
+                            oauth_query_handler,
+                        } = application::wiring::wire(pool.clone(), generic_publisher, domain_publisher, None).await?;
+
+                        app_handle.manage(client_manager);
+                        app_handle.manage(config_cmd_handler);
+                        app_handle.manage(config_query_handler);
+                        app_handle.manage(environment_cmd_handler);
+                        app_handle.manage(environment_query_handler);
+                        app_handle.manage(mcp_cmd_handler);
+                        app_handle.manage(mcp_query_handler);
+                        app_handle.manage(sampling_cmd_handler);
+                        app_handle.manage(sampling_query_handler);
+                        app_handle.manage(elicitation_cmd_handler);
+                        app_handle.manage(elicitation_query_handler);
+                        app_handle.manage(server_log_cmd_handler);
+                        app_handle.manage(server_log_query_handler);
+                        app_handle.manage(traffic_cmd_handler);
+                        app_handle.manage(traffic_query_handler);
+                        app_handle.manage(collection_cmd_handler);
+                        app_handle.manage(collection_query_handler);
+                        app_handle.manage(test_run_cmd_handler);
+                        app_handle.manage(test_run_query_handler);
+                        app_handle.manage(oauth_cmd_handler);
+                        app_handle.manage(oauth_query_handler);
+
+                        // Wire HTTP server callback -> persistence + UI refresh events
+                        let http_server_manager = app_handle.state::<infra::http_server::HttpServerManager>();
+
+                        // OAuth redirects finish the pending flow in the background
+                        http_server_manager.inner().set_oauth_callback({
+                            let oauth_manager = oauth_manager.clone();
+                            Arc::new(move |params: infra::http_server::OAuthCallbackParams| {
+                                let oauth_manager = oauth_manager.clone();
+                                tauri::async_runtime::spawn(async move {
+                                    oauth_manager.handle_callback(params).await;
+                                });
+                            })
+                        }).await;
+                        let pool_for_http_messages = pool.clone();
+                        let app_handle_for_http_messages = app_handle.clone();
+
+                        http_server_manager.inner().set_callback({
+                            let pool = pool_for_http_messages.clone();
+                            let app_handle = app_handle_for_http_messages.clone();
+                            Arc::new(move |info: infra::http_server::ReceivedMessageInfo| {
+                                let pool = pool.clone();
+                                let app_handle = app_handle.clone();
+
+                                tauri::async_runtime::spawn(async move {
+                                    let repo = SqliteHttpReceivedMessageRepository::new(pool.clone());
+                                    let message = HttpReceivedMessage {
+                                        id: info.id.clone(),
+                                        request_id: info.request_id.clone(),
+                                        content_type: info.content_type.clone(),
+                                        file_name: info.file_name.clone(),
+                                        file_path: info.file_path.clone(),
+                                        file_size: info.file_size,
+                                        raw_data: info.raw_data.clone(),
+                                        created_at: String::new(),
+                                    };
+
+                                    if let Err(err) = repo.create(message).await {
+                                        error!(target: "http_server", "Failed to persist webhook payload: {:?}", err);
+                                        return;
+                                    }
+
+                                    if let Err(err) = app_handle.emit("http-receiver:new-message", &info) {
+                                        error!(target: "http_server", "Failed to emit webhook event: {:?}", err);
+                                    }
+                                });
+                            })
+                        }).await;
+                    }
+                    Err(e) => {
+                        error!("Failed to initialize database: {:?}", e);
+                        panic!("Database initialization failed: {:?}", e);
+                    }
+                }
+                Ok::<(), error::AppError>(())
+            })?;
+
+            Ok(())
+        })
+        // Window event handling
+        .on_window_event(|window, event| {
+            match event {
+                // Save state when window is resized or moved
+                WindowEvent::Resized(_) | WindowEvent::Moved(_) => {
+                    let _ = window.app_handle().save_window_state(StateFlags::all());
+                }
+                // Handle close request -> Hide to Tray
+                WindowEvent::CloseRequested { api, .. } => {
+                    if window.label() == "main" {
+                        let _ = window.app_handle().save_window_state(StateFlags::all());
+
+                        #[cfg(not(target_os = "macos"))]
+                        {
+                            let _ = window.hide();
+                            api.prevent_close();
+                        }
+                    }
+                }
+                _ => {}
+            }
+        })
+        .invoke_handler(tauri::generate_handler![
+            // General commands
+            interface::commands::greet,
+            interface::commands::log_frontend_message,
+            interface::commands::open_log_folder,
+            interface::commands::open_storage_folder,
+            interface::commands::check_db_health,
+            // Config commands
+            interface::commands::get_app_setting,
+            interface::commands::set_app_setting,
+            interface::commands::get_all_settings,
+            // HTTP client commands
+            interface::commands::http_request,
+            // MCP Server commands
+            interface::commands::create_mcp_server,
+            interface::commands::update_mcp_server,
+            interface::commands::delete_mcp_server,
+            interface::commands::list_mcp_servers,
+            interface::commands::get_mcp_server,
+            interface::commands::connect_mcp_server,
+            interface::commands::disconnect_mcp_server,
+            interface::commands::terminate_mcp_session,
+            interface::commands::get_mcp_connection_info,
+            interface::commands::mark_mcp_server_disconnected,
+            interface::commands::set_mcp_server_roots,
+            interface::commands::set_mcp_server_heartbeat,
+            interface::commands::set_mcp_server_reconnect_policy,
+            interface::commands::get_mcp_heartbeats,
+            // MCP Tools commands
+            interface::commands::refresh_mcp_tools,
+            interface::commands::get_mcp_tools,
+            interface::commands::call_mcp_tool,
+            interface::commands::cancel_mcp_tool_call,
+            interface::commands::replay_mcp_call,
+            interface::commands::export_mcp_tools_json,
+            // MCP Resources commands
+            interface::commands::refresh_mcp_resources,
+            interface::commands::get_mcp_resources,
+            interface::commands::get_mcp_resource_templates,
+            interface::commands::read_mcp_resource,
+            interface::commands::subscribe_mcp_resource,
+            interface::commands::unsubscribe_mcp_resource,
+            interface::commands::get_mcp_resource_subscriptions,
+            // MCP Prompts commands
+            interface::commands::refresh_mcp_prompts,
+            interface::commands::get_mcp_prompts,
+            interface::commands::get_mcp_prompt,
+            interface::commands::get_mcp_prompt_history,
+            // MCP Call History
+            interface::commands::get_mcp_call_history,
+            // MCP Raw JSON-RPC
+            interface::commands::send_mcp_raw_rpc,
+            interface::commands::get_mcp_raw_rpc_history,
+            // Sampling commands
+            interface::commands::list_sampling_rules,
+            interface::commands::create_sampling_rule,
+            interface::commands::update_sampling_rule,
+            interface::commands::delete_sampling_rule,
+            interface::commands::list_pending_sampling_requests,
+            interface::commands::respond_sampling_request,
+            interface::commands::reject_sampling_request,
+            interface::commands::list_sampling_exchanges,
+            interface::commands::clear_sampling_exchanges,
+            // Elicitation commands
+            interface::commands::list_elicitation_rules,
+            interface::commands::create_elicitation_rule,
+            interface::commands::update_elicitation_rule,
+            interface::commands::delete_elicitation_rule,
+            interface::commands::list_pending_elicitation_requests,
+            interface::commands::respond_elicitation_request,
+            // MCP Server Log commands
+            interface::commands::list_mcp_server_logs,
+            interface::commands::set_mcp_log_level,
+            interface::commands::clear_mcp_server_logs,
+            // MCP Traffic commands
+            interface::commands::list_mcp_traffic,
+            interface::commands::clear_mcp_traffic,
+            interface::commands::export_mcp_traffic,
+            interface::commands::import_mcp_transcript,
+            interface::commands::list_mcp_transcripts,
+            interface::commands::get_mcp_transcript,
+            interface::commands::delete_mcp_transcript,
+            // Collection commands
+            interface::commands::list_mcp_collections,
+            interface::commands::get_mcp_collection,
+            interface::commands::create_mcp_collection,
+            interface::commands::update_mcp_collection,
+            interface::commands::delete_mcp_collection,
+            interface::commands::reorder_mcp_collections,
+            interface::commands::create_mcp_collection_folder,
+            interface::commands::update_mcp_collection_folder,
+            interface::commands::delete_mcp_collection_folder,
+            interface::commands::create_mcp_saved_call,
+            interface::commands::update_mcp_saved_call,
+            interface::commands::delete_mcp_saved_call,
+            interface::commands::reorder_mcp_collection_items,
+            interface::commands::run_mcp_collection,
+            // Test run commands
+            interface::commands::run_mcp_tests,
+            interface::commands::list_mcp_test_runs,
+            interface::commands::get_mcp_test_run,
+            interface::commands::delete_mcp_test_run,
+            // Environment commands
+            interface::commands::list_mcp_environments,
+            interface::commands::get_active_mcp_environment,
+            interface::commands::create_mcp_environment,
+            interface::commands::update_mcp_environment,
+            interface::commands::delete_mcp_environment,
+            interface::commands::set_active_mcp_environment,
+            // OAuth commands
+            interface::commands::start_mcp_oauth,
+            interface::commands::refresh_mcp_oauth_token,
+            interface::commands::clear_mcp_oauth,
+            interface::commands::get_mcp_oauth_status,
+            // HTTP Server commands
+            interface::commands::start_http_server,
+            interface::commands::stop_http_server,
+            interface::commands::is_http_server_running,
+            interface::commands::get_http_server_port,
+            interface::commands::get_local_ip_address,
+            // HTTP Received Messages
+            interface::commands::list_http_received_messages,
+            interface::commands::delete_http_received_message
+        ])
+        .run(tauri::generate_context!())
+        .expect("error while running tauri application");
+}
@@ -0,0 +1,366 @@
+//! OAuth flow against a local authorization server.
+//!
+//! The server is a small axum app that plays both the MCP endpoint (a 401 with a
+//! `WWW-Authenticate` challenge) and the authorization server. The browser step is a
+//! plain GET of the authorization URL whose redirect is handed to `handle_callback`.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use axum::extract::{Query, State};
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Redirect, Response};
+use axum::routing::{get, post};
+use axum::{Form, Json, Router};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use reqwest::Url;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use mcp_studio_lib::domain::mcp::McpServer;
+use mcp_studio_lib::domain::oauth::{IMcpOAuthRepository, McpOAuthCredentials};
+use mcp_studio_lib::error::AppError;
+use mcp_studio_lib::infra::event_publisher::EventPublisher;
+use mcp_studio_lib::infra::http_server::OAuthCallbackParams;
+use mcp_studio_lib::infra::oauth::McpOAuthManager;
+
+const REDIRECT_URI: &str = "http://127.0.0.1:1/oauth/callback";
+
+/// What the authorization server saw and issued
+#[derive(Default)]
+struct AuthServer {
+    /// Serve the well-known metadata documents (otherwise only the default endpoints exist)
+    metadata: bool,
+    /// Lifetime of issued access tokens
+    expires_in: i64,
+    base_url: String,
+    registered_clients: Vec<String>,
+    /// Code challenge and client of each issued code
+    codes: HashMap<String, (String, String)>,
+    token_requests: Vec<HashMap<String, String>>,
+    issued: usize,
+}
+
+type Shared = Arc<Mutex<AuthServer>>;
+
+async fn mcp_endpoint(State(state): State<Shared>) -> Response {
+    let state = state.lock().unwrap();
+    let challenge = if state.metadata {
+        format!(
+            "Bearer resource_metadata=\"{}/.well-known/oauth-protected-resource/mcp\", scope=\"tools:read\"",
+            state.base_url
+        )
+    } else {
+        "Bearer".to_string()
+    };
+    (StatusCode::UNAUTHORIZED, [(header::WWW_AUTHENTICATE, challenge)]).into_response()
+}
+
+async fn protected_resource(State(state): State<Shared>) -> Response {
+    let state = state.lock().unwrap();
+    if !state.metadata {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    Json(json!({
+        "resource": format!("{}/mcp", state.base_url),
+        "authorization_servers": [format!("{}/", state.base_url)],
+        "scopes_supported": ["tools:read", "tools:call"],
+    }))
+    .into_response()
+}
+
+async fn authorization_server(State(state): State<Shared>) -> Response {
+    let state = state.lock().unwrap();
+    if !state.metadata {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let base = &state.base_url;
+    Json(json!({
+        "issuer": base,
+        "authorization_endpoint": format!("{}/oauth/authorize", base),
+        "token_endpoint": format!("{}/oauth/token", base),
+        "registration_endpoint": format!("{}/oauth/register", base),
+        "code_challenge_methods_supported": ["S256"],
+    }))
+    .into_response()
+}
+
+async fn register(State(state): State<Shared>, Json(body): Json<Value>) -> Json<Value> {
+    assert_eq!(body["redirect_uris"], json!([REDIRECT_URI]));
+    assert_eq!(body["token_endpoint_auth_method"], "none");
+
+    let mut state = state.lock().unwrap();
+    let client_id = format!("client-{}", state.registered_clients.len() + 1);
+    state.registered_clients.push(client_id.clone());
+    Json(json!({ "client_id": client_id }))
+}
+
+async fn authorize(
+    State(state): State<Shared>,
+    Query(query): Query<HashMap<String, String>>,
+) -> Response {
+    assert_eq!(query["response_type"], "code");
+    assert_eq!(query["code_challenge_method"], "S256");
+
+    let mut state = state.lock().unwrap();
+    assert!(state.registered_clients.contains(&query["client_id"]), "unknown client");
+    let code = format!("code-{}", state.codes.len() + 1);
+    state.codes.insert(code.clone(), (query["code_challenge"].clone(), query["client_id"].clone()));
+
+    let mut redirect = Url::parse(&query["redirect_uri"]).unwrap();
+    redirect.query_pairs_mut().append_pair("code", &code).append_pair("state", &query["state"]);
+    Redirect::to(redirect.as_str()).into_response()
+}
+
+async fn token(State(state): State<Shared>, Form(form): Form<HashMap<String, String>>) -> Response {
+    let mut state = state.lock().unwrap();
+    state.token_requests.push(form.clone());
+
+    match form["grant_type"].as_str() {
+        "authorization_code" => {
+            let Some((challenge, client_id)) = state.codes.remove(&form["code"]) else {
+                return (StatusCode::BAD_REQUEST, Json(json!({ "error": "invalid_grant" })))
+                    .into_response();
+            };
+            let verified = URL_SAFE_NO_PAD.encode(Sha256::digest(form["code_verifier"].as_bytes()));
+            if verified != challenge || form["client_id"] != client_id {
+                return (StatusCode::BAD_REQUEST, Json(json!({ "error": "invalid_grant" })))
+                    .into_response();
+            }
+        }
+        "refresh_token" => assert!(form["refresh_token"].starts_with("refresh-")),
+        other => panic!("unexpected grant type {}", other),
+    }
+
+    state.issued += 1;
+    Json(json!({
+        "access_token": format!("access-{}", state.issued),
+        "token_type": "Bearer",
+        "expires_in": state.expires_in,
+        "refresh_token": format!("refresh-{}", state.issued),
+    }))
+    .into_response()
+}
+
+/// Start the authorization server on a free port
+async fn serve(metadata: bool, expires_in: i64) -> Shared {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let state =
+        Arc::new(Mutex::new(AuthServer { metadata, expires_in, base_url, ..Default::default() }));
+
+    let app = Router::new()
+        .route("/mcp", post(mcp_endpoint))
+        .route("/.well-known/oauth-protected-resource/mcp", get(protected_resource))
+        .route("/.well-known/oauth-authorization-server", get(authorization_server))
+        // Metadata endpoints
+        .route("/oauth/register", post(register))
+        .route("/oauth/authorize", get(authorize))
+        .route("/oauth/token", post(token))
+        // Default endpoints
+        .route("/register", post(register))
+        .route("/authorize", get(authorize))
+        .route("/token", post(token))
+        .with_state(state.clone());
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    state
+}
+
+#[derive(Default)]
+struct MemoryRepository(Mutex<HashMap<String, McpOAuthCredentials>>);
+
+#[async_trait::async_trait]
+impl IMcpOAuthRepository for MemoryRepository {
+    async fn save(
+        &self,
+        credentials: McpOAuthCredentials,
+    ) -> Result<McpOAuthCredentials, AppError> {
+        self.0.lock().unwrap().insert(credentials.server_id.clone(), credentials.clone());
+        Ok(credentials)
+    }
+
+    async fn find(&self, server_id: &str) -> Result<Option<McpOAuthCredentials>, AppError> {
+        Ok(self.0.lock().unwrap().get(server_id).cloned())
+    }
+
+    async fn delete(&self, server_id: &str) -> Result<(), AppError> {
+        self.0.lock().unwrap().remove(server_id);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct RecordingPublisher(Mutex<Vec<(String, Value)>>);
+
+#[async_trait::async_trait]
+impl EventPublisher for RecordingPublisher {
+    async fn publish(&self, event_name: &str, data: Value) {
+        self.0.lock().unwrap().push((event_name.to_string(), data));
+    }
+}
+
+fn server(url: &str) -> McpServer {
+    serde_json::from_value(json!({
+        "id": "server-1",
+        "name": "OAuth server",
+        "url": url,
+        "server_type": "streamable_http",
+        "auth": { "type": "oauth" },
+        "status": "disconnected",
+        "last_error": null,
+        "created_at": "",
+        "updated_at": "",
+    }))
+    .unwrap()
+}
+
+/// Run the flow up to the redirect and finish it through `handle_callback`
+async fn authorize_server(
+    oauth: &McpOAuthManager,
+    publisher: &RecordingPublisher,
+    server: &McpServer,
+) -> Url {
+    let start = oauth.start(server, REDIRECT_URI).await.unwrap();
+    let authorization_url = Url::parse(&start.authorization_url).unwrap();
+
+    let browser = reqwest::Client::builder()
+        .no_proxy()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .unwrap();
+    let response = browser.get(authorization_url.clone()).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    let location = Url::parse(response.headers()[header::LOCATION].to_str().unwrap()).unwrap();
+    let params: HashMap<String, String> = location.query_pairs().into_owned().collect();
+    assert_eq!(params["state"], start.state);
+
+    oauth
+        .handle_callback(OAuthCallbackParams {
+            code: params.get("code").cloned(),
+            state: params.get("state").cloned(),
+            error: None,
+            error_description: None,
+        })
+        .await;
+
+    let events = publisher.0.lock().unwrap();
+    let (name, data) = events.last().unwrap();
+    assert_eq!(name, "mcp:oauth_completed");
+    assert_eq!(data["success"], true, "flow failed: {}", data["error"]);
+
+    authorization_url
+}
+
+fn manager() -> (McpOAuthManager, Arc<RecordingPublisher>) {
+    let publisher = Arc::new(RecordingPublisher::default());
+    let oauth =
+        McpOAuthManager::new(Arc::new(MemoryRepository::default()), publisher.clone()).unwrap();
+    (oauth, publisher)
+}
+
+#[tokio::test]
+async fn discovers_metadata_registers_and_exchanges_code() {
+    let auth_server = serve(true, 3600).await;
+    let base_url = auth_server.lock().unwrap().base_url.clone();
+    let (oauth, publisher) = manager();
+    let server = server(&format!("{}/mcp", base_url));
+
+    let authorization_url = authorize_server(&oauth, &publisher, &server).await;
+
+    // Endpoints come from the metadata the 401 challenge pointed at
+    assert_eq!(authorization_url.path(), "/oauth/authorize");
+    let query: HashMap<String, String> = authorization_url.query_pairs().into_owned().collect();
+    assert_eq!(query["client_id"], "client-1");
+    assert_eq!(query["resource"], format!("{}/mcp", base_url));
+    assert_eq!(query["scope"], "tools:read");
+
+    let status = oauth.status(&server.id).await.unwrap();
+    assert!(status.authorized);
+    assert!(status.has_refresh_token);
+    assert_eq!(status.authorization_server.as_deref(), Some(base_url.as_str()));
+    assert_eq!(status.client_id.as_deref(), Some("client-1"));
+    assert_eq!(oauth.access_token(&server.id).await.unwrap().as_deref(), Some("access-1"));
+
+    let auth_server = auth_server.lock().unwrap();
+    assert_eq!(auth_server.token_requests.len(), 1);
+    assert_eq!(auth_server.token_requests[0]["redirect_uri"], REDIRECT_URI);
+    assert_eq!(auth_server.token_requests[0]["resource"], format!("{}/mcp", base_url));
+}
+
+#[tokio::test]
+async fn reuses_the_registration_of_an_earlier_flow() {
+    let auth_server = serve(true, 3600).await;
+    let base_url = auth_server.lock().unwrap().base_url.clone();
+    let (oauth, publisher) = manager();
+    let server = server(&format!("{}/mcp", base_url));
+
+    authorize_server(&oauth, &publisher, &server).await;
+    authorize_server(&oauth, &publisher, &server).await;
+
+    assert_eq!(auth_server.lock().unwrap().registered_clients, vec!["client-1"]);
+    assert_eq!(oauth.access_token(&server.id).await.unwrap().as_deref(), Some("access-2"));
+}
+
+#[tokio::test]
+async fn falls_back_to_default_endpoints_without_metadata() {
+    let auth_server = serve(false, 3600).await;
+    let base_url = auth_server.lock().unwrap().base_url.clone();
+    let (oauth, publisher) = manager();
+    let server = server(&format!("{}/mcp", base_url));
+
+    let authorization_url = authorize_server(&oauth, &publisher, &server).await;
+
+    assert_eq!(authorization_url.path(), "/authorize");
+    let query: HashMap<String, String> = authorization_url.query_pairs().into_owned().collect();
+    assert!(!query.contains_key("scope"));
+    assert_eq!(auth_server.lock().unwrap().registered_clients, vec!["client-1"]);
+    assert_eq!(oauth.access_token(&server.id).await.unwrap().as_deref(), Some("access-1"));
+}
+
+#[tokio::test]
+async fn rejects_a_callback_with_the_wrong_code() {
+    let auth_server = serve(true, 3600).await;
+    let base_url = auth_server.lock().unwrap().base_url.clone();
+    let (oauth, publisher) = manager();
+    let server = server(&format!("{}/mcp", base_url));
+
+    let start = oauth.start(&server, REDIRECT_URI).await.unwrap();
+    oauth
+        .handle_callback(OAuthCallbackParams {
+            code: Some("forged".to_string()),
+            state: Some(start.state),
+            error: None,
+            error_description: None,
+        })
+        .await;
+
+    let (_, data) = publisher.0.lock().unwrap().last().cloned().unwrap();
+    assert_eq!(data["success"], false);
+    assert!(data["error"].as_str().unwrap().contains("invalid_grant"));
+    assert!(!oauth.status(&server.id).await.unwrap().authorized);
+}
+
+#[tokio::test]
+async fn refreshes_an_expiring_access_token() {
+    // Tokens expiring within the refresh margin are refreshed before use
+    let auth_server = serve(true, 30).await;
+    let base_url = auth_server.lock().unwrap().base_url.clone();
+    let (oauth, publisher) = manager();
+    let server = server(&format!("{}/mcp", base_url));
+
+    authorize_server(&oauth, &publisher, &server).await;
+    assert_eq!(oauth.access_token(&server.id).await.unwrap().as_deref(), Some("access-2"));
+
+    {
+        let auth_server = auth_server.lock().unwrap();
+        let refresh = &auth_server.token_requests[1];
+        assert_eq!(refresh["grant_type"], "refresh_token");
+        assert_eq!(refresh["refresh_token"], "refresh-1");
+        assert_eq!(refresh["client_id"], "client-1");
+    }
+
+    let status = oauth.refresh(&server.id).await.unwrap();
+    assert!(status.authorized);
+    assert_eq!(auth_server.lock().unwrap().token_requests[2]["refresh_token"], "refresh-2");
+}
@@ -110,7 +110,7 @@ export function AddEditServerDialog({
             <Select
               value={formData.server_type}
               onValueChange={(value) =>
-                setFormData({ ...formData, server_type: value as 'sse' | 'streamable_http' })
+                setFormData({ ...formData, server_type: value as 'sse' | 'streamable_http' | 'websocket' })
               }
             >
               <SelectTrigger id="server_type">
@@ -119,6 +119,7 @@ export function AddEditServerDialog({
               <SelectContent>
                 <SelectItem value="streamable_http">Streamable HTTP</SelectItem>
                 <SelectItem value="sse">SSE (Server-Sent Events)</SelectItem>
+                <SelectItem value="websocket">WebSocket</SelectItem>
               </SelectContent>
             </Select>
           </div>
@@ -148,6 +148,8 @@ export function ToolDetail({ tool, onBack }: ToolDetailProps) {
     }
 
     setResult({
+      call_id: item.id,
+      status: item.status,
       success: item.status === 'success',
       raw_response: item.output_result || '',
       result: parsedResult,
@@ -253,6 +255,8 @@ export function ToolDetail({ tool, onBack }: ToolDetailProps) {
     } catch (error) {
       toast.error(t('mcp.playground.callError', { message: String(error) }));
       setResult({
+        call_id: '',
+        status: 'error',
         success: false,
         raw_response: JSON.stringify({ error: String(error) }, null, 2),
         result: null,
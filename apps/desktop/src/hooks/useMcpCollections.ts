@@ -0,0 +1,275 @@
+import { invoke } from '@tauri-apps/api/core';
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { McpToolCallResult } from './useMcpTools';
+
+// Types
+export interface McpCollection {
+  id: string;
+  name: string;
+  description: string | null;
+  position: number; // Lower comes first
+  created_at: string;
+  updated_at: string;
+}
+
+export interface McpCollectionFolder {
+  id: string;
+  collection_id: string;
+  parent_id: string | null; // null = top level
+  name: string;
+  position: number; // Shared with the calls of the same parent
+  created_at: string;
+  updated_at: string;
+}
+
+// Checks on the result of a saved call, evaluated by test runs
+export type McpAssertion =
+  | { type: 'success' }
+  | { type: 'is_error' } // The tool reported isError: true
+  | { type: 'json_path_equals'; path: string; value: unknown } // e.g. $.structuredContent.count
+  | { type: 'json_path_matches'; path: string; pattern: string } // Regex
+  | { type: 'duration_under'; max_ms: number }
+  | { type: 'output_schema' }; // structuredContent validates against the tool's output schema
+
+export interface McpSavedCall {
+  id: string;
+  collection_id: string;
+  folder_id: string | null; // null = top level
+  name: string;
+  server_id: string | null; // null once the server is deleted
+  tool_name: string;
+  params: string | null; // JSON string
+  notes: string | null;
+  assertions: McpAssertion[];
+  position: number; // Shared with the folders of the same parent
+  created_at: string;
+  updated_at: string;
+}
+
+export interface McpCollectionDetail {
+  collection: McpCollection;
+  folders: McpCollectionFolder[];
+  calls: McpSavedCall[];
+}
+
+export interface McpCollectionRunResult {
+  saved_call_id: string;
+  name: string;
+  folder_path: string[];
+  server_id: string | null;
+  tool_name: string;
+  status: McpToolCallResult['status'] | 'skipped';
+  success: boolean;
+  result: McpToolCallResult | null;
+  error: string | null;
+  history_id: string | null;
+  duration_ms: number;
+}
+
+export interface McpCollectionRunReport {
+  collection_id: string;
+  collection_name: string;
+  folder_id: string | null;
+  started_at: string;
+  duration_ms: number;
+  total: number;
+  passed: number;
+  failed: number;
+  skipped: number;
+  success: boolean;
+  results: McpCollectionRunResult[];
+}
+
+export interface McpSavedCallInput {
+  folder_id?: string | null;
+  name: string;
+  server_id?: string | null;
+  tool_name: string;
+  params?: Record<string, unknown> | null;
+  notes?: string | null;
+  assertions?: McpAssertion[];
+}
+
+export function useMcpCollections() {
+  const queryClient = useQueryClient();
+
+  const {
+    data: collections,
+    isLoading,
+    error,
+  } = useQuery({
+    queryKey: ['mcp-collections'],
+    queryFn: async () => {
+      return await invoke<McpCollection[]>('list_mcp_collections');
+    },
+  });
+
+  const createCollection = useMutation({
+    mutationFn: async (cmd: { name: string; description?: string | null }) => {
+      return await invoke<McpCollection>('create_mcp_collection', { cmd });
+    },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['mcp-collections'] });
+    },
+  });
+
+  const updateCollection = useMutation({
+    mutationFn: async (cmd: { id: string; name: string; description?: string | null }) => {
+      return await invoke<McpCollection>('update_mcp_collection', { cmd });
+    },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['mcp-collections'] });
+    },
+  });
+
+  const deleteCollection = useMutation({
+    mutationFn: async (id: string) => {
+      await invoke('delete_mcp_collection', { id });
+    },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['mcp-collections'] });
+      queryClient.invalidateQueries({ queryKey: ['mcp-collection'] });
+    },
+  });
+
+  // ids: every collection, in the new order
+  const reorderCollections = useMutation({
+    mutationFn: async (ids: string[]) => {
+      return await invoke<McpCollection[]>('reorder_mcp_collections', { ids });
+    },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['mcp-collections'] });
+    },
+  });
+
+  return {
+    collections,
+    isLoading,
+    error,
+    createCollection,
+    updateCollection,
+    deleteCollection,
+    reorderCollections,
+  };
+}
+
+// One collection with its folders and saved calls
+export function useMcpCollection(collectionId?: string) {
+  const queryClient = useQueryClient();
+  const invalidate = () => {
+    queryClient.invalidateQueries({ queryKey: ['mcp-collection', collectionId] });
+  };
+
+  const {
+    data: detail,
+    isLoading,
+    error,
+  } = useQuery({
+    queryKey: ['mcp-collection', collectionId],
+    queryFn: async () => {
+      return await invoke<McpCollectionDetail>('get_mcp_collection', { id: collectionId });
+    },
+    enabled: !!collectionId,
+  });
+
+  const createFolder = useMutation({
+    mutationFn: async ({ name, parentId }: { name: string; parentId?: string | null }) => {
+      return await invoke<McpCollectionFolder>('create_mcp_collection_folder', {
+        cmd: { collection_id: collectionId, parent_id: parentId || null, name },
+      });
+    },
+    onSuccess: invalidate,
+  });
+
+  // Moving to another parent puts the folder at its end
+  const updateFolder = useMutation({
+    mutationFn: async (cmd: { id: string; name: string; parent_id: string | null }) => {
+      return await invoke<McpCollectionFolder>('update_mcp_collection_folder', { cmd });
+    },
+    onSuccess: invalidate,
+  });
+
+  const deleteFolder = useMutation({
+    mutationFn: async (id: string) => {
+      await invoke('delete_mcp_collection_folder', { id });
+    },
+    onSuccess: invalidate,
+  });
+
+  const createCall = useMutation({
+    mutationFn: async (call: McpSavedCallInput) => {
+      return await invoke<McpSavedCall>('create_mcp_saved_call', {
+        cmd: { ...call, collection_id: collectionId },
+      });
+    },
+    onSuccess: invalidate,
+  });
+
+  // Moving to another folder puts the call at its end
+  const updateCall = useMutation({
+    mutationFn: async ({ id, ...call }: McpSavedCallInput & { id: string }) => {
+      return await invoke<McpSavedCall>('update_mcp_saved_call', { cmd: { ...call, id } });
+    },
+    onSuccess: invalidate,
+  });
+
+  const deleteCall = useMutation({
+    mutationFn: async (id: string) => {
+      await invoke('delete_mcp_saved_call', { id });
+    },
+    onSuccess: invalidate,
+  });
+
+  // itemIds: every folder and call of the parent, in the new order
+  const reorderItems = useMutation({
+    mutationFn: async ({ folderId, itemIds }: { folderId?: string | null; itemIds: string[] }) => {
+      await invoke('reorder_mcp_collection_items', {
+        collectionId,
+        folderId: folderId || null,
+        itemIds,
+      });
+    },
+    onSuccess: invalidate,
+  });
+
+  const runCollection = useMutation({
+    mutationFn: async ({
+      folderId,
+      serverId,
+      stopOnFailure,
+      timeoutMs,
+    }: {
+      folderId?: string; // Only this folder and its subfolders
+      serverId?: string; // Instead of each call's saved server
+      stopOnFailure?: boolean;
+      timeoutMs?: number; // Per call
+    } = {}) => {
+      return await invoke<McpCollectionRunReport>('run_mcp_collection', {
+        cmd: {
+          collection_id: collectionId,
+          folder_id: folderId || null,
+          server_id: serverId || null,
+          stop_on_failure: stopOnFailure ?? false,
+          timeout_ms: timeoutMs || null,
+        },
+      });
+    },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['mcp-call-history'] });
+    },
+  });
+
+  return {
+    detail,
+    isLoading,
+    error,
+    createFolder,
+    updateFolder,
+    deleteFolder,
+    createCall,
+    updateCall,
+    deleteCall,
+    reorderItems,
+    runCollection,
+  };
+}
@@ -0,0 +1,83 @@
+import { invoke } from '@tauri-apps/api/core';
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+
+// Types
+export interface McpEnvironmentVariable {
+  key: string; // Used as {{key}}
+  value: string;
+  secret: boolean; // Masked in call history
+}
+
+export interface McpEnvironment {
+  id: string;
+  name: string;
+  variables: McpEnvironmentVariable[];
+  created_at: string;
+  updated_at: string;
+}
+
+export function useMcpEnvironments() {
+  const queryClient = useQueryClient();
+  const invalidate = () => {
+    queryClient.invalidateQueries({ queryKey: ['mcp-environments'] });
+    queryClient.invalidateQueries({ queryKey: ['mcp-active-environment'] });
+  };
+
+  const {
+    data: environments,
+    isLoading,
+    error,
+  } = useQuery({
+    queryKey: ['mcp-environments'],
+    queryFn: async () => {
+      return await invoke<McpEnvironment[]>('list_mcp_environments');
+    },
+  });
+
+  const { data: activeEnvironment } = useQuery({
+    queryKey: ['mcp-active-environment'],
+    queryFn: async () => {
+      return await invoke<McpEnvironment | null>('get_active_mcp_environment');
+    },
+  });
+
+  const createEnvironment = useMutation({
+    mutationFn: async (cmd: { name: string; variables: McpEnvironmentVariable[] }) => {
+      return await invoke<McpEnvironment>('create_mcp_environment', { cmd });
+    },
+    onSuccess: invalidate,
+  });
+
+  const updateEnvironment = useMutation({
+    mutationFn: async (cmd: { id: string; name: string; variables: McpEnvironmentVariable[] }) => {
+      return await invoke<McpEnvironment>('update_mcp_environment', { cmd });
+    },
+    onSuccess: invalidate,
+  });
+
+  const deleteEnvironment = useMutation({
+    mutationFn: async (id: string) => {
+      await invoke('delete_mcp_environment', { id });
+    },
+    onSuccess: invalidate,
+  });
+
+  // null turns substitution off
+  const setActiveEnvironment = useMutation({
+    mutationFn: async (id: string | null) => {
+      return await invoke<McpEnvironment | null>('set_active_mcp_environment', { id });
+    },
+    onSuccess: invalidate,
+  });
+
+  return {
+    environments,
+    activeEnvironment,
+    isLoading,
+    error,
+    createEnvironment,
+    updateEnvironment,
+    deleteEnvironment,
+    setActiveEnvironment,
+  };
+}
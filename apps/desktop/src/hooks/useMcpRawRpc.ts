@@ -0,0 +1,69 @@
+import { invoke } from '@tauri-apps/api/core';
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+
+// Types
+export interface McpRawRpcHistory {
+  id: string;
+  server_id: string;
+  kind: 'request' | 'notification' | 'invalid'; // 'invalid' = not a JSON-RPC message
+  method: string | null;
+  request: string; // Raw text as sent
+  response: string | null; // Raw reply (or body of a rejected HTTP request)
+  status: 'success' | 'error' | 'timeout' | 'sent'; // 'sent' = notification, no reply expected
+  error_message: string | null;
+  duration_ms: number | null;
+  created_at: string;
+}
+
+// Send a hand-written JSON-RPC message; replies are matched by id, so prefer ids
+// that can't collide with Studio's own numeric request ids (e.g. strings)
+export function useMcpRawRpc() {
+  const queryClient = useQueryClient();
+
+  const sendRawRpc = useMutation({
+    mutationFn: async ({
+      serverId,
+      message,
+      timeoutMs,
+    }: {
+      serverId: string;
+      message: string;
+      timeoutMs?: number;
+    }) => {
+      return await invoke<McpRawRpcHistory>('send_mcp_raw_rpc', {
+        serverId,
+        message,
+        timeoutMs: timeoutMs || null,
+      });
+    },
+    onSettled: () => {
+      queryClient.invalidateQueries({ queryKey: ['mcp-raw-rpc-history'] });
+    },
+  });
+
+  return { sendRawRpc };
+}
+
+export function useMcpRawRpcHistory(serverId?: string, limit?: number) {
+  const {
+    data: history,
+    isLoading,
+    error,
+    refetch,
+  } = useQuery({
+    queryKey: ['mcp-raw-rpc-history', serverId, limit],
+    queryFn: async () => {
+      return await invoke<McpRawRpcHistory[]>('get_mcp_raw_rpc_history', {
+        serverId: serverId || null,
+        limit: limit || null,
+      });
+    },
+  });
+
+  return {
+    history,
+    isLoading,
+    error,
+    refetch,
+  };
+}
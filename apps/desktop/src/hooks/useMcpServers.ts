@@ -3,14 +3,70 @@ import { invoke } from '@tauri-apps/api/core';
 import { listen, UnlistenFn } from '@tauri-apps/api/event';
 import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
 import { logger } from '@/lib/logger';
-import { McpConnectionLostPayload } from '@/lib/events';
+import { McpConnectionLostPayload, McpReconnectingPayload } from '@/lib/events';
 
 // Types
+export interface McpRoot {
+  uri: string;
+  name: string | null;
+}
+
+export type McpServerAuth =
+  | { type: 'none' }
+  | { type: 'bearer'; token: string }
+  | { type: 'basic'; username: string; password: string }
+  | {
+      type: 'oauth';
+      client_id?: string | null;
+      client_secret?: string | null;
+      scope?: string | null;
+    };
+
+export interface McpOAuthStatus {
+  server_id: string;
+  authorized: boolean;
+  authorization_server: string | null;
+  client_id: string | null;
+  scope: string | null;
+  expires_at: number | null; // Unix seconds
+  has_refresh_token: boolean;
+  updated_at: string | null;
+}
+
+export interface McpOAuthStart {
+  server_id: string;
+  state: string;
+  authorization_url: string; // Open in the system browser
+  redirect_uri: string;
+}
+
+export type HeartbeatStrategy = 'ping' | 'list_tools' | 'none';
+
+export interface McpReconnectPolicy {
+  enabled: boolean;
+  max_attempts: number;
+  initial_delay_ms: number; // Doubled after every failed attempt
+  max_delay_ms: number;
+}
+
 export interface McpServer {
   id: string;
   name: string;
   url: string;
-  server_type: 'sse' | 'streamable_http' | 'stdio';
+  server_type: 'sse' | 'streamable_http' | 'websocket' | 'stdio';
+  command: string | null;
+  args: string[];
+  env: Record<string, string>;
+  cwd: string | null;
+  roots: McpRoot[];
+  headers: Record<string, string>; // Remote transports only (sent with the WebSocket handshake)
+  auth: McpServerAuth;
+  heartbeat_strategy: HeartbeatStrategy;
+  heartbeat_interval_secs: number | null; // null = global heartbeat_interval setting, else 10
+  heartbeat_max_failures: number | null; // null = 1 (disconnect on the first failure)
+  reconnect_policy: McpReconnectPolicy;
+  standalone_sse: boolean; // Streamable HTTP: open the GET stream for server-initiated messages
+  sse_reconnect: boolean; // Legacy SSE: reopen the GET stream when it drops
   status: 'connected' | 'disconnected' | 'connecting' | 'error';
   last_error: string | null;
   created_at: string;
@@ -20,14 +76,68 @@ export interface McpServer {
 export interface CreateMcpServerCmd {
   name: string;
   url: string;
-  server_type?: 'sse' | 'streamable_http' | 'stdio';
+  server_type?: 'sse' | 'streamable_http' | 'websocket' | 'stdio';
+  command?: string | null;
+  args?: string[];
+  env?: Record<string, string>;
+  cwd?: string | null;
+  roots?: McpRoot[];
+  headers?: Record<string, string>;
+  auth?: McpServerAuth;
+  heartbeat_strategy?: HeartbeatStrategy;
+  heartbeat_interval_secs?: number | null;
+  heartbeat_max_failures?: number | null;
+  reconnect_policy?: McpReconnectPolicy;
+  standalone_sse?: boolean;
+  sse_reconnect?: boolean;
+}
+
+export interface McpHeartbeat {
+  id: string;
+  server_id: string;
+  strategy: HeartbeatStrategy;
+  success: boolean;
+  rtt_ms: number;
+  error_message: string | null;
+  created_at: string;
 }
 
 export interface UpdateMcpServerCmd {
   id: string;
   name: string;
   url: string;
-  server_type?: 'sse' | 'streamable_http' | 'stdio';
+  server_type?: 'sse' | 'streamable_http' | 'websocket' | 'stdio';
+  command?: string | null;
+  args?: string[];
+  env?: Record<string, string>;
+  cwd?: string | null;
+  roots?: McpRoot[];
+  headers?: Record<string, string>;
+  auth?: McpServerAuth;
+  standalone_sse?: boolean;
+  sse_reconnect?: boolean;
+}
+
+export interface McpStreamableSession {
+  session_id: string | null; // null = stateless server
+  standalone_sse: boolean;
+  last_event_id: string | null; // Sent as Last-Event-ID when a stream is resumed
+  resumptions: number;
+  terminated: boolean;
+}
+
+export interface McpConnectionInfo {
+  server_id: string;
+  server_type: McpServer['server_type'];
+  server_info: Record<string, unknown>; // Initialize result
+  session: McpStreamableSession | null; // Streamable HTTP only
+  connection_id: string; // Traffic frames of this connection are recorded under it
+}
+
+export interface McpSessionTermination {
+  server_id: string;
+  session_id: string;
+  status: number; // HTTP status of the DELETE (405 = not allowed by the server)
 }
 
 export function useMcpServers() {
@@ -97,6 +207,37 @@ export function useMcpServers() {
     };
   }, [queryClient]);
 
+  // Listen for automatic reconnect progress
+  useEffect(() => {
+    let unlisten: UnlistenFn | undefined;
+
+    const setupListener = async () => {
+      try {
+        unlisten = await listen<McpReconnectingPayload>('mcp:reconnecting', (event) => {
+          const { server_id, attempt, max_attempts, status, error: errorMsg } = event.payload;
+          logger.info('MCP reconnect progress', {
+            fields: { server_id, attempt, max_attempts, status, error: errorMsg },
+          });
+
+          if (status === 'connected') {
+            queryClient.invalidateQueries({ queryKey: ['mcp-servers'] });
+            queryClient.invalidateQueries({ queryKey: ['mcp-tools', server_id] });
+          }
+        });
+      } catch (e) {
+        logger.error('Failed to listen to mcp:reconnecting event', { fields: { error: e } });
+      }
+    };
+
+    setupListener();
+
+    return () => {
+      if (unlisten) {
+        unlisten();
+      }
+    };
+  }, [queryClient]);
+
   const createServer = useMutation({
     mutationFn: async (cmd: CreateMcpServerCmd) => {
       return await invoke<McpServer>('create_mcp_server', { cmd });
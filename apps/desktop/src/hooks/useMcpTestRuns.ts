@@ -0,0 +1,135 @@
+import { invoke } from '@tauri-apps/api/core';
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { McpAssertion } from './useMcpCollections';
+
+// Types
+export interface McpAssertionResult {
+  assertion: McpAssertion;
+  passed: boolean;
+  message: string | null; // Why it failed
+}
+
+export interface McpTestRun {
+  id: string;
+  collection_id: string | null; // null once the collection is deleted
+  collection_name: string;
+  folder_id: string | null;
+  server_id: string | null;
+  started_at: string;
+  duration_ms: number;
+  total: number;
+  passed: number;
+  failed: number;
+  skipped: number;
+  success: boolean;
+  created_at: string;
+}
+
+export interface McpTestResult {
+  id: string;
+  run_id: string;
+  saved_call_id: string | null; // null once the saved call is deleted
+  name: string;
+  folder_path: string[];
+  server_id: string | null;
+  tool_name: string;
+  status: 'success' | 'error' | 'cancelled' | 'timeout' | 'skipped';
+  passed: boolean; // Every assertion passed
+  error: string | null;
+  history_id: string | null;
+  duration_ms: number;
+  assertions: McpAssertionResult[];
+  position: number;
+}
+
+export interface McpTestRunDetail {
+  run: McpTestRun;
+  results: McpTestResult[];
+}
+
+export function useMcpTestRuns(collectionId?: string, limit?: number) {
+  const queryClient = useQueryClient();
+
+  const {
+    data: runs,
+    isLoading,
+    error,
+  } = useQuery({
+    queryKey: ['mcp-test-runs', collectionId, limit],
+    queryFn: async () => {
+      return await invoke<McpTestRun[]>('list_mcp_test_runs', {
+        collectionId: collectionId || null,
+        limit: limit || null,
+      });
+    },
+  });
+
+  // Calls without assertions are checked for success
+  const runTests = useMutation({
+    mutationFn: async ({
+      collectionId: runCollectionId,
+      folderId,
+      savedCallIds,
+      serverId,
+      stopOnFailure,
+      timeoutMs,
+    }: {
+      collectionId: string;
+      folderId?: string; // Only this folder and its subfolders
+      savedCallIds?: string[]; // Only these calls
+      serverId?: string; // Instead of each call's saved server
+      stopOnFailure?: boolean;
+      timeoutMs?: number; // Per call
+    }) => {
+      return await invoke<McpTestRunDetail>('run_mcp_tests', {
+        cmd: {
+          collection_id: runCollectionId,
+          folder_id: folderId || null,
+          saved_call_ids: savedCallIds || null,
+          server_id: serverId || null,
+          stop_on_failure: stopOnFailure ?? false,
+          timeout_ms: timeoutMs || null,
+        },
+      });
+    },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['mcp-test-runs'] });
+      queryClient.invalidateQueries({ queryKey: ['mcp-call-history'] });
+    },
+  });
+
+  const deleteRun = useMutation({
+    mutationFn: async (id: string) => {
+      await invoke('delete_mcp_test_run', { id });
+    },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['mcp-test-runs'] });
+      queryClient.invalidateQueries({ queryKey: ['mcp-test-run'] });
+    },
+  });
+
+  return {
+    runs,
+    isLoading,
+    error,
+    runTests,
+    deleteRun,
+  };
+}
+
+// One test run with the outcome of every call and assertion
+export function useMcpTestRun(runId?: string) {
+  const {
+    data: detail,
+    isLoading,
+    error,
+  } = useQuery({
+    queryKey: ['mcp-test-run', runId],
+    queryFn: async () => {
+      return await invoke<McpTestRunDetail>('get_mcp_test_run', { id: runId });
+    },
+    enabled: !!runId,
+  });
+
+  return { detail, isLoading, error };
+}
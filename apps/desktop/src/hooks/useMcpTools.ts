@@ -1,5 +1,8 @@
+import { useEffect } from 'react';
 import { invoke } from '@tauri-apps/api/core';
+import { listen, UnlistenFn } from '@tauri-apps/api/event';
 import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { McpToolsChangedPayload } from '@/lib/events';
 
 // Types
 export interface McpTool {
@@ -13,12 +16,22 @@ export interface McpTool {
   created_at: string;
 }
 
+export interface McpListPaging {
+  page_count: number;
+  raw_pages: string[]; // Raw JSON of every page, in order
+  truncated: boolean; // Stopped at the page cap or on a repeated cursor
+  duration_ms: number;
+}
+
 export interface McpToolsListResult {
   tools: McpTool[];
-  raw_response: string; // Raw JSON for debugging
+  raw_response: string; // Raw JSON for debugging (array of pages if paged)
+  paging: McpListPaging;
 }
 
 export interface McpToolCallResult {
+  call_id: string;
+  status: 'success' | 'error' | 'cancelled' | 'timeout';
   success: boolean;
   raw_response: string; // Raw JSON for debugging
   result: unknown | null;
@@ -30,12 +43,32 @@ export interface McpCallHistory {
   id: string;
   server_id: string;
   tool_name: string;
-  input_params: string | null;
+  input_params: string | null; // Resolved, secrets masked
   output_result: string | null;
-  status: 'success' | 'error';
+  status: 'success' | 'error' | 'cancelled' | 'timeout';
   error_message: string | null;
   duration_ms: number | null;
   created_at: string;
+  replayed_from: string | null; // History entry this call re-ran
+  input_template: string | null; // Params as written, when {{variables}} were substituted
+}
+
+export interface McpJsonChange {
+  path: string; // JSONPath, e.g. $.content[0].text
+  kind: 'added' | 'removed' | 'changed';
+  before: unknown | null;
+  after: unknown | null;
+}
+
+export interface McpCallReplay {
+  original: McpCallHistory;
+  replay: McpCallHistory;
+  result: McpToolCallResult;
+  diff: {
+    identical: boolean;
+    status_changed: boolean;
+    changes: McpJsonChange[];
+  };
 }
 
 // Batch testing types
@@ -75,6 +108,21 @@ export function useMcpTools(serverId: string | null) {
     enabled: !!serverId,
   });
 
+  // Server pushed notifications/tools/list_changed; backend already refreshed its cache
+  useEffect(() => {
+    let unlisten: UnlistenFn | undefined;
+
+    listen<McpToolsChangedPayload>('mcp:tools_changed', (event) => {
+      queryClient.invalidateQueries({ queryKey: ['mcp-tools', event.payload.server_id] });
+    }).then((fn) => {
+      unlisten = fn;
+    });
+
+    return () => {
+      unlisten?.();
+    };
+  }, [queryClient]);
+
   // Refresh tools from server (fetches fresh list and caches)
   const refreshTools = useMutation({
     mutationFn: async (serverId: string) => {
@@ -91,15 +139,21 @@ export function useMcpTools(serverId: string | null) {
       serverId,
       toolName,
       params,
+      callId,
+      timeoutMs,
     }: {
       serverId: string;
       toolName: string;
       params?: Record<string, unknown>;
+      callId?: string;
+      timeoutMs?: number;
     }) => {
       return await invoke<McpToolCallResult>('call_mcp_tool', {
         serverId,
         toolName,
         params: params || null,
+        callId: callId || null,
+        timeoutMs: timeoutMs || null,
       });
     },
     onSuccess: () => {
@@ -107,6 +161,11 @@ export function useMcpTools(serverId: string | null) {
     },
   });
 
+  // Cancel an in-flight tool call (the pending callTool resolves with status 'cancelled')
+  const cancelToolCall = async (callId: string): Promise<void> => {
+    await invoke('cancel_mcp_tool_call', { callId });
+  };
+
   // Export tools as JSON
   const exportToolsJson = async (serverId: string): Promise<string> => {
     return await invoke<string>('export_mcp_tools_json', { serverId });
@@ -119,6 +178,7 @@ export function useMcpTools(serverId: string | null) {
     refetch,
     refreshTools,
     callTool,
+    cancelToolCall,
     exportToolsJson,
   };
 }
@@ -146,3 +206,29 @@ export function useMcpCallHistory(serverId?: string, limit?: number) {
     refetch,
   };
 }
+
+// Re-run a history entry, on its own server or another connected one
+export function useReplayMcpCall() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: async ({
+      historyId,
+      serverId,
+      timeoutMs,
+    }: {
+      historyId: string;
+      serverId?: string;
+      timeoutMs?: number;
+    }) => {
+      return await invoke<McpCallReplay>('replay_mcp_call', {
+        historyId,
+        serverId: serverId || null,
+        timeoutMs: timeoutMs || null,
+      });
+    },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['mcp-call-history'] });
+    },
+  });
+}
@@ -0,0 +1,219 @@
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen, UnlistenFn } from '@tauri-apps/api/event';
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { logger } from '@/lib/logger';
+import { McpTrafficPayload } from '@/lib/events';
+import { McpServer } from './useMcpServers';
+import { McpCallHistory } from './useMcpTools';
+
+// Types
+export type McpTrafficFrame = McpTrafficPayload;
+
+export type McpTrafficExportFormat = 'har' | 'transcript'; // HAR: SSE and streamable HTTP only
+
+export interface McpTrafficExport {
+  format: McpTrafficExportFormat;
+  file_name: string; // Suggested name (.har or .ndjson)
+  content: string;
+  frame_count: number;
+  call_count: number;
+}
+
+export interface McpTranscriptHeader {
+  format: string;
+  version: number;
+  server: { id: string; name: string; url: string; transport: McpServer['server_type'] };
+  connection_id: string;
+  server_info: Record<string, unknown> | null; // Initialize result
+  started_at: string | null;
+  ended_at: string | null;
+  exported_at: string;
+}
+
+export interface McpTranscriptFrame {
+  direction: McpTrafficFrame['direction'];
+  kind: McpTrafficFrame['kind'];
+  method: string | null;
+  correlation_id: string | null;
+  timestamp: string;
+  elapsed_ms: number; // Since the first frame
+  latency_ms: number | null; // Responses only
+  size_bytes: number;
+  frame: string;
+}
+
+export interface McpTranscript {
+  id: string;
+  name: string;
+  server_name: string;
+  server_url: string;
+  transport: McpServer['server_type'];
+  connection_id: string;
+  frame_count: number;
+  call_count: number;
+  started_at: string | null;
+  created_at: string;
+}
+
+export interface McpTranscriptDetail {
+  transcript: McpTranscript;
+  header: McpTranscriptHeader;
+  frames: McpTranscriptFrame[];
+  calls: McpCallHistory[];
+}
+
+export interface McpTrafficFilter {
+  serverId?: string;
+  connectionId?: string;
+  direction?: McpTrafficFrame['direction'];
+  correlationId?: string;
+  limit?: number;
+}
+
+// Recorded frames, newest first
+export function useMcpTraffic(filter: McpTrafficFilter = {}) {
+  const queryClient = useQueryClient();
+  const { serverId, connectionId, direction, correlationId, limit } = filter;
+
+  const {
+    data: frames,
+    isLoading,
+    error,
+    refetch,
+  } = useQuery({
+    queryKey: ['mcp-traffic', serverId, connectionId, direction, correlationId, limit],
+    queryFn: async () => {
+      return await invoke<McpTrafficFrame[]>('list_mcp_traffic', {
+        serverId: serverId || null,
+        connectionId: connectionId || null,
+        direction: direction || null,
+        correlationId: correlationId || null,
+        limit: limit || null,
+      });
+    },
+  });
+
+  const clearTraffic = useMutation({
+    mutationFn: async (serverId?: string) => {
+      await invoke('clear_mcp_traffic', { serverId: serverId || null });
+    },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['mcp-traffic'] });
+    },
+  });
+
+  // Returns the file contents; the caller saves them (e.g. with the dialog and fs plugins)
+  const exportTraffic = useMutation({
+    mutationFn: async ({
+      serverId,
+      connectionId,
+      format,
+    }: {
+      serverId: string;
+      connectionId?: string; // Defaults to the most recent connection
+      format: McpTrafficExportFormat;
+    }) => {
+      return await invoke<McpTrafficExport>('export_mcp_traffic', {
+        serverId,
+        connectionId: connectionId || null,
+        format,
+      });
+    },
+  });
+
+  return {
+    frames,
+    isLoading,
+    error,
+    refetch,
+    clearTraffic,
+    exportTraffic,
+  };
+}
+
+export function useMcpTranscripts() {
+  const queryClient = useQueryClient();
+
+  const {
+    data: transcripts,
+    isLoading,
+    error,
+  } = useQuery({
+    queryKey: ['mcp-transcripts'],
+    queryFn: async () => {
+      return await invoke<McpTranscript[]>('list_mcp_transcripts');
+    },
+  });
+
+  const importTranscript = useMutation({
+    mutationFn: async ({ content, name }: { content: string; name?: string }) => {
+      return await invoke<McpTranscript>('import_mcp_transcript', { content, name: name || null });
+    },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['mcp-transcripts'] });
+    },
+  });
+
+  const deleteTranscript = useMutation({
+    mutationFn: async (id: string) => {
+      await invoke('delete_mcp_transcript', { id });
+    },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['mcp-transcripts'] });
+    },
+  });
+
+  return {
+    transcripts,
+    isLoading,
+    error,
+    importTranscript,
+    deleteTranscript,
+  };
+}
+
+export function useMcpTranscript(id?: string) {
+  const { data: transcript, isLoading, error } = useQuery({
+    queryKey: ['mcp-transcript', id],
+    queryFn: async () => {
+      return await invoke<McpTranscriptDetail>('get_mcp_transcript', { id });
+    },
+    enabled: !!id,
+  });
+
+  return { transcript, isLoading, error };
+}
+
+// Frames as they happen (oldest first), capped at `maxFrames`
+export function useMcpTrafficStream(serverId?: string, maxFrames = 1000) {
+  const [frames, setFrames] = useState<McpTrafficFrame[]>([]);
+
+  useEffect(() => {
+    let unlisten: UnlistenFn | undefined;
+    setFrames([]);
+
+    const setupListener = async () => {
+      try {
+        unlisten = await listen<McpTrafficPayload>('mcp:traffic', (event) => {
+          if (serverId && event.payload.server_id !== serverId) {
+            return;
+          }
+          setFrames((prev) => [...prev, event.payload].slice(-maxFrames));
+        });
+      } catch (e) {
+        logger.error('Failed to listen to mcp:traffic event', { fields: { error: e } });
+      }
+    };
+
+    setupListener();
+
+    return () => {
+      if (unlisten) {
+        unlisten();
+      }
+    };
+  }, [serverId, maxFrames]);
+
+  return { frames, clear: () => setFrames([]) };
+}
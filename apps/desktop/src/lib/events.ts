@@ -3,7 +3,21 @@
 
 export type AppEvent =
   | { event: 'config:changed'; payload: { key: string; value: string } }
-  | { event: 'mcp:connection_lost'; payload: McpConnectionLostPayload };
+  | { event: 'mcp:connection_lost'; payload: McpConnectionLostPayload }
+  | { event: 'mcp:reconnecting'; payload: McpReconnectingPayload }
+  | { event: 'mcp:tools_changed'; payload: McpToolsChangedPayload }
+  | { event: 'mcp:resources_changed'; payload: McpResourcesChangedPayload }
+  | { event: 'mcp:resource_updated'; payload: McpResourceUpdatedPayload }
+  | { event: 'mcp:prompts_changed'; payload: McpPromptsChangedPayload }
+  | { event: 'mcp:sampling_request'; payload: McpSamplingRequestPayload }
+  | { event: 'mcp:sampling_completed'; payload: McpSamplingCompletedPayload }
+  | { event: 'mcp:elicitation_request'; payload: McpElicitationRequestPayload }
+  | { event: 'mcp:elicitation_completed'; payload: McpElicitationCompletedPayload }
+  | { event: 'mcp:server_log'; payload: McpServerLogPayload }
+  | { event: 'mcp:tool_call_progress'; payload: McpToolCallProgressPayload }
+  | { event: 'mcp:heartbeat'; payload: McpHeartbeatPayload }
+  | { event: 'mcp:oauth_completed'; payload: McpOAuthCompletedPayload }
+  | { event: 'mcp:traffic'; payload: McpTrafficPayload };
 // | { event: 'download:progress'; payload: { id: string; progress: number } }
 
 export type EventName = AppEvent['event'];
@@ -16,4 +30,133 @@ export interface McpConnectionLostPayload {
   status: string;
   error: string;
   reason?: string;
+  reconnect?: boolean; // An automatic reconnect follows
+}
+
+export interface McpReconnectingPayload {
+  server_id: string;
+  attempt: number;
+  max_attempts: number;
+  status: 'scheduled' | 'failed' | 'connected' | 'gave_up';
+  delay_ms: number | null; // Set when an attempt is scheduled
+  error: string | null;
+}
+
+export interface McpToolsChangedPayload {
+  server_id: string;
+  tools_count: number;
+  before: string[];
+  after: string[];
+  added: string[];
+  removed: string[];
+}
+
+export interface McpResourcesChangedPayload {
+  server_id: string;
+  resources_count: number;
+}
+
+export interface McpResourceUpdatedPayload {
+  server_id: string;
+  uri: string;
+}
+
+export interface McpPromptsChangedPayload {
+  server_id: string;
+  prompts_count: number;
+}
+
+// Sampling Events
+export interface McpSamplingRequestPayload {
+  request_id: string;
+  server_id: string;
+  request: Record<string, unknown>; // CreateMessageRequestParam
+  created_at: string;
+}
+
+export interface McpSamplingCompletedPayload {
+  request_id: string;
+  server_id: string;
+  status: 'answered' | 'rejected' | 'timeout' | 'cancelled';
+  source: 'rule' | 'manual' | null;
+}
+
+// Elicitation Events
+export type McpElicitationAction = 'accept' | 'decline' | 'cancel';
+
+export interface McpElicitationRequestPayload {
+  request_id: string;
+  server_id: string;
+  message: string;
+  requested_schema: Record<string, unknown>; // JSON schema of the expected content
+  created_at: string;
+}
+
+export interface McpElicitationCompletedPayload {
+  request_id: string;
+  server_id: string;
+  action: McpElicitationAction;
+  content?: Record<string, unknown> | null;
+  source: 'rule' | 'manual';
+}
+
+// Server Log Events
+export type McpLogLevel =
+  | 'debug'
+  | 'info'
+  | 'notice'
+  | 'warning'
+  | 'error'
+  | 'critical'
+  | 'alert'
+  | 'emergency';
+
+export interface McpServerLogPayload {
+  id: string;
+  server_id: string;
+  level: McpLogLevel;
+  logger: string | null;
+  data: string; // JSON string
+  created_at: string;
+}
+
+// Tool Call Progress Events
+export interface McpToolCallProgressPayload {
+  call_id: string;
+  server_id: string;
+  progress_token: string;
+  progress: number;
+  total: number | null;
+  message: string | null;
+}
+
+// Heartbeat Events
+export interface McpHeartbeatPayload {
+  server_id: string;
+  strategy: 'ping' | 'list_tools' | 'none';
+  success: boolean;
+  rtt_ms: number;
+  error: string | null;
+}
+
+// OAuth Events
+export interface McpOAuthCompletedPayload {
+  server_id: string;
+  success: boolean;
+  error: string | null;
+}
+
+// Traffic Events (one per JSON-RPC frame on the wire)
+export interface McpTrafficPayload {
+  id: string;
+  server_id: string;
+  connection_id: string; // New for every connect
+  transport: 'sse' | 'streamable_http' | 'websocket' | 'stdio';
+  direction: 'outbound' | 'inbound';
+  kind: 'request' | 'response' | 'error' | 'notification' | 'batch' | 'invalid';
+  method: string | null;
+  correlation_id: string | null; // JSON-RPC id as JSON text, e.g. 1 or "abc"
+  frame: string; // Raw text as sent or received
+  size_bytes: number;
+  created_at: string; // Millisecond precision
 }